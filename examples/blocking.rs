@@ -0,0 +1,34 @@
+#[cfg(feature = "blocking")]
+use std::env;
+
+use hubcaps::Result;
+
+#[cfg(feature = "blocking")]
+use hubcaps::{blocking::Github, Credentials};
+
+fn main() -> Result<()> {
+    pretty_env_logger::init();
+
+    #[cfg(not(feature = "blocking"))]
+    {
+        println!("rerun this example with `cargo run --features blocking --example blocking`");
+        Ok(())
+    }
+
+    #[cfg(feature = "blocking")]
+    match env::var("GITHUB_TOKEN").ok() {
+        Some(token) => {
+            let github = Github::new(
+                concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION")),
+                Credentials::Token(token),
+            )?;
+            let releases =
+                github.collect(|github| github.repo("octokit", "rest.js").releases().iter())?;
+            for release in releases {
+                println!("{}", release.tag_name);
+            }
+            Ok(())
+        }
+        _ => Err("example missing GITHUB_TOKEN".into()),
+    }
+}
@@ -11,7 +11,7 @@ use hubcaps::git::GetReferenceResponse;
 use hubcaps::{Credentials, Github, InstallationTokenGenerator, JWTCredentials, Result};
 
 fn var(name: &str) -> Result<String> {
-    if let Some(v) = env::var(name).ok() {
+    if let Ok(v) = env::var(name) {
         Ok(v)
     } else {
         Err(format!("example missing {}", name).into())
@@ -66,8 +66,8 @@ fn main() -> Result<()> {
                 label: "nix-build -A pkgB".to_string(),
             },
         ]),
-        completed_at: Some("2018-01-01T01:01:01Z".to_string()),
-        started_at: Some("2018-08-01T01:01:01Z".to_string()),
+        completed_at: Some("2018-01-01T01:01:01Z".parse().unwrap()),
+        started_at: Some("2018-08-01T01:01:01Z".parse().unwrap()),
         conclusion: Some(Conclusion::Neutral),
         details_url: Some("https://nix.ci/status/hi".to_string()),
         external_id: Some("heyyy".to_string()),
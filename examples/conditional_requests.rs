@@ -25,7 +25,7 @@ fn main() -> Result<()> {
         let host = "https://api.github.com";
         let agent = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
         let client = Client::builder().build()?;
-        let http_cache = HttpCache::in_home_dir();
+        let http_cache = <dyn HttpCache>::in_home_dir();
         let github = Github::custom(host, agent, None, client, http_cache);
 
         let _repos = rt.block_on(github.user_repos("dwijnand").list(&Default::default()))?;
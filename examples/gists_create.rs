@@ -3,7 +3,7 @@ use std::env;
 
 use tokio::runtime::Runtime;
 
-use hubcaps::gists::{Content, GistOptions};
+use hubcaps::gists::{GistEditOptions, GistOptions};
 use hubcaps::{Credentials, Github, Result};
 
 fn main() -> Result<()> {
@@ -24,23 +24,22 @@ fn main() -> Result<()> {
             println!("{:#?}", gist);
 
             // edit file1
-            let mut files = HashMap::new();
-            files.insert("file1", "Hello World!!");
-            let options = GistOptions::new(None as Option<String>, false, files);
+            let options = GistEditOptions::builder()
+                .update("file1", "Hello World!!")
+                .build();
             let gist = rt.block_on(github.gists().edit(&gist.id, &options))?;
             println!("{:#?}", gist);
 
-            // rename file1 to file2
-            let mut files = HashMap::new();
-            files.insert(
-                String::from("file1"),
-                Content::new(Some("file2"), "Hello World!!"),
-            );
-            let options = GistOptions {
-                description: None as Option<String>,
-                public: None,
-                files: files,
-            };
+            // rename file1 to file2 and add file3
+            let options = GistEditOptions::builder()
+                .rename("file1", "file2")
+                .update("file3", "Hello again")
+                .build();
+            let gist = rt.block_on(github.gists().edit(&gist.id, &options))?;
+            println!("{:#?}", gist);
+
+            // delete file3
+            let options = GistEditOptions::builder().delete("file3").build();
             let gist = rt.block_on(github.gists().edit(&gist.id, &options))?;
             println!("{:#?}", gist);
 
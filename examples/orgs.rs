@@ -25,14 +25,14 @@ fn main() -> Result<()> {
                 println!("{}", repo.name)
             }
 
-            println!("");
+            println!();
 
             println!("My organizations:");
             for org in rt.block_on(github.orgs().list())? {
                 println!("{}", org.login)
             }
 
-            println!("");
+            println!();
 
             println!("softprops' organizations:");
             for org in rt.block_on(github.user_orgs("softprops").list())? {
@@ -73,8 +73,8 @@ fn main() -> Result<()> {
                 rt.block_on(team.remove_user("grahamc"))
             );
 
-            let deleted_team = rt.block_on(team.delete())?;
-            println!("Deleted team: {:#?}", deleted_team);
+            rt.block_on(team.delete())?;
+            println!("Deleted team");
 
             Ok(())
         }
@@ -0,0 +1,1957 @@
+//! Actions interface
+//!
+//! See the [github docs](https://developer.github.com/v3/actions/) for more information
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crypto_box::aead::OsRng;
+use futures::{Future as StdFuture, IntoFuture};
+use http::Method;
+use percent_encoding::{utf8_percent_encode, PATH_SEGMENT_ENCODE_SET};
+use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
+
+use crate::errors::{ErrorKind, Result};
+use crate::repositories::Repo;
+use crate::users::User;
+use crate::{AuthenticationConstraint, DateTime, Future, Github, MediaType, SortDirection, Stream};
+
+/// Provides access to the actions settings of an organization
+pub struct OrgActions {
+    github: Github,
+    org: String,
+}
+
+impl OrgActions {
+    #[doc(hidden)]
+    pub fn new<O>(github: Github, org: O) -> Self
+    where
+        O: Into<String>,
+    {
+        OrgActions {
+            github,
+            org: org.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/orgs/{}/actions{}", self.org, more)
+    }
+
+    /// get which repositories may run actions and which actions they may run
+    ///
+    /// See the [github docs](https://developer.github.com/v3/actions/permissions/#get-github-actions-permissions-for-an-organization)
+    /// for more information.
+    pub fn permissions(&self) -> Future<OrgActionsPermissions> {
+        self.github.get(&self.path("/permissions"))
+    }
+
+    /// set which repositories may run actions and which actions they may run
+    pub fn set_permissions(&self, permissions: &OrgActionsPermissions) -> Future<()> {
+        self.github
+            .put_no_response(&self.path("/permissions"), json!(permissions))
+    }
+
+    /// list the repositories enabled for actions when `enabled_repositories`
+    /// is `selected`
+    pub fn enabled_repositories(&self) -> Future<EnabledRepositoriesList> {
+        self.github.get(&self.path("/permissions/repositories"))
+    }
+
+    /// replace the repositories enabled for actions when `enabled_repositories`
+    /// is `selected`
+    pub fn set_enabled_repositories(&self, repository_ids: &[u64]) -> Future<()> {
+        self.github.put_no_response(
+            &self.path("/permissions/repositories"),
+            json_lit!({ "selected_repository_ids": repository_ids }),
+        )
+    }
+
+    /// add a repository to those enabled for actions
+    pub fn enable_repository(&self, repository_id: u64) -> Future<()> {
+        self.github.put_no_response(
+            &self.path(&format!("/permissions/repositories/{}", repository_id)),
+            Vec::new(),
+        )
+    }
+
+    /// remove a repository from those enabled for actions
+    pub fn disable_repository(&self, repository_id: u64) -> Future<()> {
+        self.github
+            .delete(&self.path(&format!("/permissions/repositories/{}", repository_id)))
+    }
+
+    /// get the actions allowed when `allowed_actions` is `selected`
+    pub fn selected_actions(&self) -> Future<SelectedActions> {
+        self.github.get(&self.path("/permissions/selected-actions"))
+    }
+
+    /// set the actions allowed when `allowed_actions` is `selected`
+    pub fn set_selected_actions(&self, actions: &SelectedActions) -> Future<()> {
+        self.github
+            .put_no_response(&self.path("/permissions/selected-actions"), json!(actions))
+    }
+
+    /// get the default permissions of the `GITHUB_TOKEN` workflows run with
+    pub fn workflow_permissions(&self) -> Future<WorkflowPermissions> {
+        self.github.get(&self.path("/permissions/workflow"))
+    }
+
+    /// set the default permissions of the `GITHUB_TOKEN` workflows run with
+    pub fn set_workflow_permissions(&self, permissions: &WorkflowPermissions) -> Future<()> {
+        self.github
+            .put_no_response(&self.path("/permissions/workflow"), json!(permissions))
+    }
+
+    /// get which outside contributors' pull request workflows require approval to run
+    pub fn fork_pr_approval(&self) -> Future<ForkPrApproval> {
+        self.github
+            .get(&self.path("/permissions/fork-pr-contributor-approval"))
+    }
+
+    /// set which outside contributors' pull request workflows require approval to run
+    pub fn set_fork_pr_approval(&self, approval: &ForkPrApproval) -> Future<()> {
+        self.github.put_no_response(
+            &self.path("/permissions/fork-pr-contributor-approval"),
+            json!(approval),
+        )
+    }
+
+    /// returns a reference to an interface for self-hosted runner group operations
+    pub fn runner_groups(&self) -> RunnerGroups {
+        RunnerGroups::new(self.github.clone(), self.org.as_str())
+    }
+
+    /// get a reference to the secrets of this organization
+    pub fn secrets(&self) -> Secrets {
+        Secrets::for_org(self.github.clone(), self.org.as_str())
+    }
+}
+
+/// Provides access to the self-hosted runner groups of an organization
+///
+/// See the [github docs](https://developer.github.com/v3/actions/self-hosted-runner-groups/)
+/// for more information.
+pub struct RunnerGroups {
+    github: Github,
+    org: String,
+}
+
+impl RunnerGroups {
+    #[doc(hidden)]
+    pub fn new<O>(github: Github, org: O) -> Self
+    where
+        O: Into<String>,
+    {
+        RunnerGroups {
+            github,
+            org: org.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/orgs/{}/actions/runner-groups{}", self.org, more)
+    }
+
+    pub fn list(&self) -> Future<RunnerGroupList> {
+        self.github.get(&self.path(""))
+    }
+
+    pub fn get(&self, id: u64) -> Future<RunnerGroup> {
+        self.github.get(&self.path(&format!("/{}", id)))
+    }
+
+    pub fn create(&self, group: &RunnerGroupOptions) -> Future<RunnerGroup> {
+        self.github.post(&self.path(""), json!(group))
+    }
+
+    /// update the name and visibility of a group. the repositories and
+    /// runners of a group are managed with their own methods
+    pub fn edit(&self, id: u64, group: &RunnerGroupOptions) -> Future<RunnerGroup> {
+        self.github
+            .patch(&self.path(&format!("/{}", id)), json!(group))
+    }
+
+    pub fn delete(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.path(&format!("/{}", id)))
+    }
+
+    /// list the repositories which may use a group with `selected` visibility
+    pub fn repositories(&self, id: u64) -> Future<RunnerGroupRepositoryList> {
+        self.github
+            .get(&self.path(&format!("/{}/repositories", id)))
+    }
+
+    /// replace the repositories which may use a group with `selected` visibility
+    pub fn set_repositories(&self, id: u64, repository_ids: &[u64]) -> Future<()> {
+        self.github.put_no_response(
+            &self.path(&format!("/{}/repositories", id)),
+            json_lit!({ "selected_repository_ids": repository_ids }),
+        )
+    }
+
+    pub fn add_repository(&self, id: u64, repository_id: u64) -> Future<()> {
+        self.github.put_no_response(
+            &self.path(&format!("/{}/repositories/{}", id, repository_id)),
+            Vec::new(),
+        )
+    }
+
+    pub fn remove_repository(&self, id: u64, repository_id: u64) -> Future<()> {
+        self.github
+            .delete(&self.path(&format!("/{}/repositories/{}", id, repository_id)))
+    }
+
+    /// list the self-hosted runners in a group
+    pub fn runners(&self, id: u64) -> Future<RunnerList> {
+        self.github.get(&self.path(&format!("/{}/runners", id)))
+    }
+
+    /// replace the self-hosted runners in a group
+    pub fn set_runners(&self, id: u64, runner_ids: &[u64]) -> Future<()> {
+        self.github.put_no_response(
+            &self.path(&format!("/{}/runners", id)),
+            json_lit!({ "runners": runner_ids }),
+        )
+    }
+
+    /// move a self-hosted runner into a group
+    pub fn add_runner(&self, id: u64, runner_id: u64) -> Future<()> {
+        self.github.put_no_response(
+            &self.path(&format!("/{}/runners/{}", id, runner_id)),
+            Vec::new(),
+        )
+    }
+
+    /// move a self-hosted runner out of a group and back into the default group
+    pub fn remove_runner(&self, id: u64, runner_id: u64) -> Future<()> {
+        self.github
+            .delete(&self.path(&format!("/{}/runners/{}", id, runner_id)))
+    }
+}
+
+/// Provides access to the actions settings of a repository
+pub struct RepoActions {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl RepoActions {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        RepoActions {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/actions{}", self.owner, self.repo, more)
+    }
+
+    /// get whether the repository may run actions and which actions it may run
+    ///
+    /// See the [github docs](https://developer.github.com/v3/actions/permissions/#get-github-actions-permissions-for-a-repository)
+    /// for more information.
+    pub fn permissions(&self) -> Future<RepoActionsPermissions> {
+        self.github.get(&self.path("/permissions"))
+    }
+
+    /// set whether the repository may run actions and which actions it may run
+    pub fn set_permissions(&self, permissions: &RepoActionsPermissions) -> Future<()> {
+        self.github
+            .put_no_response(&self.path("/permissions"), json!(permissions))
+    }
+
+    /// get the actions allowed when `allowed_actions` is `selected`
+    pub fn selected_actions(&self) -> Future<SelectedActions> {
+        self.github.get(&self.path("/permissions/selected-actions"))
+    }
+
+    /// set the actions allowed when `allowed_actions` is `selected`
+    pub fn set_selected_actions(&self, actions: &SelectedActions) -> Future<()> {
+        self.github
+            .put_no_response(&self.path("/permissions/selected-actions"), json!(actions))
+    }
+
+    /// get the default permissions of the `GITHUB_TOKEN` workflows run with
+    pub fn workflow_permissions(&self) -> Future<WorkflowPermissions> {
+        self.github.get(&self.path("/permissions/workflow"))
+    }
+
+    /// set the default permissions of the `GITHUB_TOKEN` workflows run with
+    pub fn set_workflow_permissions(&self, permissions: &WorkflowPermissions) -> Future<()> {
+        self.github
+            .put_no_response(&self.path("/permissions/workflow"), json!(permissions))
+    }
+
+    /// get which outside contributors' pull request workflows require approval to run
+    pub fn fork_pr_approval(&self) -> Future<ForkPrApproval> {
+        self.github
+            .get(&self.path("/permissions/fork-pr-contributor-approval"))
+    }
+
+    /// set which outside contributors' pull request workflows require approval to run
+    pub fn set_fork_pr_approval(&self, approval: &ForkPrApproval) -> Future<()> {
+        self.github.put_no_response(
+            &self.path("/permissions/fork-pr-contributor-approval"),
+            json!(approval),
+        )
+    }
+
+    /// get a reference to the actions caches of this repository
+    pub fn caches(&self) -> Caches {
+        Caches::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to the secrets of this repository
+    pub fn secrets(&self) -> Secrets {
+        Secrets::for_repo(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to the actions workflows of this repository
+    pub fn workflows(&self) -> Workflows {
+        Workflows::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to the workflow runs of this repository and their jobs
+    pub fn runs(&self) -> WorkflowRuns {
+        WorkflowRuns::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to the artifacts of this repository's workflow runs
+    pub fn artifacts(&self) -> Artifacts {
+        Artifacts::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+}
+
+/// Provides access to the actions workflows of a repository
+///
+/// Workflows are identified by either their id or the file name of their
+/// definition, like `deploy.yml`. See the
+/// [github docs](https://docs.github.com/en/rest/actions/workflows)
+/// for more information.
+pub struct Workflows {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Workflows {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Workflows {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!(
+            "/repos/{}/{}/actions/workflows{}",
+            self.owner, self.repo, more
+        )
+    }
+
+    /// list the workflows of this repository
+    pub fn list(&self, options: &WorkflowListOptions) -> Future<WorkflowList> {
+        let mut uri = vec![self.path("")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get(&uri.join("?"))
+    }
+
+    /// get a workflow by id or file name
+    pub fn get<W>(&self, workflow: W) -> Future<Workflow>
+    where
+        W: fmt::Display,
+    {
+        self.github.get(&self.path(&format!("/{}", workflow)))
+    }
+
+    /// enable a workflow, allowing it to run again
+    pub fn enable<W>(&self, workflow: W) -> Future<()>
+    where
+        W: fmt::Display,
+    {
+        self.github
+            .put_no_response(&self.path(&format!("/{}/enable", workflow)), Vec::new())
+    }
+
+    /// disable a workflow, preventing it from running
+    pub fn disable<W>(&self, workflow: W) -> Future<()>
+    where
+        W: fmt::Display,
+    {
+        self.github
+            .put_no_response(&self.path(&format!("/{}/disable", workflow)), Vec::new())
+    }
+
+    /// trigger a run of a workflow with a `workflow_dispatch` trigger
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/actions/workflows#create-a-workflow-dispatch-event)
+    /// for more information.
+    pub fn dispatch<W>(&self, workflow: W, options: &WorkflowDispatchOptions) -> Future<()>
+    where
+        W: fmt::Display,
+    {
+        self.github.post_no_response(
+            &self.path(&format!("/{}/dispatches", workflow)),
+            json!(options),
+        )
+    }
+}
+
+/// Provides access to the actions caches of a repository
+///
+/// See the [github docs](https://docs.github.com/en/rest/actions/cache)
+/// for more information.
+pub struct Caches {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Caches {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Caches {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/actions{}", self.owner, self.repo, more)
+    }
+
+    /// list the caches of this repository
+    pub fn list(&self, options: &CacheListOptions) -> Future<CacheList> {
+        let mut uri = vec![self.path("/caches")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get(&uri.join("?"))
+    }
+
+    /// get the number and total size of the active caches of this repository
+    pub fn usage(&self) -> Future<CacheUsage> {
+        self.github.get(&self.path("/cache/usage"))
+    }
+
+    /// delete the caches matching a key, optionally only those of a ref like
+    /// `refs/heads/main`, returning the deleted caches
+    pub fn delete_by_key<K>(&self, key: K, reference: Option<&str>) -> Future<CacheList>
+    where
+        K: Into<String>,
+    {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        query.append_pair("key", &key.into());
+        if let Some(reference) = reference {
+            query.append_pair("ref", reference);
+        }
+        self.github.request_entity(
+            Method::DELETE,
+            &(self.github.host.clone()
+                + &format!("{}?{}", self.path("/caches"), query.finish())),
+            None,
+            MediaType::Json,
+            AuthenticationConstraint::Unconstrained,
+        )
+    }
+
+    /// delete a cache by id
+    pub fn delete(&self, id: u64) -> Future<()> {
+        self.github
+            .delete(&self.path(&format!("/caches/{}", id)))
+    }
+}
+
+/// Provides access to the workflow runs of a repository and their jobs
+///
+/// See the [github docs](https://docs.github.com/en/rest/actions/workflow-runs)
+/// for more information.
+pub struct WorkflowRuns {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl WorkflowRuns {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        WorkflowRuns {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/actions{}", self.owner, self.repo, more)
+    }
+
+    /// list the workflow runs of this repository
+    pub fn list(&self, options: &WorkflowRunListOptions) -> Future<WorkflowRunList> {
+        let mut uri = vec![self.path("/runs")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get(&uri.join("?"))
+    }
+
+    /// list the runs of a workflow, identified by id or file name
+    pub fn list_for_workflow<W>(
+        &self,
+        workflow: W,
+        options: &WorkflowRunListOptions,
+    ) -> Future<WorkflowRunList>
+    where
+        W: fmt::Display,
+    {
+        let mut uri = vec![self.path(&format!("/workflows/{}/runs", workflow))];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get(&uri.join("?"))
+    }
+
+    /// get a workflow run
+    pub fn get(&self, id: u64) -> Future<WorkflowRun> {
+        self.github.get(&self.path(&format!("/runs/{}", id)))
+    }
+
+    /// cancel a workflow run
+    pub fn cancel(&self, id: u64) -> Future<()> {
+        self.github
+            .post_no_response(&self.path(&format!("/runs/{}/cancel", id)), Vec::new())
+    }
+
+    /// re-run every job of a workflow run
+    pub fn rerun(&self, id: u64) -> Future<()> {
+        self.github
+            .post_no_response(&self.path(&format!("/runs/{}/rerun", id)), Vec::new())
+    }
+
+    /// re-run the failed jobs of a workflow run and the jobs that depend on them
+    pub fn rerun_failed_jobs(&self, id: u64) -> Future<()> {
+        self.github.post_no_response(
+            &self.path(&format!("/runs/{}/rerun-failed-jobs", id)),
+            Vec::new(),
+        )
+    }
+
+    /// delete a workflow run
+    pub fn delete(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.path(&format!("/runs/{}", id)))
+    }
+
+    /// download the logs of a workflow run as a zip archive
+    pub fn logs(&self, id: u64) -> Stream<Vec<u8>> {
+        self.github
+            .get_bytes(&self.path(&format!("/runs/{}/logs", id)))
+    }
+
+    /// list the jobs of a workflow run
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/actions/workflow-jobs)
+    /// for more information.
+    pub fn jobs(&self, id: u64, options: &JobListOptions) -> Future<JobList> {
+        let mut uri = vec![self.path(&format!("/runs/{}/jobs", id))];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get(&uri.join("?"))
+    }
+
+    /// get a job of a workflow run
+    pub fn job(&self, job_id: u64) -> Future<Job> {
+        self.github.get(&self.path(&format!("/jobs/{}", job_id)))
+    }
+
+    /// download the plain text logs of a job
+    pub fn job_logs(&self, job_id: u64) -> Stream<Vec<u8>> {
+        self.github
+            .get_bytes(&self.path(&format!("/jobs/{}/logs", job_id)))
+    }
+}
+
+/// Provides access to the artifacts of a repository's workflow runs
+///
+/// See the [github docs](https://docs.github.com/en/rest/actions/artifacts)
+/// for more information.
+pub struct Artifacts {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Artifacts {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Artifacts {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/actions{}", self.owner, self.repo, more)
+    }
+
+    /// list the artifacts of this repository
+    pub fn list(&self, options: &ArtifactListOptions) -> Future<ArtifactList> {
+        let mut uri = vec![self.path("/artifacts")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get(&uri.join("?"))
+    }
+
+    /// list the artifacts of a workflow run
+    pub fn list_for_run(&self, run_id: u64, options: &ArtifactListOptions) -> Future<ArtifactList> {
+        let mut uri = vec![self.path(&format!("/runs/{}/artifacts", run_id))];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get(&uri.join("?"))
+    }
+
+    /// get an artifact
+    pub fn get(&self, id: u64) -> Future<Artifact> {
+        self.github.get(&self.path(&format!("/artifacts/{}", id)))
+    }
+
+    /// delete an artifact
+    pub fn delete(&self, id: u64) -> Future<()> {
+        self.github
+            .delete(&self.path(&format!("/artifacts/{}", id)))
+    }
+
+    /// download an artifact as a zip archive
+    pub fn download(&self, id: u64) -> Stream<Vec<u8>> {
+        self.github
+            .get_bytes(&self.path(&format!("/artifacts/{}/zip", id)))
+    }
+}
+
+/// Provides access to actions secrets, and the dependabot secrets of
+/// repositories
+///
+/// Secret values are encrypted with a libsodium sealed box for the public key
+/// of their scope before they leave the client. See the
+/// [github docs](https://docs.github.com/en/rest/actions/secrets) for more information.
+pub struct Secrets {
+    github: Github,
+    prefix: String,
+}
+
+impl Secrets {
+    /// secrets of a repository
+    #[doc(hidden)]
+    pub fn for_repo<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Secrets {
+            github,
+            prefix: format!("/repos/{}/{}/actions", owner.into(), repo.into()),
+        }
+    }
+
+    /// secrets of an organization
+    #[doc(hidden)]
+    pub fn for_org<O>(github: Github, org: O) -> Self
+    where
+        O: Into<String>,
+    {
+        Secrets {
+            github,
+            prefix: format!("/orgs/{}/actions", org.into()),
+        }
+    }
+
+    /// dependabot secrets of a repository
+    #[doc(hidden)]
+    pub fn for_dependabot<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Secrets {
+            github,
+            prefix: format!("/repos/{}/{}/dependabot", owner.into(), repo.into()),
+        }
+    }
+
+    /// secrets of a repository environment
+    #[doc(hidden)]
+    pub fn for_environment<O, R, E>(github: Github, owner: O, repo: R, environment: E) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+        E: Into<String>,
+    {
+        Secrets {
+            github,
+            prefix: format!(
+                "/repos/{}/{}/environments/{}",
+                owner.into(),
+                repo.into(),
+                utf8_percent_encode(&environment.into(), PATH_SEGMENT_ENCODE_SET)
+            ),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("{}/secrets{}", self.prefix, more)
+    }
+
+    /// get the public key secret values must be encrypted with
+    pub fn public_key(&self) -> Future<PublicKey> {
+        self.github.get(&self.path("/public-key"))
+    }
+
+    /// list the names of the secrets in this scope. values are never returned
+    pub fn list(&self) -> Future<SecretList> {
+        self.github.get(&self.path("?per_page=100"))
+    }
+
+    /// get a secret's metadata by name
+    pub fn get<N>(&self, name: N) -> Future<Secret>
+    where
+        N: Into<String>,
+    {
+        self.github
+            .get(&self.path(&format!("/{}", name.into())))
+    }
+
+    /// create or update a secret, fetching the public key of this scope and
+    /// encrypting `value` with it. organization secrets need a visibility,
+    /// see `set_with_options`
+    pub fn set<N, V>(&self, name: N, value: V) -> Future<()>
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.set_with_options(name, value, &SecretOptions::default())
+    }
+
+    /// like `set` but also sets which repositories may use an organization secret
+    pub fn set_with_options<N, V>(&self, name: N, value: V, options: &SecretOptions) -> Future<()>
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        let github = self.github.clone();
+        let uri = self.path(&format!("/{}", name.into()));
+        let value = value.into();
+        let options = options.clone();
+        Box::new(self.public_key().and_then(move |key| {
+            key.encrypt(value.as_bytes())
+                .into_future()
+                .and_then(move |encrypted_value| -> Future<()> {
+                    let secret = SecretValue {
+                        encrypted_value,
+                        key_id: key.key_id,
+                        options,
+                    };
+                    github.put_no_response(&uri, json!(secret))
+                })
+        }))
+    }
+
+    /// create or update a secret with a value already encrypted with the
+    /// public key identified by `key_id`
+    pub fn set_encrypted<N, V, K>(&self, name: N, encrypted_value: V, key_id: K) -> Future<()>
+    where
+        N: Into<String>,
+        V: Into<String>,
+        K: Into<String>,
+    {
+        self.github.put_no_response(
+            &self.path(&format!("/{}", name.into())),
+            json_lit!({ "encrypted_value": encrypted_value.into(), "key_id": key_id.into() }),
+        )
+    }
+
+    /// delete a secret by name
+    pub fn delete<N>(&self, name: N) -> Future<()>
+    where
+        N: Into<String>,
+    {
+        self.github
+            .delete(&self.path(&format!("/{}", name.into())))
+    }
+
+    /// list the repositories which may use an organization secret with
+    /// `selected` visibility
+    pub fn selected_repositories<N>(&self, name: N) -> Future<SecretRepositoryList>
+    where
+        N: Into<String>,
+    {
+        self.github
+            .get(&self.path(&format!("/{}/repositories", name.into())))
+    }
+
+    /// replace the repositories which may use an organization secret with
+    /// `selected` visibility
+    pub fn set_selected_repositories<N>(&self, name: N, repository_ids: &[u64]) -> Future<()>
+    where
+        N: Into<String>,
+    {
+        self.github.put_no_response(
+            &self.path(&format!("/{}/repositories", name.into())),
+            json_lit!({ "selected_repository_ids": repository_ids }),
+        )
+    }
+
+    /// allow a repository to use an organization secret with `selected` visibility
+    pub fn add_selected_repository<N>(&self, name: N, repository_id: u64) -> Future<()>
+    where
+        N: Into<String>,
+    {
+        self.github.put_no_response(
+            &self.path(&format!("/{}/repositories/{}", name.into(), repository_id)),
+            Vec::new(),
+        )
+    }
+
+    /// stop a repository from using an organization secret with `selected` visibility
+    pub fn remove_selected_repository<N>(&self, name: N, repository_id: u64) -> Future<()>
+    where
+        N: Into<String>,
+    {
+        self.github.delete(&self.path(&format!(
+            "/{}/repositories/{}",
+            name.into(),
+            repository_id
+        )))
+    }
+}
+
+/// Provides access to actions configuration variables
+///
+/// See the [github docs](https://docs.github.com/en/rest/actions/variables)
+/// for more information.
+pub struct Variables {
+    github: Github,
+    prefix: String,
+}
+
+impl Variables {
+    /// variables of a repository environment
+    #[doc(hidden)]
+    pub fn for_environment<O, R, E>(github: Github, owner: O, repo: R, environment: E) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+        E: Into<String>,
+    {
+        Variables {
+            github,
+            prefix: format!(
+                "/repos/{}/{}/environments/{}",
+                owner.into(),
+                repo.into(),
+                utf8_percent_encode(&environment.into(), PATH_SEGMENT_ENCODE_SET)
+            ),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("{}/variables{}", self.prefix, more)
+    }
+
+    /// list the variables in this scope
+    pub fn list(&self) -> Future<VariableList> {
+        self.github.get(&self.path("?per_page=30"))
+    }
+
+    /// get a variable by name
+    pub fn get<N>(&self, name: N) -> Future<Variable>
+    where
+        N: Into<String>,
+    {
+        self.github
+            .get(&self.path(&format!("/{}", name.into())))
+    }
+
+    /// create a variable
+    pub fn create<N, V>(&self, name: N, value: V) -> Future<()>
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.github.post_no_response(
+            &self.path(""),
+            json_lit!({ "name": name.into(), "value": value.into() }),
+        )
+    }
+
+    /// update the value of a variable
+    pub fn update<N, V>(&self, name: N, value: V) -> Future<()>
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.github.patch_no_response(
+            &self.path(&format!("/{}", name.into())),
+            json_lit!({ "value": value.into() }),
+        )
+    }
+
+    /// delete a variable by name
+    pub fn delete<N>(&self, name: N) -> Future<()>
+    where
+        N: Into<String>,
+    {
+        self.github
+            .delete(&self.path(&format!("/{}", name.into())))
+    }
+}
+
+// representations
+
+/// The public key secret values of a scope are encrypted with
+#[derive(Debug, Deserialize)]
+pub struct PublicKey {
+    pub key_id: String,
+    /// the base64 encoded key
+    pub key: String,
+}
+
+impl PublicKey {
+    /// encrypt a secret value with a libsodium sealed box for this key,
+    /// returning the base64 encoded `encrypted_value` github expects
+    pub fn encrypt(&self, value: &[u8]) -> Result<String> {
+        let key = base64::decode(&self.key)
+            .map_err(|e| ErrorKind::Msg(format!("invalid public key: {}", e)))?;
+        if key.len() != crypto_box::KEY_SIZE {
+            return Err(ErrorKind::Msg(format!(
+                "invalid public key: expected {} bytes, got {}",
+                crypto_box::KEY_SIZE,
+                key.len()
+            ))
+            .into());
+        }
+        let mut bytes = [0; crypto_box::KEY_SIZE];
+        bytes.copy_from_slice(&key);
+        let sealed = crypto_box::PublicKey::from(bytes)
+            .seal(&mut OsRng, value)
+            .map_err(|_| ErrorKind::Msg("failed to encrypt secret".into()))?;
+        Ok(base64::encode(&sealed))
+    }
+}
+
+/// An actions secret. secret values are never returned
+#[derive(Debug, Deserialize)]
+pub struct Secret {
+    pub name: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    /// which repositories may use an organization secret
+    pub visibility: Option<SecretVisibility>,
+    pub selected_repositories_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SecretList {
+    pub total_count: u64,
+    pub secrets: Vec<Secret>,
+}
+
+/// Which repositories of an organization may use a secret
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretVisibility {
+    All,
+    Private,
+    Selected,
+}
+
+/// Options for setting organization secrets. Repository and environment
+/// secrets ignore them
+#[derive(Debug, Default, Clone, Serialize, PartialEq)]
+pub struct SecretOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<SecretVisibility>,
+    /// the repositories which may use a secret with `selected` visibility
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_repository_ids: Option<Vec<u64>>,
+}
+
+/// The body of a request setting a secret
+#[derive(Serialize)]
+struct SecretValue {
+    encrypted_value: String,
+    key_id: String,
+    #[serde(flatten)]
+    options: SecretOptions,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SecretRepositoryList {
+    pub total_count: u64,
+    pub repositories: Vec<Repo>,
+}
+
+/// An actions configuration variable
+#[derive(Debug, Deserialize)]
+pub struct Variable {
+    pub name: String,
+    pub value: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VariableList {
+    pub total_count: u64,
+    pub variables: Vec<Variable>,
+}
+
+/// Which repositories of an organization may run actions
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EnabledRepositories {
+    All,
+    None,
+    Selected,
+}
+
+/// Which actions may be run
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AllowedActions {
+    All,
+    /// only actions defined in the organization's own repositories
+    LocalOnly,
+    /// only the actions listed in the `SelectedActions`
+    Selected,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OrgActionsPermissions {
+    pub enabled_repositories: EnabledRepositories,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    #[serde(skip_serializing)]
+    pub selected_actions_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RepoActionsPermissions {
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    #[serde(skip_serializing)]
+    pub selected_actions_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EnabledRepositoriesList {
+    pub total_count: u64,
+    pub repositories: Vec<Repo>,
+}
+
+/// The actions allowed when `allowed_actions` is `selected`
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SelectedActions {
+    /// whether actions created by github are allowed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub github_owned_allowed: Option<bool>,
+    /// whether actions from verified marketplace creators are allowed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified_allowed: Option<bool>,
+    /// patterns of allowed actions, like `octo-org/*` or `octo-org/action@v1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patterns_allowed: Option<Vec<String>>,
+}
+
+/// The default access of the `GITHUB_TOKEN`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DefaultWorkflowPermissions {
+    Read,
+    Write,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowPermissions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_workflow_permissions: Option<DefaultWorkflowPermissions>,
+    /// whether workflows may approve pull request reviews
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_approve_pull_request_reviews: Option<bool>,
+}
+
+/// Which outside contributors need approval before their pull request
+/// workflows run
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalPolicy {
+    FirstTimeContributorsNewToGithub,
+    FirstTimeContributors,
+    AllExternalContributors,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ForkPrApproval {
+    pub approval_policy: ApprovalPolicy,
+}
+
+/// Which repositories may use a runner group
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RunnerGroupVisibility {
+    All,
+    Selected,
+    Private,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RunnerGroup {
+    pub id: u64,
+    pub name: String,
+    pub visibility: RunnerGroupVisibility,
+    /// whether this is the group runners are added to by default
+    pub default: bool,
+    pub selected_repositories_url: Option<String>,
+    pub runners_url: String,
+    /// whether the group is inherited from the organization's enterprise
+    pub inherited: bool,
+    pub allows_public_repositories: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RunnerGroupList {
+    pub total_count: u64,
+    pub runner_groups: Vec<RunnerGroup>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RunnerGroupRepositoryList {
+    pub total_count: u64,
+    pub repositories: Vec<Repo>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct RunnerGroupOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<RunnerGroupVisibility>,
+    /// repositories which may use a group with `selected` visibility. only
+    /// used when creating a group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_repository_ids: Option<Vec<u64>>,
+    /// runners to move into the group. only used when creating a group
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runners: Option<Vec<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allows_public_repositories: Option<bool>,
+}
+
+impl RunnerGroupOptions {
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        RunnerGroupOptions {
+            name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// A self-hosted runner
+#[derive(Debug, Deserialize)]
+pub struct Runner {
+    pub id: u64,
+    pub name: String,
+    pub os: String,
+    /// `online` or `offline`
+    pub status: String,
+    pub busy: bool,
+    #[serde(default)]
+    pub labels: Vec<RunnerLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RunnerLabel {
+    pub id: Option<u64>,
+    pub name: String,
+    /// `read-only` for labels github applies or `custom`
+    #[serde(rename = "type")]
+    pub label_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RunnerList {
+    pub total_count: u64,
+    pub runners: Vec<Runner>,
+}
+
+/// An actions cache
+#[derive(Debug, Deserialize)]
+pub struct Cache {
+    pub id: u64,
+    #[serde(rename = "ref")]
+    pub reference: String,
+    pub key: String,
+    pub version: String,
+    pub last_accessed_at: DateTime,
+    pub created_at: DateTime,
+    pub size_in_bytes: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CacheList {
+    pub total_count: u64,
+    pub actions_caches: Vec<Cache>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CacheUsage {
+    pub full_name: String,
+    pub active_caches_size_in_bytes: u64,
+    pub active_caches_count: u64,
+}
+
+/// Describes sorting options for actions caches
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CacheSort {
+    CreatedAt,
+    LastAccessedAt,
+    SizeInBytes,
+}
+
+impl fmt::Display for CacheSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CacheSort::CreatedAt => "created_at",
+            CacheSort::LastAccessedAt => "last_accessed_at",
+            CacheSort::SizeInBytes => "size_in_bytes",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Default)]
+pub struct CacheListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl CacheListOptions {
+    pub fn builder() -> CacheListOptionsBuilder {
+        CacheListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CacheListOptionsBuilder(CacheListOptions);
+
+impl CacheListOptionsBuilder {
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
+    /// only list the caches of a ref, like `refs/heads/main`
+    pub fn reference<R>(&mut self, reference: R) -> &mut Self
+    where
+        R: Into<String>,
+    {
+        self.0.params.insert("ref", reference.into());
+        self
+    }
+
+    /// only list the caches whose keys start with a prefix
+    pub fn key<K>(&mut self, key: K) -> &mut Self
+    where
+        K: Into<String>,
+    {
+        self.0.params.insert("key", key.into());
+        self
+    }
+
+    pub fn sort(&mut self, sort: CacheSort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    pub fn direction(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("direction", direction.to_string());
+        self
+    }
+
+    pub fn build(&self) -> CacheListOptions {
+        CacheListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// The state of a workflow
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowState {
+    Active,
+    Deleted,
+    DisabledFork,
+    DisabledInactivity,
+    DisabledManually,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Workflow {
+    pub id: u64,
+    pub node_id: String,
+    pub name: String,
+    /// the path of the workflow's definition, like `.github/workflows/deploy.yml`
+    pub path: String,
+    pub state: WorkflowState,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub url: String,
+    pub html_url: String,
+    pub badge_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WorkflowList {
+    pub total_count: u64,
+    pub workflows: Vec<Workflow>,
+}
+
+#[derive(Default)]
+pub struct WorkflowListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl WorkflowListOptions {
+    pub fn builder() -> WorkflowListOptionsBuilder {
+        WorkflowListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct WorkflowListOptionsBuilder(WorkflowListOptions);
+
+impl WorkflowListOptionsBuilder {
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> WorkflowListOptions {
+        WorkflowListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// The status of a workflow run, job, or step
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowRunStatus {
+    Requested,
+    Queued,
+    Pending,
+    Waiting,
+    InProgress,
+    Completed,
+}
+
+/// The conclusion of a completed workflow run, job, or step
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowRunConclusion {
+    Success,
+    Failure,
+    Neutral,
+    Cancelled,
+    Skipped,
+    TimedOut,
+    ActionRequired,
+    Stale,
+    StartupFailure,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WorkflowRun {
+    pub id: u64,
+    pub name: Option<String>,
+    pub node_id: String,
+    pub head_branch: Option<String>,
+    pub head_sha: String,
+    pub run_number: u64,
+    pub run_attempt: Option<u64>,
+    /// the event which triggered the run, like `push` or `workflow_dispatch`
+    pub event: String,
+    pub status: Option<WorkflowRunStatus>,
+    pub conclusion: Option<WorkflowRunConclusion>,
+    pub workflow_id: u64,
+    pub actor: Option<User>,
+    pub url: String,
+    pub html_url: String,
+    pub jobs_url: String,
+    pub logs_url: String,
+    pub artifacts_url: String,
+    pub cancel_url: String,
+    pub rerun_url: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub run_started_at: Option<DateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WorkflowRunList {
+    pub total_count: u64,
+    pub workflow_runs: Vec<WorkflowRun>,
+}
+
+#[derive(Default)]
+pub struct WorkflowRunListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl WorkflowRunListOptions {
+    pub fn builder() -> WorkflowRunListOptionsBuilder {
+        WorkflowRunListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct WorkflowRunListOptionsBuilder(WorkflowRunListOptions);
+
+impl WorkflowRunListOptionsBuilder {
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
+    /// only list the runs triggered by a user's login
+    pub fn actor<A>(&mut self, actor: A) -> &mut Self
+    where
+        A: Into<String>,
+    {
+        self.0.params.insert("actor", actor.into());
+        self
+    }
+
+    /// only list the runs of a branch
+    pub fn branch<B>(&mut self, branch: B) -> &mut Self
+    where
+        B: Into<String>,
+    {
+        self.0.params.insert("branch", branch.into());
+        self
+    }
+
+    /// only list the runs triggered by an event, like `push` or `workflow_dispatch`
+    pub fn event<E>(&mut self, event: E) -> &mut Self
+    where
+        E: Into<String>,
+    {
+        self.0.params.insert("event", event.into());
+        self
+    }
+
+    /// only list the runs with a status or conclusion, like `in_progress` or `failure`
+    pub fn status<S>(&mut self, status: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.0.params.insert("status", status.into());
+        self
+    }
+
+    /// only list the runs created in a date range, using github's search
+    /// syntax like `>=2020-01-01` or `2020-01-01..2020-02-01`
+    pub fn created<C>(&mut self, created: C) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        self.0.params.insert("created", created.into());
+        self
+    }
+
+    /// only list the runs of a commit
+    pub fn head_sha<S>(&mut self, head_sha: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.0.params.insert("head_sha", head_sha.into());
+        self
+    }
+
+    /// omit the pull requests of listed runs
+    pub fn exclude_pull_requests(&mut self, exclude: bool) -> &mut Self {
+        self.0
+            .params
+            .insert("exclude_pull_requests", exclude.to_string());
+        self
+    }
+
+    pub fn build(&self) -> WorkflowRunListOptions {
+        WorkflowRunListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Job {
+    pub id: u64,
+    pub run_id: u64,
+    pub run_attempt: Option<u64>,
+    pub node_id: String,
+    pub name: String,
+    pub head_sha: String,
+    pub url: String,
+    pub html_url: Option<String>,
+    pub status: WorkflowRunStatus,
+    pub conclusion: Option<WorkflowRunConclusion>,
+    pub started_at: DateTime,
+    pub completed_at: Option<DateTime>,
+    #[serde(default)]
+    pub steps: Vec<JobStep>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    pub runner_id: Option<u64>,
+    pub runner_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JobStep {
+    pub name: String,
+    pub number: u64,
+    pub status: WorkflowRunStatus,
+    pub conclusion: Option<WorkflowRunConclusion>,
+    pub started_at: Option<DateTime>,
+    pub completed_at: Option<DateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JobList {
+    pub total_count: u64,
+    pub jobs: Vec<Job>,
+}
+
+#[derive(Default)]
+pub struct JobListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl JobListOptions {
+    pub fn builder() -> JobListOptionsBuilder {
+        JobListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct JobListOptionsBuilder(JobListOptions);
+
+impl JobListOptionsBuilder {
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
+    /// list the jobs of every attempt of the run rather than only the latest
+    pub fn all_attempts(&mut self) -> &mut Self {
+        self.0.params.insert("filter", "all".into());
+        self
+    }
+
+    pub fn build(&self) -> JobListOptions {
+        JobListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Artifact {
+    pub id: u64,
+    pub node_id: String,
+    pub name: String,
+    pub size_in_bytes: u64,
+    pub url: String,
+    pub archive_download_url: String,
+    pub expired: bool,
+    pub created_at: Option<DateTime>,
+    pub expires_at: Option<DateTime>,
+    pub updated_at: Option<DateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArtifactList {
+    pub total_count: u64,
+    pub artifacts: Vec<Artifact>,
+}
+
+#[derive(Default)]
+pub struct ArtifactListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl ArtifactListOptions {
+    pub fn builder() -> ArtifactListOptionsBuilder {
+        ArtifactListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ArtifactListOptionsBuilder(ArtifactListOptions);
+
+impl ArtifactListOptionsBuilder {
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
+    /// only list the artifacts with a name
+    pub fn name<N>(&mut self, name: N) -> &mut Self
+    where
+        N: Into<String>,
+    {
+        self.0.params.insert("name", name.into());
+        self
+    }
+
+    pub fn build(&self) -> ArtifactListOptions {
+        ArtifactListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// The value of a `workflow_dispatch` input. `choice` and `environment`
+/// inputs take strings
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum WorkflowInput {
+    String(String),
+    Boolean(bool),
+    Number(serde_json::Number),
+}
+
+impl From<String> for WorkflowInput {
+    fn from(value: String) -> Self {
+        WorkflowInput::String(value)
+    }
+}
+
+impl<'a> From<&'a str> for WorkflowInput {
+    fn from(value: &'a str) -> Self {
+        WorkflowInput::String(value.into())
+    }
+}
+
+impl From<bool> for WorkflowInput {
+    fn from(value: bool) -> Self {
+        WorkflowInput::Boolean(value)
+    }
+}
+
+macro_rules! workflow_input_from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for WorkflowInput {
+                fn from(value: $ty) -> Self {
+                    WorkflowInput::Number(value.into())
+                }
+            }
+        )*
+    };
+}
+
+workflow_input_from_number!(i32, i64, u32, u64);
+
+impl From<f64> for WorkflowInput {
+    /// values json can't represent, like `NaN`, are passed as strings
+    fn from(value: f64) -> Self {
+        serde_json::Number::from_f64(value)
+            .map(WorkflowInput::Number)
+            .unwrap_or_else(|| WorkflowInput::String(value.to_string()))
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct WorkflowDispatchOptions {
+    /// the branch or tag to run the workflow from
+    #[serde(rename = "ref")]
+    reference: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    inputs: BTreeMap<String, WorkflowInput>,
+}
+
+impl WorkflowDispatchOptions {
+    pub fn builder<R>(reference: R) -> WorkflowDispatchOptionsBuilder
+    where
+        R: Into<String>,
+    {
+        WorkflowDispatchOptionsBuilder(WorkflowDispatchOptions {
+            reference: reference.into(),
+            ..Default::default()
+        })
+    }
+}
+
+pub struct WorkflowDispatchOptionsBuilder(WorkflowDispatchOptions);
+
+impl WorkflowDispatchOptionsBuilder {
+    /// set the value of one of the workflow's `workflow_dispatch` inputs
+    pub fn input<N, V>(&mut self, name: N, value: V) -> &mut Self
+    where
+        N: Into<String>,
+        V: Into<WorkflowInput>,
+    {
+        self.0.inputs.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn build(&self) -> WorkflowDispatchOptions {
+        WorkflowDispatchOptions {
+            reference: self.0.reference.clone(),
+            inputs: self.0.inputs.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_secret_value() {
+        let secret = SecretValue {
+            encrypted_value: "c2VjcmV0".into(),
+            key_id: "568250167242549743".into(),
+            options: SecretOptions::default(),
+        };
+        assert_eq!(
+            serde_json::to_string(&secret).unwrap(),
+            r#"{"encrypted_value":"c2VjcmV0","key_id":"568250167242549743"}"#
+        );
+        let secret = SecretValue {
+            options: SecretOptions {
+                visibility: Some(SecretVisibility::Selected),
+                selected_repository_ids: Some(vec![1296269]),
+            },
+            ..secret
+        };
+        assert_eq!(
+            serde_json::to_string(&secret).unwrap(),
+            r#"{"encrypted_value":"c2VjcmV0","key_id":"568250167242549743","visibility":"selected","selected_repository_ids":[1296269]}"#
+        );
+    }
+
+    #[test]
+    fn deserialize_job() {
+        let job: Job = serde_json::from_str(
+            r#"{
+                "id": 399444496,
+                "run_id": 29679449,
+                "node_id": "MDEyOldvcmtmbG93IEpvYjM5OTQ0NDQ5Ng==",
+                "name": "build",
+                "head_sha": "f83a356604ae3c5d03e1b46ef4d1ca77d64a90b0",
+                "url": "https://api.github.com/repos/octo-org/octo-repo/actions/jobs/399444496",
+                "html_url": "https://github.com/octo-org/octo-repo/runs/399444496",
+                "status": "completed",
+                "conclusion": "timed_out",
+                "started_at": "2020-01-20T17:42:40Z",
+                "completed_at": "2020-01-20T17:44:39Z",
+                "steps": [
+                    {
+                        "name": "Set up job",
+                        "status": "in_progress",
+                        "conclusion": null,
+                        "number": 1,
+                        "started_at": "2020-01-20T09:42:40.000-08:00",
+                        "completed_at": null
+                    }
+                ],
+                "labels": ["ubuntu-latest"]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(job.status, WorkflowRunStatus::Completed);
+        assert_eq!(job.conclusion, Some(WorkflowRunConclusion::TimedOut));
+        assert_eq!(job.steps[0].status, WorkflowRunStatus::InProgress);
+        assert_eq!(job.runner_name, None);
+    }
+
+    #[test]
+    fn workflow_dispatch_options() {
+        let options = WorkflowDispatchOptions::builder("main")
+            .input("environment", "production")
+            .input("dry_run", true)
+            .input("replicas", 3)
+            .build();
+        assert_eq!(
+            serde_json::to_string(&options).unwrap(),
+            r#"{"ref":"main","inputs":{"dry_run":true,"environment":"production","replicas":3}}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&WorkflowDispatchOptions::builder("v1.0.0").build()).unwrap(),
+            r#"{"ref":"v1.0.0"}"#
+        );
+    }
+
+    #[test]
+    fn encrypt_secret() {
+        let secret = crypto_box::SecretKey::from([7; crypto_box::KEY_SIZE]);
+        let key = PublicKey {
+            key_id: "568250167242549743".into(),
+            key: base64::encode(secret.public_key().as_bytes()),
+        };
+        let encrypted = base64::decode(&key.encrypt(b"hunter2").unwrap()).unwrap();
+        assert_eq!(secret.unseal(&encrypted).unwrap(), b"hunter2".to_vec());
+    }
+
+    #[test]
+    fn encrypt_secret_with_invalid_key() {
+        let key = PublicKey {
+            key_id: "568250167242549743".into(),
+            key: base64::encode(b"too short"),
+        };
+        assert!(key.encrypt(b"hunter2").is_err());
+    }
+
+    #[test]
+    fn permissions_round_trip() {
+        let permissions: OrgActionsPermissions = serde_json::from_str(
+            r#"{
+                "enabled_repositories": "selected",
+                "allowed_actions": "local_only",
+                "selected_actions_url": "https://api.github.com/orgs/o/actions/permissions/selected-actions"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            permissions.enabled_repositories,
+            EnabledRepositories::Selected
+        );
+        assert_eq!(permissions.allowed_actions, Some(AllowedActions::LocalOnly));
+        assert_eq!(
+            serde_json::to_string(&permissions).unwrap(),
+            r#"{"enabled_repositories":"selected","allowed_actions":"local_only"}"#
+        )
+    }
+}
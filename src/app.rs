@@ -1,6 +1,19 @@
-//! Labels interface
+//! Apps interface
+use std::collections::HashMap;
+
+#[cfg(feature = "hooks")]
+use futures::Future as StdFuture;
+use http::Method;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 
+#[cfg(feature = "hooks")]
+use crate::hooks::{HookDelivery, HookDeliveryDetail, HookDeliveryListOptions, WebHookConfig};
+use crate::repositories::Repo;
+use crate::users::User;
+#[cfg(feature = "hooks")]
+use crate::{Error, ErrorKind};
+use crate::DateTime;
 use self::super::{AuthenticationConstraint, Future, Github, MediaType};
 
 pub struct App {
@@ -10,13 +23,76 @@ pub struct App {
 impl App {
     #[doc(hidden)]
     pub(crate) fn new(github: Github) -> Self {
-        App { github: github }
+        App { github }
     }
 
     fn path(&self, more: &str) -> String {
         format!("/app{}", more)
     }
 
+    /// get the app the credentials authenticate as
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/apps/apps#get-the-authenticated-app)
+    /// for more information.
+    pub fn get(&self) -> Future<GithubApp> {
+        self.jwt(Method::GET, "", None)
+    }
+
+    /// get a public app by its slug, like `dependabot`
+    pub fn get_by_slug<S>(&self, slug: S) -> Future<GithubApp>
+    where
+        S: Into<String>,
+    {
+        self.github.get(&format!("/apps/{}", slug.into()))
+    }
+
+    /// list the installations of the app
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/apps/apps#list-installations-for-the-authenticated-app)
+    /// for more information.
+    pub fn installations(&self) -> Future<Vec<Installation>> {
+        self.jwt(Method::GET, "/installations?per_page=100", None)
+    }
+
+    /// get an installation of the app by its id
+    pub fn installation(&self, installation_id: u64) -> Future<Installation> {
+        self.jwt(
+            Method::GET,
+            &format!("/installations/{}", installation_id),
+            None,
+        )
+    }
+
+    /// list the repositories an installation can access. requires credentials
+    /// authenticating as the installation, like `Credentials::InstallationToken`
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/apps/installations#list-repositories-accessible-to-the-app-installation)
+    /// for more information.
+    pub fn installation_repositories(&self) -> Future<InstallationRepositories> {
+        self.github.get("/installation/repositories?per_page=100")
+    }
+
+    /// list the installations of apps the authenticated user can access,
+    /// with a user access token
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/apps/installations#list-app-installations-accessible-to-the-user-access-token)
+    /// for more information.
+    pub fn user_installations(&self) -> Future<UserInstallations> {
+        self.github.get("/user/installations?per_page=100")
+    }
+
+    /// list the repositories of an installation the authenticated user can
+    /// access, with a user access token
+    pub fn user_installation_repositories(
+        &self,
+        installation_id: u64,
+    ) -> Future<InstallationRepositories> {
+        self.github.get(&format!(
+            "/user/installations/{}/repositories?per_page=100",
+            installation_id
+        ))
+    }
+
     pub fn make_access_token(&self, installation_id: u64) -> Future<AccessToken> {
         self.github.post_media::<AccessToken>(
             &self.path(&format!("/installations/{}/access_tokens", installation_id)),
@@ -35,6 +111,82 @@ impl App {
             MediaType::Preview("machine-man"),
         )
     }
+
+    /// get the configuration of the app's webhook
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/apps/webhooks#get-a-webhook-configuration-for-an-app)
+    /// for more information.
+    #[cfg(feature = "hooks")]
+    pub fn hook_config(&self) -> Future<WebHookConfig> {
+        self.jwt(Method::GET, "/hook/config", None)
+    }
+
+    /// update the configuration of the app's webhook
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/apps/webhooks#update-a-webhook-configuration-for-an-app)
+    /// for more information.
+    #[cfg(feature = "hooks")]
+    pub fn update_hook_config(&self, config: &WebHookConfig) -> Future<WebHookConfig> {
+        self.jwt(Method::PATCH, "/hook/config", Some(json!(config)))
+    }
+
+    /// list deliveries of the app's webhook
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/apps/webhooks#list-deliveries-for-an-app-webhook)
+    /// for more information.
+    #[cfg(feature = "hooks")]
+    pub fn hook_deliveries(
+        &self,
+        options: &HookDeliveryListOptions,
+    ) -> Future<Vec<HookDelivery>> {
+        let mut uri = vec!["/hook/deliveries".to_string()];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.jwt(Method::GET, &uri.join("?"), None)
+    }
+
+    /// get a delivery of the app's webhook, including its request and response
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/apps/webhooks#get-a-delivery-for-an-app-webhook)
+    /// for more information.
+    #[cfg(feature = "hooks")]
+    pub fn hook_delivery(&self, id: u64) -> Future<HookDeliveryDetail> {
+        self.jwt(Method::GET, &format!("/hook/deliveries/{}", id), None)
+    }
+
+    /// redeliver a delivery of the app's webhook
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/apps/webhooks#redeliver-a-delivery-for-an-app-webhook)
+    /// for more information.
+    #[cfg(feature = "hooks")]
+    pub fn redeliver_hook_delivery(&self, id: u64) -> Future<()> {
+        Box::new(
+            self.jwt::<()>(
+                Method::POST,
+                &format!("/hook/deliveries/{}/attempts", id),
+                Some(Vec::new()),
+            )
+            .or_else(|err| match err {
+                Error(ErrorKind::Codec(_), _) => Ok(()),
+                err => Err(err),
+            }),
+        )
+    }
+
+    /// app endpoints must be authenticated as the app itself
+    fn jwt<D>(&self, method: Method, more: &str, body: Option<Vec<u8>>) -> Future<D>
+    where
+        D: DeserializeOwned + 'static + Send,
+    {
+        self.github.request_entity(
+            method,
+            &(self.github.host.clone() + &self.path(more)),
+            body,
+            MediaType::Json,
+            AuthenticationConstraint::JWT,
+        )
+    }
 }
 
 // representations
@@ -42,22 +194,63 @@ impl App {
 #[derive(Debug, Deserialize)]
 pub struct AccessToken {
     pub token: String,
-    pub expires_at: String,
+    pub expires_at: DateTime,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct Installation {
     pub id: u64,
-    // account: Account
+    /// the user or organization the app is installed on
+    pub account: Option<User>,
     pub access_tokens_url: String,
     pub repositories_url: String,
     pub html_url: String,
     pub app_id: i32,
     pub target_id: i32,
     pub target_type: String,
-    // permissions: Permissions
+    /// the access the installation was granted, like `issues: write`
+    #[serde(default)]
+    pub permissions: HashMap<String, String>,
     pub events: Vec<String>,
-    // created_at, updated_at
+    pub created_at: Option<DateTime>,
+    pub updated_at: Option<DateTime>,
+    /// when the installation was suspended, if it is
+    pub suspended_at: Option<DateTime>,
     pub single_file_name: Option<String>,
     pub repository_selection: String,
 }
+
+/// A github app
+#[derive(Debug, Deserialize)]
+pub struct GithubApp {
+    pub id: u64,
+    pub slug: Option<String>,
+    pub node_id: String,
+    pub owner: Option<User>,
+    pub name: String,
+    pub description: Option<String>,
+    pub external_url: String,
+    pub html_url: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    /// the access the app asks installations for, like `issues: write`
+    #[serde(default)]
+    pub permissions: HashMap<String, String>,
+    /// the webhook events the app subscribes to
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// only included when authenticated as the app
+    pub installations_count: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InstallationRepositories {
+    pub total_count: u64,
+    pub repositories: Vec<Repo>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserInstallations {
+    pub total_count: u64,
+    pub installations: Vec<Installation>,
+}
@@ -0,0 +1,74 @@
+//! Repo assignees interface
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/issues/assignees/)
+use futures::Future as StdFuture;
+use http::StatusCode;
+
+use crate::users::User;
+use crate::{Error, ErrorKind, Future, Github, Stream};
+
+/// Provides access to the users issues of a repository may be assigned to
+pub struct Assignees {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Assignees {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Assignees {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/assignees{}", self.owner, self.repo, more)
+    }
+
+    /// list the users available for assignment to issues of this repository
+    ///
+    /// See the [github docs](https://developer.github.com/v3/issues/assignees/#list-assignees)
+    /// for more information.
+    pub fn list(&self) -> Future<Vec<User>> {
+        self.github.get(&self.path(""))
+    }
+
+    /// provides a stream over all pages of users available for assignment
+    pub fn iter(&self) -> Stream<User> {
+        self.github.get_stream(&self.path(""))
+    }
+
+    /// check whether a user may be assigned to issues of this repository
+    ///
+    /// See the [github docs](https://developer.github.com/v3/issues/assignees/#check-assignee)
+    /// for more information.
+    pub fn check<U>(&self, user: U) -> Future<bool>
+    where
+        U: Into<String>,
+    {
+        Box::new(
+            self.github
+                .get::<()>(&self.path(&format!("/{}", user.into())))
+                .map(|_| true)
+                .or_else(|err| match err {
+                    Error(
+                        ErrorKind::Fault {
+                            code: StatusCode::NOT_FOUND,
+                            ..
+                        },
+                        _,
+                    ) => Ok(false),
+                    Error(ErrorKind::Codec(_), _) => Ok(true),
+                    otherwise => Err(otherwise),
+                }),
+        )
+    }
+}
@@ -0,0 +1,80 @@
+//! Repo autolinks interface
+//!
+//! Autolinks turn references to external resources, like `JIRA-123`,
+//! into links. See the [github docs](https://developer.github.com/v3/repos/autolinks/)
+//! for more information.
+use serde::{Deserialize, Serialize};
+
+use crate::{Future, Github};
+
+pub struct Autolinks {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Autolinks {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Autolinks {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/autolinks{}", self.owner, self.repo, more)
+    }
+
+    pub fn create(&self, autolink: &AutolinkOptions) -> Future<Autolink> {
+        self.github.post(&self.path(""), json!(autolink))
+    }
+
+    pub fn list(&self) -> Future<Vec<Autolink>> {
+        self.github.get(&self.path(""))
+    }
+
+    pub fn get(&self, id: u64) -> Future<Autolink> {
+        self.github.get(&self.path(&format!("/{}", id)))
+    }
+
+    pub fn delete(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.path(&format!("/{}", id)))
+    }
+}
+
+// representations
+
+#[derive(Debug, Deserialize)]
+pub struct Autolink {
+    pub id: u64,
+    pub key_prefix: String,
+    pub url_template: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutolinkOptions {
+    /// prefix of the references to link, like `JIRA-`
+    pub key_prefix: String,
+    /// url the references link to, which must contain `<num>` for the
+    /// reference number, like `https://jira.example.com/browse/JIRA-<num>`
+    pub url_template: String,
+}
+
+impl AutolinkOptions {
+    pub fn new<K, U>(key_prefix: K, url_template: U) -> AutolinkOptions
+    where
+        K: Into<String>,
+        U: Into<String>,
+    {
+        AutolinkOptions {
+            key_prefix: key_prefix.into(),
+            url_template: url_template.into(),
+        }
+    }
+}
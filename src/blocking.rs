@@ -0,0 +1,343 @@
+//! Blocking interface
+//!
+//! A facade over the asynchronous client for programs that don't run an
+//! executor of their own, like small command line tools and build scripts.
+//! Repositories, their releases, and their issues have blocking interfaces
+//! of their own, which resolve results rather than futures. Listings have an
+//! `all` counterpart collecting the items of every page. Other requests can
+//! be built with the asynchronous interface and run to completion with
+//! `Github#run` and `Github#collect`, on a runtime the facade owns.
+//!
+//! ```no_run
+//! use hubcaps::blocking::Github;
+//! use hubcaps::Credentials;
+//!
+//! # fn main() -> hubcaps::Result<()> {
+//! let github = Github::new(
+//!     "my-cool-user-agent/0.1.0",
+//!     Credentials::Token("personal-access-token".into()),
+//! )?;
+//! for release in github.repo("octokit", "rest.js").releases().list()? {
+//!     println!("{}", release.tag_name);
+//! }
+//! let languages = github.run(|github| github.repo("octokit", "rest.js").languages())?;
+//! # Ok(())
+//! # }
+//! ```
+use std::sync::{Arc, Mutex};
+
+use futures::Stream as StdStream;
+use tokio::runtime::Runtime;
+
+#[cfg(feature = "issues")]
+use crate::issues::{Issue, IssueListOptions, IssueOptions, IssueState, LockReason};
+#[cfg(feature = "releases")]
+use crate::releases::{Release, ReleaseOptions};
+use crate::repositories::{Repo, RepoEditOptions};
+use crate::{Credentials, Future, Result, Stream};
+
+/// A client which blocks on the requests it runs
+pub struct Github {
+    github: crate::Github,
+    runtime: Arc<Mutex<Runtime>>,
+}
+
+impl Github {
+    pub fn new<A, C>(agent: A, credentials: C) -> Result<Self>
+    where
+        A: Into<String>,
+        C: Into<Option<Credentials>>,
+    {
+        Self::wrap(crate::Github::new(agent, credentials)?)
+    }
+
+    /// Create a client for the api of a Github Enterprise Server instance,
+    /// like `https://github.example.com/api/v3`
+    pub fn host<H, A, C>(host: H, agent: A, credentials: C) -> Result<Self>
+    where
+        H: Into<String>,
+        A: Into<String>,
+        C: Into<Option<Credentials>>,
+    {
+        Self::wrap(crate::Github::host(host, agent, credentials)?)
+    }
+
+    /// Block on the requests of an existing asynchronous client, like one
+    /// created with `Github::custom`
+    pub fn wrap(github: crate::Github) -> Result<Self> {
+        Ok(Self {
+            github,
+            runtime: Arc::new(Mutex::new(Runtime::new()?)),
+        })
+    }
+
+    /// the asynchronous client requests are built with
+    pub fn get_ref(&self) -> &crate::Github {
+        &self.github
+    }
+
+    /// Return a reference to a repository
+    pub fn repo<O, R>(&self, owner: O, repo: R) -> Repository
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Repository {
+            repository: self.github.repo(owner, repo),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Run the request `build` builds to completion
+    pub fn run<F, T>(&self, build: F) -> Result<T>
+    where
+        F: FnOnce(&crate::Github) -> Future<T>,
+        T: Send + 'static,
+    {
+        block_on(&self.runtime, build(&self.github))
+    }
+
+    /// Run a paginated request `build` builds, collecting the items of all
+    /// of its pages
+    pub fn collect<F, T>(&self, build: F) -> Result<Vec<T>>
+    where
+        F: FnOnce(&crate::Github) -> Stream<T>,
+        T: Send + 'static,
+    {
+        collect(&self.runtime, build(&self.github))
+    }
+}
+
+fn block_on<T>(runtime: &Mutex<Runtime>, request: Future<T>) -> Result<T>
+where
+    T: Send + 'static,
+{
+    runtime
+        .lock()
+        .expect("blocking runtime lock poisoned")
+        .block_on(request)
+}
+
+fn collect<T>(runtime: &Mutex<Runtime>, items: Stream<T>) -> Result<Vec<T>>
+where
+    T: Send + 'static,
+{
+    block_on(runtime, Box::new(items.collect()))
+}
+
+/// A blocking reference to a repository
+pub struct Repository {
+    repository: crate::repositories::Repository,
+    runtime: Arc<Mutex<Runtime>>,
+}
+
+impl Repository {
+    /// the asynchronous repository reference requests are built with
+    pub fn get_ref(&self) -> &crate::repositories::Repository {
+        &self.repository
+    }
+
+    /// Get the repository
+    pub fn get(&self) -> Result<Repo> {
+        block_on(&self.runtime, self.repository.get())
+    }
+
+    /// Edit the repository
+    pub fn edit(&self, options: &RepoEditOptions) -> Result<Repo> {
+        block_on(&self.runtime, self.repository.edit(options))
+    }
+
+    /// Delete the repository
+    pub fn delete(&self) -> Result<()> {
+        block_on(&self.runtime, self.repository.delete())
+    }
+
+    /// Get a blocking reference to the repository's issues
+    #[cfg(feature = "issues")]
+    pub fn issues(&self) -> Issues {
+        Issues {
+            issues: self.repository.issues(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Get a blocking reference to the repository's releases
+    #[cfg(feature = "releases")]
+    pub fn releases(&self) -> Releases {
+        Releases {
+            releases: self.repository.releases(),
+            runtime: self.runtime.clone(),
+        }
+    }
+}
+
+/// Blocking access to the releases of a repository
+#[cfg(feature = "releases")]
+pub struct Releases {
+    releases: crate::releases::Releases,
+    runtime: Arc<Mutex<Runtime>>,
+}
+
+#[cfg(feature = "releases")]
+impl Releases {
+    pub fn create(&self, rel: &ReleaseOptions) -> Result<Release> {
+        block_on(&self.runtime, self.releases.create(rel))
+    }
+
+    pub fn edit(&self, id: u64, rel: &ReleaseOptions) -> Result<Release> {
+        block_on(&self.runtime, self.releases.edit(id, rel))
+    }
+
+    pub fn delete(&self, id: u64) -> Result<()> {
+        block_on(&self.runtime, self.releases.delete(id))
+    }
+
+    /// list the first page of releases
+    pub fn list(&self) -> Result<Vec<Release>> {
+        block_on(&self.runtime, self.releases.list())
+    }
+
+    /// list the releases of every page
+    pub fn all(&self) -> Result<Vec<Release>> {
+        collect(&self.runtime, self.releases.iter())
+    }
+
+    pub fn get(&self, id: u64) -> Result<Release> {
+        block_on(&self.runtime, self.releases.get(id).get())
+    }
+
+    pub fn latest(&self) -> Result<Release> {
+        block_on(&self.runtime, self.releases.latest())
+    }
+
+    pub fn by_tag<S>(&self, tag_name: S) -> Result<Release>
+    where
+        S: Into<String>,
+    {
+        block_on(&self.runtime, self.releases.by_tag(tag_name))
+    }
+}
+
+/// Blocking access to the issues of a repository
+#[cfg(feature = "issues")]
+pub struct Issues {
+    issues: crate::issues::Issues,
+    runtime: Arc<Mutex<Runtime>>,
+}
+
+#[cfg(feature = "issues")]
+impl Issues {
+    /// Get a blocking reference to an issue by its number
+    pub fn get(&self, number: u64) -> IssueRef {
+        IssueRef {
+            issue: self.issues.get(number),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    pub fn create(&self, is: &IssueOptions) -> Result<Issue> {
+        block_on(&self.runtime, self.issues.create(is))
+    }
+
+    /// list the first page of issues matching `options`
+    pub fn list(&self, options: &IssueListOptions) -> Result<Vec<Issue>> {
+        block_on(&self.runtime, self.issues.list(options))
+    }
+
+    /// list the issues matching `options` of every page
+    pub fn all(&self, options: &IssueListOptions) -> Result<Vec<Issue>> {
+        collect(&self.runtime, self.issues.iter(options))
+    }
+}
+
+/// A blocking reference to an issue
+#[cfg(feature = "issues")]
+pub struct IssueRef {
+    issue: crate::issues::IssueRef,
+    runtime: Arc<Mutex<Runtime>>,
+}
+
+#[cfg(feature = "issues")]
+impl IssueRef {
+    /// the asynchronous issue reference requests are built with, for
+    /// reaching its labels, assignees, and comments
+    pub fn get_ref(&self) -> &crate::issues::IssueRef {
+        &self.issue
+    }
+
+    pub fn get(&self) -> Result<Issue> {
+        block_on(&self.runtime, self.issue.get())
+    }
+
+    pub fn edit(&self, is: &IssueOptions) -> Result<Issue> {
+        block_on(&self.runtime, self.issue.edit(is))
+    }
+
+    /// Set the milestone of this issue by its number, or clear it with `None`
+    pub fn set_milestone(&self, milestone: Option<u64>) -> Result<Issue> {
+        block_on(&self.runtime, self.issue.set_milestone(milestone))
+    }
+
+    /// Open or close this issue
+    pub fn set_state(&self, state: IssueState) -> Result<Issue> {
+        block_on(&self.runtime, self.issue.set_state(state))
+    }
+
+    /// Lock this issue's conversation, optionally giving a reason
+    pub fn lock<R>(&self, reason: R) -> Result<()>
+    where
+        R: Into<Option<LockReason>>,
+    {
+        block_on(&self.runtime, self.issue.lock(reason))
+    }
+
+    pub fn unlock(&self) -> Result<()> {
+        block_on(&self.runtime, self.issue.unlock())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{future, stream};
+    use http::{Method, StatusCode};
+
+    use super::*;
+    use crate::transport::MockTransport;
+
+    #[test]
+    fn runs_requests_to_completion() {
+        let github = Github::new("agent", None).unwrap();
+        assert_eq!(github.run(|_| Box::new(future::ok(1))).unwrap(), 1);
+        assert_eq!(
+            github
+                .collect(|_| Box::new(stream::iter_ok(vec![1, 2, 3])))
+                .unwrap(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "releases")]
+    fn wraps_interfaces() {
+        let mut async_github = crate::Github::new("agent", None).unwrap();
+        async_github.set_transport(
+            MockTransport::new()
+                .respond(
+                    Method::DELETE,
+                    "/repos/softprops/hubcaps/releases/1",
+                    StatusCode::NO_CONTENT,
+                    "",
+                )
+                .respond(
+                    Method::DELETE,
+                    "/repos/softprops/hubcaps/releases/2",
+                    StatusCode::NOT_FOUND,
+                    r#"{"message": "Not Found"}"#,
+                ),
+        );
+        let github = Github::wrap(async_github).unwrap();
+        let releases = github.repo("softprops", "hubcaps").releases();
+        assert!(releases.delete(1).is_ok());
+        assert!(releases.delete(2).is_err());
+    }
+}
@@ -4,9 +4,11 @@
 //! [Github docs](https://developer.github.com/v3/repos/branches/)
 use serde::{Deserialize, Serialize};
 
+use crate::teams::Team;
+use crate::users::User;
 use crate::{Future, Github, Stream};
 
-/// reference to gists associated with a github user
+/// reference to branches associated with a github repo
 pub struct Branches {
     github: Github,
     owner: String,
@@ -58,6 +60,21 @@ impl Branches {
         ))
     }
 
+    /// renames a branch, updating pull requests and branch protection rules
+    /// that target it
+    ///
+    /// https://docs.github.com/en/rest/branches/branches#rename-a-branch
+    pub fn rename<B, N>(&self, branch: B, new_name: N) -> Future<Branch>
+    where
+        B: Into<String>,
+        N: Into<String>,
+    {
+        self.github.post(
+            &self.path(&branch.into(), "/rename"),
+            json_lit!({ "new_name": new_name.into() }),
+        )
+    }
+
     /// update branch production for a given branch
     ///
     /// https://developer.github.com/v3/repos/branches/#update-branch-protection
@@ -65,14 +82,129 @@ impl Branches {
     where
         B: Into<String>,
     {
-        self.github.put(
-            &format!(
-                "/repos/{owner}/{repo}/branches/{branch}/protection",
-                owner = self.owner,
-                repo = self.repo,
-                branch = branch.into()
-            ),
-            json!(pro),
+        self.github
+            .put(&self.path(&branch.into(), "/protection"), json!(pro))
+    }
+
+    /// gets the protection of a given branch
+    ///
+    /// https://developer.github.com/v3/repos/branches/#get-branch-protection
+    pub fn get_protection<B>(&self, branch: B) -> Future<ProtectionState>
+    where
+        B: Into<String>,
+    {
+        self.github.get(&self.path(&branch.into(), "/protection"))
+    }
+
+    /// removes all protection from a given branch
+    ///
+    /// https://developer.github.com/v3/repos/branches/#remove-branch-protection
+    pub fn remove_protection<B>(&self, branch: B) -> Future<()>
+    where
+        B: Into<String>,
+    {
+        self.github
+            .delete(&self.path(&branch.into(), "/protection"))
+    }
+
+    /// updates the status checks required to pass before merging into a
+    /// protected branch
+    ///
+    /// https://developer.github.com/v3/repos/branches/#update-required-status-checks-of-protected-branch
+    pub fn update_required_status_checks<B>(
+        &self,
+        branch: B,
+        checks: &StatusChecks,
+    ) -> Future<StatusChecks>
+    where
+        B: Into<String>,
+    {
+        self.github.patch(
+            &self.path(&branch.into(), "/protection/required_status_checks"),
+            json!(checks),
+        )
+    }
+
+    /// stops requiring status checks to pass before merging into a protected
+    /// branch
+    pub fn remove_required_status_checks<B>(&self, branch: B) -> Future<()>
+    where
+        B: Into<String>,
+    {
+        self.github.delete(&self.path(
+            &branch.into(),
+            "/protection/required_status_checks",
+        ))
+    }
+
+    /// updates the reviews required before merging into a protected branch
+    ///
+    /// https://developer.github.com/v3/repos/branches/#update-pull-request-review-enforcement-of-protected-branch
+    pub fn update_required_reviews<B>(
+        &self,
+        branch: B,
+        reviews: &RequiredPullRequestReviews,
+    ) -> Future<ReviewProtection>
+    where
+        B: Into<String>,
+    {
+        self.github.patch(
+            &self.path(&branch.into(), "/protection/required_pull_request_reviews"),
+            json!(reviews),
+        )
+    }
+
+    /// stops requiring reviews before merging into a protected branch
+    pub fn remove_required_reviews<B>(&self, branch: B) -> Future<()>
+    where
+        B: Into<String>,
+    {
+        self.github.delete(&self.path(
+            &branch.into(),
+            "/protection/required_pull_request_reviews",
+        ))
+    }
+
+    /// applies the protection of a branch to administrators too
+    ///
+    /// https://developer.github.com/v3/repos/branches/#add-admin-enforcement-of-protected-branch
+    pub fn enforce_admins<B>(&self, branch: B) -> Future<EnforceAdmins>
+    where
+        B: Into<String>,
+    {
+        self.github.post(
+            &self.path(&branch.into(), "/protection/enforce_admins"),
+            Vec::new(),
+        )
+    }
+
+    /// lets administrators bypass the protection of a branch
+    pub fn remove_enforce_admins<B>(&self, branch: B) -> Future<()>
+    where
+        B: Into<String>,
+    {
+        self.github
+            .delete(&self.path(&branch.into(), "/protection/enforce_admins"))
+    }
+
+    /// lets anyone with push access push to a protected branch
+    ///
+    /// https://developer.github.com/v3/repos/branches/#remove-user-team-and-app-restrictions-of-protected-branch
+    pub fn remove_restrictions<B>(&self, branch: B) -> Future<()>
+    where
+        B: Into<String>,
+    {
+        self.github
+            .delete(&self.path(&branch.into(), "/protection/restrictions"))
+    }
+
+    fn path(&self, branch: &str, more: &str) -> String {
+        format!(
+            "/repos/{owner}/{repo}/branches/{branch}{more}",
+            owner = self.owner,
+            repo = self.repo,
+            branch = branch,
+            more = more
         )
     }
 }
@@ -87,12 +219,14 @@ pub struct Branch {
     // todo: commit ref
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+/// The protection currently applied to a branch. Protections that are not
+/// enabled are `None`
+#[derive(Debug, Deserialize)]
 pub struct ProtectionState {
     pub required_status_checks: Option<StatusChecks>,
     pub enforce_admins: Option<EnforceAdmins>,
-    //pub required_pull_request_reviews: Option<RequiredPullRequestReviews>,
-    //pub restrictions: Option<Restrictions>,
+    pub required_pull_request_reviews: Option<ReviewProtection>,
+    pub restrictions: Option<RestrictionsState>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -101,7 +235,25 @@ pub struct EnforceAdmins {
     pub enabled: bool,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+/// The review requirements currently applied to a branch
+#[derive(Debug, Deserialize)]
+pub struct ReviewProtection {
+    pub dismissal_restrictions: Option<RestrictionsState>,
+    pub dismiss_stale_reviews: bool,
+    pub require_code_owner_reviews: bool,
+    pub required_approving_review_count: Option<u32>,
+}
+
+/// The users and teams currently allowed to push to a branch
+#[derive(Debug, Deserialize)]
+pub struct RestrictionsState {
+    pub users: Vec<User>,
+    pub teams: Vec<Team>,
+}
+
+/// The protection to apply to a branch. Every protection is replaced, so
+/// protections left unset are removed
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct Protection {
     pub required_status_checks: Option<StatusChecks>,
     pub enforce_admins: bool,
@@ -109,21 +261,126 @@ pub struct Protection {
     pub restrictions: Option<Restrictions>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+impl Protection {
+    pub fn builder() -> ProtectionBuilder {
+        ProtectionBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct ProtectionBuilder(Protection);
+
+impl ProtectionBuilder {
+    /// require status checks with the given `contexts` to pass before
+    /// merging. when `strict`, branches must also be up to date with the
+    /// protected branch
+    pub fn required_status_checks<C>(&mut self, strict: bool, contexts: Vec<C>) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        self.0.required_status_checks = Some(StatusChecks {
+            strict,
+            contexts: contexts.into_iter().map(|c| c.into()).collect(),
+        });
+        self
+    }
+
+    /// apply the protection to administrators too
+    pub fn enforce_admins(&mut self, enforce: bool) -> &mut Self {
+        self.0.enforce_admins = enforce;
+        self
+    }
+
+    pub fn required_reviews(&mut self, reviews: RequiredPullRequestReviews) -> &mut Self {
+        self.0.required_pull_request_reviews = Some(reviews);
+        self
+    }
+
+    /// only let the given users and team slugs push to the branch. only
+    /// available for organization repositories
+    pub fn restrictions<U, T>(&mut self, users: Vec<U>, teams: Vec<T>) -> &mut Self
+    where
+        U: Into<String>,
+        T: Into<String>,
+    {
+        self.0.restrictions = Some(Restrictions::new(users, teams));
+        self
+    }
+
+    pub fn build(&self) -> Protection {
+        Protection {
+            required_status_checks: self.0.required_status_checks.clone(),
+            enforce_admins: self.0.enforce_admins,
+            required_pull_request_reviews: self.0.required_pull_request_reviews.clone(),
+            restrictions: self.0.restrictions.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Restrictions {
     pub users: Vec<String>,
     pub teams: Vec<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+impl Restrictions {
+    pub fn new<U, T>(users: Vec<U>, teams: Vec<T>) -> Self
+    where
+        U: Into<String>,
+        T: Into<String>,
+    {
+        Restrictions {
+            users: users.into_iter().map(|u| u.into()).collect(),
+            teams: teams.into_iter().map(|t| t.into()).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct RequiredPullRequestReviews {
-    pub dismissal_restrictions: Restrictions,
+    /// the users and team slugs who may dismiss reviews. only available for
+    /// organization repositories
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismissal_restrictions: Option<Restrictions>,
     pub dismiss_stale_reviews: bool,
     pub require_code_owner_reviews: bool,
+    /// between 1 and 6
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_approving_review_count: Option<u32>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct StatusChecks {
     pub strict: bool,
     pub contexts: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_protection() {
+        let protection = Protection::builder()
+            .required_status_checks(true, vec!["ci/build"])
+            .enforce_admins(true)
+            .required_reviews(RequiredPullRequestReviews {
+                required_approving_review_count: Some(2),
+                ..Default::default()
+            })
+            .build();
+        assert_eq!(
+            serde_json::to_value(&protection).unwrap(),
+            serde_json::json!({
+                "required_status_checks": {"strict": true, "contexts": ["ci/build"]},
+                "enforce_admins": true,
+                "required_pull_request_reviews": {
+                    "dismiss_stale_reviews": false,
+                    "require_code_owner_reviews": false,
+                    "required_approving_review_count": 2
+                },
+                "restrictions": null
+            })
+        );
+    }
+}
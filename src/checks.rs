@@ -3,7 +3,7 @@
 use futures::IntoFuture;
 use serde::{Deserialize, Serialize};
 
-use self::super::{AuthenticationConstraint, Future, Github, MediaType};
+use self::super::{AuthenticationConstraint, DateTime, Future, Github, MediaType};
 
 pub struct CheckRuns {
     github: Github,
@@ -11,7 +11,7 @@ pub struct CheckRuns {
     repo: String,
 }
 
-impl<'a> CheckRuns {
+impl CheckRuns {
     #[doc(hidden)]
     pub(crate) fn new<O, R>(github: Github, owner: O, repo: R) -> Self
     where
@@ -19,7 +19,7 @@ impl<'a> CheckRuns {
         R: Into<String>,
     {
         CheckRuns {
-            github: github,
+            github,
             owner: owner.into(),
             repo: repo.into(),
         }
@@ -149,11 +149,11 @@ pub struct CheckRunOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<CheckRunState>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<String>,
+    pub started_at: Option<DateTime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conclusion: Option<Conclusion>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<String>,
+    pub completed_at: Option<DateTime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output: Option<Output>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -172,11 +172,11 @@ pub struct CheckRunUpdateOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<CheckRunState>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub started_at: Option<String>,
+    pub started_at: Option<DateTime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conclusion: Option<Conclusion>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub completed_at: Option<String>,
+    pub completed_at: Option<DateTime>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output: Option<Output>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -193,9 +193,9 @@ pub struct CheckRun {
     pub details_url: Option<String>,
     pub external_id: Option<String>,
     pub status: Option<CheckRunState>,
-    pub started_at: Option<String>,
+    pub started_at: Option<DateTime>,
     pub conclusion: Option<Conclusion>,
-    pub completed_at: Option<String>,
+    pub completed_at: Option<DateTime>,
     /*
     Deleted for now:
 
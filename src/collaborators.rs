@@ -0,0 +1,330 @@
+//! Repository collaborators and invitations interface
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/repos/collaborators/)
+use std::collections::HashMap;
+use std::fmt;
+
+use futures::Future as StdFuture;
+use http::StatusCode;
+use serde::Deserialize;
+use url::form_urlencoded;
+
+use crate::repositories::{Permissions, Repo};
+use crate::teams::Permission;
+use crate::users::User;
+use crate::{DateTime, Error, ErrorKind, Future, Github, Stream};
+
+/// Provides access to the collaborators and pending invitations of a
+/// repository
+pub struct Collaborators {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Collaborators {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Collaborators {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}{}", self.owner, self.repo, more)
+    }
+
+    fn list_uri(&self, options: &CollaboratorListOptions) -> String {
+        let mut uri = vec![self.path("/collaborators")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        uri.join("?")
+    }
+
+    /// list the collaborators of this repository, including organization
+    /// members with access through their teams or base permissions
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/collaborators/#list-collaborators)
+    /// for more information.
+    pub fn list(&self, options: &CollaboratorListOptions) -> Future<Vec<Collaborator>> {
+        self.github.get(&self.list_uri(options))
+    }
+
+    /// provides a stream over all pages of this repository's collaborators
+    pub fn iter(&self, options: &CollaboratorListOptions) -> Stream<Collaborator> {
+        self.github.get_stream(&self.list_uri(options))
+    }
+
+    /// check whether a user is a collaborator of this repository
+    pub fn is_collaborator<U>(&self, user: U) -> Future<bool>
+    where
+        U: Into<String>,
+    {
+        Box::new(
+            self.github
+                .get::<()>(&self.path(&format!("/collaborators/{}", user.into())))
+                .map(|_| true)
+                .or_else(|err| match err {
+                    Error(
+                        ErrorKind::Fault {
+                            code: StatusCode::NOT_FOUND,
+                            ..
+                        },
+                        _,
+                    ) => Ok(false),
+                    Error(ErrorKind::Codec(_), _) => Ok(true),
+                    otherwise => Err(otherwise),
+                }),
+        )
+    }
+
+    /// get the permission a user has on this repository
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/collaborators/#review-a-users-permission-level)
+    /// for more information.
+    pub fn permission<U>(&self, user: U) -> Future<CollaboratorPermission>
+    where
+        U: Into<String>,
+    {
+        self.github.get(&self.path(&format!(
+            "/collaborators/{}/permission",
+            user.into()
+        )))
+    }
+
+    /// add a collaborator, or update the permission of an existing one.
+    /// users who aren't collaborators yet are sent an invitation, which is
+    /// returned. the result is `None` when an existing collaborator's
+    /// permission was updated
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/collaborators/#add-user-as-a-collaborator)
+    /// for more information.
+    pub fn add<U>(&self, user: U, permission: Permission) -> Future<Option<RepoInvitation>>
+    where
+        U: Into<String>,
+    {
+        Box::new(
+            self.github
+                .put::<RepoInvitation>(
+                    &self.path(&format!("/collaborators/{}", user.into())),
+                    json_lit!({ "permission": permission.to_string() }),
+                )
+                .map(Some)
+                .or_else(|err| match err {
+                    Error(ErrorKind::Codec(_), _) => Ok(None),
+                    otherwise => Err(otherwise),
+                }),
+        )
+    }
+
+    /// remove a collaborator from this repository
+    pub fn remove<U>(&self, user: U) -> Future<()>
+    where
+        U: Into<String>,
+    {
+        self.github
+            .delete(&self.path(&format!("/collaborators/{}", user.into())))
+    }
+
+    /// list the pending invitations to collaborate on this repository
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/invitations/#list-invitations-for-a-repository)
+    /// for more information.
+    pub fn invitations(&self) -> Future<Vec<RepoInvitation>> {
+        self.github.get(&self.path("/invitations"))
+    }
+
+    /// provides a stream over all pages of this repository's pending
+    /// invitations
+    pub fn iter_invitations(&self) -> Stream<RepoInvitation> {
+        self.github.get_stream(&self.path("/invitations"))
+    }
+
+    /// delete a pending invitation
+    pub fn delete_invitation(&self, id: u64) -> Future<()> {
+        self.github
+            .delete(&self.path(&format!("/invitations/{}", id)))
+    }
+}
+
+/// Provides access to the repository invitations received by the
+/// authenticated user
+pub struct UserRepoInvitations {
+    github: Github,
+}
+
+impl UserRepoInvitations {
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        UserRepoInvitations { github }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/user/repository_invitations{}", more)
+    }
+
+    /// list the pending invitations of the authenticated user
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/invitations/#list-a-users-repository-invitations)
+    /// for more information.
+    pub fn list(&self) -> Future<Vec<RepoInvitation>> {
+        self.github.get(&self.path(""))
+    }
+
+    /// provides a stream over all pages of the authenticated user's pending
+    /// invitations
+    pub fn iter(&self) -> Stream<RepoInvitation> {
+        self.github.get_stream(&self.path(""))
+    }
+
+    /// accept an invitation, becoming a collaborator of its repository
+    pub fn accept(&self, id: u64) -> Future<()> {
+        self.github
+            .patch_no_response(&self.path(&format!("/{}", id)), Vec::new())
+    }
+
+    /// decline an invitation
+    pub fn decline(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.path(&format!("/{}", id)))
+    }
+}
+
+// representations
+
+/// Filters collaborators by how they are affiliated with a repository
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CollaboratorAffiliation {
+    /// outside collaborators of an organization owned repository
+    Outside,
+    /// collaborators with access to the repository itself, regardless of
+    /// organization membership
+    Direct,
+    All,
+}
+
+impl fmt::Display for CollaboratorAffiliation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CollaboratorAffiliation::Outside => "outside",
+            CollaboratorAffiliation::Direct => "direct",
+            CollaboratorAffiliation::All => "all",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Default)]
+pub struct CollaboratorListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl CollaboratorListOptions {
+    pub fn builder() -> CollaboratorListOptionsBuilder {
+        CollaboratorListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CollaboratorListOptionsBuilder(CollaboratorListOptions);
+
+impl CollaboratorListOptionsBuilder {
+    pub fn affiliation(&mut self, affiliation: CollaboratorAffiliation) -> &mut Self {
+        self.0.params.insert("affiliation", affiliation.to_string());
+        self
+    }
+
+    /// only list collaborators with exactly this permission
+    pub fn permission(&mut self, permission: Permission) -> &mut Self {
+        self.0.params.insert("permission", permission.to_string());
+        self
+    }
+
+    pub fn per_page(&mut self, n: u32) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> CollaboratorListOptions {
+        CollaboratorListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Collaborator {
+    pub login: String,
+    pub id: u64,
+    pub avatar_url: String,
+    pub url: String,
+    pub html_url: String,
+    pub site_admin: bool,
+    pub permissions: Permissions,
+    /// the name of the collaborator's role, which may be a custom
+    /// organization role
+    pub role_name: Option<String>,
+}
+
+/// A user's permission on a repository
+#[derive(Debug, Deserialize)]
+pub struct CollaboratorPermission {
+    /// one of `admin`, `write`, `read`, or `none`. `maintain` and `triage`
+    /// roles are reported as `write` and `read`, see `role_name`
+    pub permission: String,
+    pub role_name: Option<String>,
+    pub user: Option<User>,
+}
+
+/// An invitation to collaborate on a repository
+#[derive(Debug, Deserialize)]
+pub struct RepoInvitation {
+    pub id: u64,
+    pub repository: Repo,
+    /// `None` when the invited user has since been deleted
+    pub invitee: Option<User>,
+    pub inviter: Option<User>,
+    /// the permission the invitation grants, like `write`
+    pub permissions: String,
+    pub created_at: DateTime,
+    #[serde(default)]
+    pub expired: bool,
+    pub url: String,
+    pub html_url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collaborator_list_reqs() {
+        assert_eq!(CollaboratorListOptions::builder().build().serialize(), None);
+        assert_eq!(
+            CollaboratorListOptions::builder()
+                .affiliation(CollaboratorAffiliation::Outside)
+                .build()
+                .serialize(),
+            Some("affiliation=outside".to_owned())
+        );
+    }
+}
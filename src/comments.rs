@@ -1,11 +1,30 @@
 //! Comments interface
 use std::collections::HashMap;
+use std::fmt;
 
 use url::form_urlencoded;
 use serde::{Deserialize, Serialize};
 
 use crate::users::User;
-use crate::{Future, Github};
+use crate::{DateTime, Future, Github, SortDirection, Stream};
+
+/// Sort options available for issue comments
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum CommentSort {
+    #[default]
+    Created,
+    Updated,
+}
+
+impl fmt::Display for CommentSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CommentSort::Created => "created",
+            CommentSort::Updated => "updated",
+        }
+        .fmt(f)
+    }
+}
 
 /// A structure for interfacing with a issue comments
 pub struct Comments {
@@ -35,7 +54,7 @@ impl Comments {
         self.github.post(&self.path(), json!(comment))
     }
 
-    /// list pull requests
+    /// list comments
     pub fn list(&self, options: &CommentListOptions) -> Future<Vec<Comment>> {
         let mut uri = vec![self.path()];
         if let Some(query) = options.serialize() {
@@ -44,12 +63,41 @@ impl Comments {
         self.github.get(&uri.join("?"))
     }
 
+    /// provides a stream over all pages of comments
+    pub fn iter(&self, options: &CommentListOptions) -> Stream<Comment> {
+        let mut uri = vec![self.path()];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get_stream(&uri.join("?"))
+    }
+
+    /// get a single comment by its id
+    pub fn get(&self, id: u64) -> Future<Comment> {
+        self.github.get(&self.comment_path(id))
+    }
+
+    /// replace the body of a comment
+    pub fn edit(&self, id: u64, comment: &CommentOptions) -> Future<Comment> {
+        self.github.patch(&self.comment_path(id), json!(comment))
+    }
+
+    /// delete a comment
+    pub fn delete(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.comment_path(id))
+    }
+
     fn path(&self) -> String {
         format!(
             "/repos/{}/{}/issues/{}/comments",
             self.owner, self.repo, self.number
         )
     }
+
+    /// individual comments are addressed by id, independent of their issue
+    fn comment_path(&self, id: u64) -> String {
+        format!("/repos/{}/{}/issues/comments/{}", self.owner, self.repo, id)
+    }
 }
 
 // representations
@@ -59,10 +107,11 @@ pub struct Comment {
     pub id: u64,
     pub url: String,
     pub html_url: String,
+    pub issue_url: String,
     pub body: String,
     pub user: User,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
 }
 
 #[derive(Debug, Serialize)]
@@ -105,9 +154,43 @@ impl CommentListOptionsBuilder {
         self
     }
 
+    /// only applies to repository wide listings with `Issues#comments`
+    pub fn sort(&mut self, sort: CommentSort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    /// only applies to repository wide listings with `Issues#comments`
+    pub fn direction(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("direction", direction.to_string());
+        self
+    }
+
+    pub fn per_page(&mut self, n: u32) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
     pub fn build(&self) -> CommentListOptions {
         CommentListOptions {
             params: self.0.params.clone(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comment_list_reqs() {
+        assert_eq!(CommentListOptions::builder().build().serialize(), None);
+        assert_eq!(
+            CommentListOptions::builder()
+                .sort(CommentSort::Updated)
+                .build()
+                .serialize(),
+            Some("sort=updated".to_owned())
+        );
+    }
+}
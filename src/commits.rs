@@ -0,0 +1,299 @@
+//! Repo commits interface
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/repos/commits/)
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use url::form_urlencoded;
+
+use crate::users::User;
+use crate::{DateTime, Future, Github, Stream};
+
+/// Provides access to the commits of a repository
+pub struct Commits {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Commits {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Commits {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/commits{}", self.owner, self.repo, more)
+    }
+
+    /// list the commits of this repository
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/commits/#list-commits-on-a-repository)
+    /// for more information.
+    pub fn list(&self, options: &CommitListOptions) -> Future<Vec<Commit>> {
+        let mut uri = vec![self.path("")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get(&uri.join("?"))
+    }
+
+    /// provides a stream over all pages of commits of this repository
+    ///
+    /// Note: You'll typically want to use a `CommitListOptions` with a `per_page`
+    /// of 100 for maximum api credential rate limit efficency
+    pub fn iter(&self, options: &CommitListOptions) -> Stream<Commit> {
+        let mut uri = vec![self.path("")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get_stream(&uri.join("?"))
+    }
+
+    /// get a single commit, including its stats and changed files, by sha or
+    /// any other reference github can resolve
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/commits/#get-a-single-commit)
+    /// for more information.
+    pub fn get<S>(&self, sha: S) -> Future<Commit>
+    where
+        S: Into<String>,
+    {
+        self.github.get(&self.path(&format!("/{}", sha.into())))
+    }
+
+    /// compare two commits, branches, or tags, listing the commits reachable
+    /// from `head` but not from `base`. Comparisons list at most 250 commits
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/commits/#compare-two-commits)
+    /// for more information.
+    pub fn compare<B, H>(&self, base: B, head: H) -> Future<Comparison>
+    where
+        B: Into<String>,
+        H: Into<String>,
+    {
+        self.github.get(&format!(
+            "/repos/{}/{}/compare/{}...{}",
+            self.owner,
+            self.repo,
+            base.into(),
+            head.into()
+        ))
+    }
+}
+
+// representations
+
+/// Representation of a repository commit
+#[derive(Debug, Deserialize)]
+pub struct Commit {
+    pub url: String,
+    pub sha: String,
+    pub html_url: String,
+    pub comments_url: String,
+    pub commit: CommitDetails,
+    /// the github user of the author, if github could associate one
+    pub author: Option<User>,
+    /// the github user of the committer, if github could associate one
+    pub committer: Option<User>,
+    pub parents: Vec<CommitRef>,
+    /// only provided when getting a single commit
+    pub stats: Option<CommitStats>,
+    /// only provided when getting a single commit
+    pub files: Option<Vec<CommitFile>>,
+}
+
+/// Representation of the git details of a commit
+#[derive(Debug, Deserialize)]
+pub struct CommitDetails {
+    pub url: String,
+    pub author: Option<UserStamp>,
+    pub committer: Option<UserStamp>,
+    pub message: String,
+    pub tree: CommitRef,
+    pub comment_count: u64,
+}
+
+/// Representation of a reference to a commit
+#[derive(Debug, Deserialize)]
+pub struct CommitRef {
+    pub url: String,
+    pub sha: String,
+}
+
+/// Representation of a git user
+#[derive(Debug, Deserialize)]
+pub struct UserStamp {
+    pub name: String,
+    pub email: String,
+    pub date: DateTime,
+}
+
+/// Representation of the line changes of a commit
+#[derive(Debug, Deserialize)]
+pub struct CommitStats {
+    pub additions: u64,
+    pub deletions: u64,
+    pub total: u64,
+}
+
+/// Representation of a file changed by a commit
+#[derive(Debug, Deserialize)]
+pub struct CommitFile {
+    /// sha from GitHub may be null when file mode changed without contents changing
+    pub sha: Option<String>,
+    pub filename: String,
+    pub status: String,
+    pub additions: u64,
+    pub deletions: u64,
+    pub changes: u64,
+    pub blob_url: String,
+    pub raw_url: String,
+    pub contents_url: String,
+    /// patch is typically None for binary files
+    pub patch: Option<String>,
+    /// the previous name of renamed files
+    pub previous_filename: Option<String>,
+}
+
+/// How the head of a comparison relates to its base
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ComparisonStatus {
+    /// head is ahead of base
+    Ahead,
+    /// head is behind base
+    Behind,
+    /// head and base have each gained commits since their merge base
+    Diverged,
+    Identical,
+}
+
+/// Representation of a comparison between two commits
+#[derive(Debug, Deserialize)]
+pub struct Comparison {
+    pub url: String,
+    pub html_url: String,
+    pub permalink_url: String,
+    pub diff_url: String,
+    pub patch_url: String,
+    pub base_commit: Commit,
+    pub merge_base_commit: Commit,
+    pub status: ComparisonStatus,
+    /// the number of commits head has that base doesn't
+    pub ahead_by: u64,
+    /// the number of commits base has that head doesn't
+    pub behind_by: u64,
+    pub total_commits: u64,
+    pub commits: Vec<Commit>,
+    #[serde(default)]
+    pub files: Vec<CommitFile>,
+}
+
+/// Options used to filter repository commit listings
+///
+/// See the [github docs](https://developer.github.com/v3/repos/commits/#list-commits-on-a-repository)
+/// for more information
+#[derive(Default)]
+pub struct CommitListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl CommitListOptions {
+    pub fn builder() -> CommitListOptionsBuilder {
+        CommitListOptionsBuilder::default()
+    }
+
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+/// a mutable commit list builder
+#[derive(Default)]
+pub struct CommitListOptionsBuilder(CommitListOptions);
+
+impl CommitListOptionsBuilder {
+    /// sha or branch to start listing commits from
+    pub fn sha<S>(&mut self, sha: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.0.params.insert("sha", sha.into());
+        self
+    }
+
+    /// only commits containing this file path
+    pub fn path<P>(&mut self, path: P) -> &mut Self
+    where
+        P: Into<String>,
+    {
+        self.0.params.insert("path", path.into());
+        self
+    }
+
+    /// only commits by this github login or email address
+    pub fn author<A>(&mut self, author: A) -> &mut Self
+    where
+        A: Into<String>,
+    {
+        self.0.params.insert("author", author.into());
+        self
+    }
+
+    /// only commits after this ISO 8601 timestamp
+    pub fn since<S>(&mut self, since: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.0.params.insert("since", since.into());
+        self
+    }
+
+    /// only commits before this ISO 8601 timestamp
+    pub fn until<U>(&mut self, until: U) -> &mut Self
+    where
+        U: Into<String>,
+    {
+        self.0.params.insert("until", until.into());
+        self
+    }
+
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> CommitListOptions {
+        CommitListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_comparison_status() {
+        let status: ComparisonStatus = serde_json::from_str(r#""diverged""#).unwrap();
+        assert_eq!(status, ComparisonStatus::Diverged);
+    }
+}
@@ -3,9 +3,10 @@ use std::fmt;
 use std::ops;
 
 use percent_encoding::{percent_encode, DEFAULT_ENCODE_SET};
-use serde::Deserialize;
 use serde::de::{self, Visitor};
+use serde::{Deserialize, Serialize};
 
+use crate::git::GitCommit;
 use crate::{Future, Github, Stream};
 
 /// Provides access to the content information for a repository
@@ -50,6 +51,27 @@ impl Content {
         self.github.get(&self.path(location))
     }
 
+    /// Gets the preferred README of the repository.
+    pub fn readme(&self) -> Future<File> {
+        self.github
+            .get(&format!("/repos/{}/{}/readme", self.owner, self.repo))
+    }
+
+    /// Creates a new file at `location`, or replaces it if `options` includes
+    /// the sha of the existing file.
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/contents/#create-or-update-a-file)
+    /// for more information.
+    pub fn create_or_update(&self, location: &str, options: &FileOptions) -> Future<ContentUpdate> {
+        self.github.put(&self.path(location), json!(options))
+    }
+
+    /// Deletes the file at `location`.
+    pub fn delete(&self, location: &str, options: &DeleteFileOptions) -> Future<()> {
+        self.github
+            .delete_message(&self.path(location), json!(options))
+    }
+
     /// List the root directory.
     pub fn root(&self) -> Stream<DirectoryItem> {
         self.iter("/")
@@ -147,13 +169,180 @@ pub struct Links {
     pub html: String,
 }
 
+/// The result of creating or updating a file.
+#[derive(Debug, Deserialize)]
+pub struct ContentUpdate {
+    pub content: DirectoryItem,
+    pub commit: GitCommit,
+}
+
+/// The name and email to attribute a commit to.
+#[derive(Clone, Debug, Serialize)]
+pub struct Identity {
+    pub name: String,
+    pub email: String,
+}
+
+impl Identity {
+    pub fn new<N, E>(name: N, email: E) -> Self
+    where
+        N: Into<String>,
+        E: Into<String>,
+    {
+        Identity {
+            name: name.into(),
+            email: email.into(),
+        }
+    }
+}
+
+/// Options for creating or updating a file.
+#[derive(Debug, Default, Serialize)]
+pub struct FileOptions {
+    message: String,
+    /// base64 encoded
+    content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sha: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    committer: Option<Identity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<Identity>,
+}
+
+impl FileOptions {
+    /// Creates a builder for committing `content` with a commit `message`.
+    pub fn builder<M, C>(message: M, content: C) -> FileOptionsBuilder
+    where
+        M: Into<String>,
+        C: AsRef<[u8]>,
+    {
+        FileOptionsBuilder(FileOptions {
+            message: message.into(),
+            content: base64::encode(content.as_ref()),
+            ..Default::default()
+        })
+    }
+}
+
+pub struct FileOptionsBuilder(FileOptions);
+
+impl FileOptionsBuilder {
+    /// The blob sha of the file being replaced. Required when updating a file.
+    pub fn sha<S>(&mut self, sha: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.0.sha = Some(sha.into());
+        self
+    }
+
+    /// The branch to commit to. Defaults to the repository's default branch.
+    pub fn branch<B>(&mut self, branch: B) -> &mut Self
+    where
+        B: Into<String>,
+    {
+        self.0.branch = Some(branch.into());
+        self
+    }
+
+    /// Defaults to the authenticated user.
+    pub fn committer(&mut self, committer: Identity) -> &mut Self {
+        self.0.committer = Some(committer);
+        self
+    }
+
+    /// Defaults to the committer.
+    pub fn author(&mut self, author: Identity) -> &mut Self {
+        self.0.author = Some(author);
+        self
+    }
+
+    pub fn build(&self) -> FileOptions {
+        FileOptions {
+            message: self.0.message.clone(),
+            content: self.0.content.clone(),
+            sha: self.0.sha.clone(),
+            branch: self.0.branch.clone(),
+            committer: self.0.committer.clone(),
+            author: self.0.author.clone(),
+        }
+    }
+}
+
+/// Options for deleting a file.
+#[derive(Debug, Default, Serialize)]
+pub struct DeleteFileOptions {
+    message: String,
+    sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    committer: Option<Identity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<Identity>,
+}
+
+impl DeleteFileOptions {
+    /// Creates a builder for deleting the file with blob `sha` with a commit
+    /// `message`.
+    pub fn builder<M, S>(message: M, sha: S) -> DeleteFileOptionsBuilder
+    where
+        M: Into<String>,
+        S: Into<String>,
+    {
+        DeleteFileOptionsBuilder(DeleteFileOptions {
+            message: message.into(),
+            sha: sha.into(),
+            ..Default::default()
+        })
+    }
+}
+
+pub struct DeleteFileOptionsBuilder(DeleteFileOptions);
+
+impl DeleteFileOptionsBuilder {
+    /// The branch to commit to. Defaults to the repository's default branch.
+    pub fn branch<B>(&mut self, branch: B) -> &mut Self
+    where
+        B: Into<String>,
+    {
+        self.0.branch = Some(branch.into());
+        self
+    }
+
+    /// Defaults to the authenticated user.
+    pub fn committer(&mut self, committer: Identity) -> &mut Self {
+        self.0.committer = Some(committer);
+        self
+    }
+
+    /// Defaults to the committer.
+    pub fn author(&mut self, author: Identity) -> &mut Self {
+        self.0.author = Some(author);
+        self
+    }
+
+    pub fn build(&self) -> DeleteFileOptions {
+        DeleteFileOptions {
+            message: self.0.message.clone(),
+            sha: self.0.sha.clone(),
+            branch: self.0.branch.clone(),
+            committer: self.0.committer.clone(),
+            author: self.0.author.clone(),
+        }
+    }
+}
+
 /// Decoded file contents.
 #[derive(Debug)]
 pub struct DecodedContents(Vec<u8>);
 
-impl Into<Vec<u8>> for DecodedContents {
-    fn into(self) -> Vec<u8> {
-        self.0
+impl From<DecodedContents> for Vec<u8> {
+    fn from(contents: DecodedContents) -> Vec<u8> {
+        contents.0
     }
 }
 
@@ -215,3 +404,25 @@ impl<'de> Deserialize<'de> for DecodedContents {
         deserializer.deserialize_str(DecodedContentsVisitor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_file_options() {
+        let options = FileOptions::builder("add greeting", "hello")
+            .branch("main")
+            .committer(Identity::new("Monalisa Octocat", "octocat@github.com"))
+            .build();
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({
+                "message": "add greeting",
+                "content": "aGVsbG8=",
+                "branch": "main",
+                "committer": {"name": "Monalisa Octocat", "email": "octocat@github.com"}
+            })
+        );
+    }
+}
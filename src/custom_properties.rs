@@ -0,0 +1,253 @@
+//! Custom properties interface
+//!
+//! Custom properties are organization defined metadata attached to
+//! repositories. See the [github docs](https://docs.github.com/en/rest/orgs/custom-properties)
+//! for more information.
+use serde::{Deserialize, Serialize};
+
+use crate::{Future, Github};
+
+/// Provides access to the custom property schema of an organization and the
+/// values its repositories hold
+pub struct OrgCustomProperties {
+    github: Github,
+    org: String,
+}
+
+impl OrgCustomProperties {
+    #[doc(hidden)]
+    pub fn new<O>(github: Github, org: O) -> Self
+    where
+        O: Into<String>,
+    {
+        OrgCustomProperties {
+            github,
+            org: org.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/orgs/{}/properties{}", self.org, more)
+    }
+
+    /// list the custom properties defined for this organization
+    pub fn schema(&self) -> Future<Vec<CustomProperty>> {
+        self.github.get(&self.path("/schema"))
+    }
+
+    /// get a custom property defined for this organization by name
+    pub fn get<N>(&self, name: N) -> Future<CustomProperty>
+    where
+        N: Into<String>,
+    {
+        self.github
+            .get(&self.path(&format!("/schema/{}", name.into())))
+    }
+
+    /// create or update custom property definitions in bulk, returning the
+    /// updated definitions
+    pub fn define(&self, properties: &[CustomProperty]) -> Future<Vec<CustomProperty>> {
+        self.github.patch(
+            &self.path("/schema"),
+            json_lit!({ "properties": properties }),
+        )
+    }
+
+    /// remove a custom property definition and all of its values
+    pub fn delete<N>(&self, name: N) -> Future<()>
+    where
+        N: Into<String>,
+    {
+        self.github
+            .delete(&self.path(&format!("/schema/{}", name.into())))
+    }
+
+    /// list the custom property values of this organization's repositories
+    pub fn values(&self) -> Future<Vec<RepoCustomPropertyValues>> {
+        self.github.get(&self.path("/values"))
+    }
+
+    /// set custom property values for up to 30 repositories at once. values of
+    /// `None` remove the property from the repositories
+    pub fn set_values<R>(
+        &self,
+        repository_names: Vec<R>,
+        properties: &[CustomPropertyValue],
+    ) -> Future<()>
+    where
+        R: Into<String>,
+    {
+        let repository_names = repository_names
+            .into_iter()
+            .map(|name| name.into())
+            .collect::<Vec<String>>();
+        self.github.patch_no_response(
+            &self.path("/values"),
+            json_lit!({
+                "repository_names": repository_names,
+                "properties": properties
+            }),
+        )
+    }
+}
+
+/// Provides access to the custom property values of a repository
+pub struct RepoCustomProperties {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl RepoCustomProperties {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        RepoCustomProperties {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self) -> String {
+        format!("/repos/{}/{}/properties/values", self.owner, self.repo)
+    }
+
+    /// list the custom property values of this repository
+    pub fn list(&self) -> Future<Vec<CustomPropertyValue>> {
+        self.github.get(&self.path())
+    }
+
+    /// create or update custom property values of this repository. values of
+    /// `None` remove the property from the repository
+    pub fn set(&self, properties: &[CustomPropertyValue]) -> Future<()> {
+        self.github
+            .patch_no_response(&self.path(), json_lit!({ "properties": properties }))
+    }
+}
+
+// representations
+
+/// The type of values a custom property holds
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueType {
+    String,
+    SingleSelect,
+    MultiSelect,
+    TrueFalse,
+}
+
+/// The value of a custom property. `multi_select` properties hold a list of
+/// values, all others a single value
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum PropertyValue {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+/// The definition of a custom property
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CustomProperty {
+    pub property_name: String,
+    pub value_type: ValueType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<PropertyValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// the values `single_select` and `multi_select` properties may hold
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_values: Option<Vec<String>>,
+    /// who may edit the property's values: `org_actors` or `org_and_repo_actors`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values_editable_by: Option<String>,
+}
+
+impl CustomProperty {
+    pub fn new<N>(property_name: N, value_type: ValueType) -> Self
+    where
+        N: Into<String>,
+    {
+        CustomProperty {
+            property_name: property_name.into(),
+            value_type,
+            required: None,
+            default_value: None,
+            description: None,
+            allowed_values: None,
+            values_editable_by: None,
+        }
+    }
+}
+
+/// The value a repository holds for a custom property
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CustomPropertyValue {
+    pub property_name: String,
+    /// serialized as null when `None`, which removes the property's value
+    pub value: Option<PropertyValue>,
+}
+
+impl CustomPropertyValue {
+    pub fn new<N, V>(property_name: N, value: V) -> Self
+    where
+        N: Into<String>,
+        V: Into<Option<PropertyValue>>,
+    {
+        CustomPropertyValue {
+            property_name: property_name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// The custom property values of one of an organization's repositories
+#[derive(Debug, Deserialize)]
+pub struct RepoCustomPropertyValues {
+    pub repository_id: u64,
+    pub repository_name: String,
+    pub repository_full_name: String,
+    pub properties: Vec<CustomPropertyValue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_property_values() {
+        let values: Vec<CustomPropertyValue> = serde_json::from_str(
+            r#"[
+                {"property_name": "team", "value": "platform"},
+                {"property_name": "regions", "value": ["us", "eu"]},
+                {"property_name": "tier", "value": null}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            values,
+            vec![
+                CustomPropertyValue::new("team", PropertyValue::Single("platform".into())),
+                CustomPropertyValue::new(
+                    "regions",
+                    PropertyValue::Multiple(vec!["us".into(), "eu".into()])
+                ),
+                CustomPropertyValue::new("tier", None),
+            ]
+        )
+    }
+
+    #[test]
+    fn serialize_property_values() {
+        assert_eq!(
+            serde_json::to_string(&CustomPropertyValue::new("tier", None)).unwrap(),
+            r#"{"property_name":"tier","value":null}"#
+        )
+    }
+}
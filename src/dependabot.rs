@@ -0,0 +1,351 @@
+//! Dependabot interface
+//!
+//! Dependabot alerts flag dependencies of a repository with known
+//! vulnerabilities. Dependabot secrets are the secrets available to dependabot
+//! when it updates dependencies, and are managed like actions secrets.
+//!
+//! See the [github docs](https://docs.github.com/en/rest/dependabot) for more information
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
+
+use crate::actions::Secrets;
+use crate::users::User;
+use crate::{DateTime, Future, Github, SortDirection, Stream};
+
+/// Provides access to the dependabot alerts and secrets of a repository
+pub struct Dependabot {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Dependabot {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Dependabot {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/dependabot{}", self.owner, self.repo, more)
+    }
+
+    fn alerts_uri(&self, options: &AlertListOptions) -> String {
+        let mut uri = vec![self.path("/alerts")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        uri.join("?")
+    }
+
+    /// list the dependabot alerts of this repository
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/dependabot/alerts#list-dependabot-alerts-for-a-repository)
+    /// for more information.
+    pub fn alerts(&self, options: &AlertListOptions) -> Future<Vec<Alert>> {
+        self.github.get(&self.alerts_uri(options))
+    }
+
+    /// provides a stream over all pages of the dependabot alerts of this repository
+    pub fn iter_alerts(&self, options: &AlertListOptions) -> Stream<Alert> {
+        self.github.get_stream(&self.alerts_uri(options))
+    }
+
+    /// get a dependabot alert by its number
+    pub fn alert(&self, number: u64) -> Future<Alert> {
+        self.github
+            .get(&self.path(&format!("/alerts/{}", number)))
+    }
+
+    /// dismiss an alert, giving a reason and optionally a comment
+    pub fn dismiss<C>(&self, number: u64, reason: DismissedReason, comment: C) -> Future<Alert>
+    where
+        C: Into<Option<String>>,
+    {
+        self.update_alert(
+            number,
+            &AlertUpdate {
+                state: AlertState::Dismissed,
+                dismissed_reason: Some(reason),
+                dismissed_comment: comment.into(),
+            },
+        )
+    }
+
+    /// reopen a dismissed alert
+    pub fn reopen(&self, number: u64) -> Future<Alert> {
+        self.update_alert(
+            number,
+            &AlertUpdate {
+                state: AlertState::Open,
+                dismissed_reason: None,
+                dismissed_comment: None,
+            },
+        )
+    }
+
+    fn update_alert(&self, number: u64, update: &AlertUpdate) -> Future<Alert> {
+        self.github
+            .patch(&self.path(&format!("/alerts/{}", number)), json!(update))
+    }
+
+    /// get a reference to the dependabot secrets of this repository
+    pub fn secrets(&self) -> Secrets {
+        Secrets::for_dependabot(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+}
+
+// representations
+
+/// The state of a dependabot alert
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertState {
+    AutoDismissed,
+    Dismissed,
+    Fixed,
+    Open,
+}
+
+impl fmt::Display for AlertState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            AlertState::AutoDismissed => "auto_dismissed",
+            AlertState::Dismissed => "dismissed",
+            AlertState::Fixed => "fixed",
+            AlertState::Open => "open",
+        }
+        .fmt(f)
+    }
+}
+
+/// Why a dependabot alert was dismissed
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DismissedReason {
+    /// a fix has already been started
+    FixStarted,
+    /// the alert is inaccurate or incorrect
+    Inaccurate,
+    /// there is no bandwidth to fix the vulnerability
+    NoBandwidth,
+    /// the vulnerable code is not actually used
+    NotUsed,
+    /// the risk is tolerable to the repository
+    TolerableRisk,
+}
+
+/// Sort options available for dependabot alerts
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AlertSort {
+    Created,
+    Updated,
+}
+
+impl fmt::Display for AlertSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            AlertSort::Created => "created",
+            AlertSort::Updated => "updated",
+        }
+        .fmt(f)
+    }
+}
+
+/// Options used to filter dependabot alert listings
+#[derive(Default)]
+pub struct AlertListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl AlertListOptions {
+    pub fn builder() -> AlertListOptionsBuilder {
+        AlertListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct AlertListOptionsBuilder(AlertListOptions);
+
+impl AlertListOptionsBuilder {
+    pub fn state(&mut self, state: AlertState) -> &mut Self {
+        self.0.params.insert("state", state.to_string());
+        self
+    }
+
+    /// only list alerts of advisories with these severities, like `critical,high`
+    pub fn severity<S>(&mut self, severity: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.0.params.insert("severity", severity.into());
+        self
+    }
+
+    /// only list alerts of packages of these ecosystems, like `npm,pip`
+    pub fn ecosystem<E>(&mut self, ecosystem: E) -> &mut Self
+    where
+        E: Into<String>,
+    {
+        self.0.params.insert("ecosystem", ecosystem.into());
+        self
+    }
+
+    /// only list alerts of these packages, separated by commas
+    pub fn package<P>(&mut self, package: P) -> &mut Self
+    where
+        P: Into<String>,
+    {
+        self.0.params.insert("package", package.into());
+        self
+    }
+
+    /// only list alerts of these manifest paths, separated by commas
+    pub fn manifest<M>(&mut self, manifest: M) -> &mut Self
+    where
+        M: Into<String>,
+    {
+        self.0.params.insert("manifest", manifest.into());
+        self
+    }
+
+    pub fn sort(&mut self, sort: AlertSort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    pub fn direction(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("direction", direction.to_string());
+        self
+    }
+
+    pub fn per_page(&mut self, n: u32) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> AlertListOptions {
+        AlertListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// The body of a request updating the state of an alert
+#[derive(Serialize)]
+struct AlertUpdate {
+    state: AlertState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dismissed_reason: Option<DismissedReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dismissed_comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Alert {
+    pub number: u64,
+    pub state: AlertState,
+    pub dependency: Dependency,
+    pub security_advisory: SecurityAdvisory,
+    pub security_vulnerability: SecurityVulnerability,
+    pub url: String,
+    pub html_url: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub dismissed_at: Option<DateTime>,
+    pub dismissed_by: Option<User>,
+    pub dismissed_reason: Option<DismissedReason>,
+    pub dismissed_comment: Option<String>,
+    pub fixed_at: Option<DateTime>,
+    pub auto_dismissed_at: Option<DateTime>,
+}
+
+/// The vulnerable dependency of an alert
+#[derive(Debug, Deserialize)]
+pub struct Dependency {
+    pub package: Package,
+    /// the path of the manifest declaring the dependency, like `Cargo.lock`
+    pub manifest_path: String,
+    /// `runtime` or `development`
+    pub scope: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Package {
+    /// like `npm`, `pip`, or `rust`
+    pub ecosystem: String,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SecurityAdvisory {
+    pub ghsa_id: String,
+    pub cve_id: Option<String>,
+    pub summary: String,
+    pub description: String,
+    /// one of `low`, `medium`, `high`, or `critical`
+    pub severity: String,
+    pub published_at: DateTime,
+    pub updated_at: DateTime,
+    pub withdrawn_at: Option<DateTime>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SecurityVulnerability {
+    pub package: Package,
+    pub severity: String,
+    /// like `< 1.2.3`
+    pub vulnerable_version_range: String,
+    pub first_patched_version: Option<PatchedVersion>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PatchedVersion {
+    pub identifier: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dismissal_req() {
+        let update = AlertUpdate {
+            state: AlertState::Dismissed,
+            dismissed_reason: Some(DismissedReason::TolerableRisk),
+            dismissed_comment: None,
+        };
+        assert_eq!(
+            serde_json::to_string(&update).unwrap(),
+            r#"{"state":"dismissed","dismissed_reason":"tolerable_risk"}"#
+        );
+        let update = AlertUpdate {
+            state: AlertState::Open,
+            dismissed_reason: None,
+            dismissed_comment: None,
+        };
+        assert_eq!(serde_json::to_string(&update).unwrap(), r#"{"state":"open"}"#);
+    }
+}
@@ -0,0 +1,217 @@
+//! Dependency graph interface
+//!
+//! See the [github docs](https://docs.github.com/en/rest/dependency-graph) for more information
+use serde::Deserialize;
+use url::form_urlencoded;
+
+use crate::{DateTime, Future, Github};
+
+/// Provides access to the dependency graph of a repository
+pub struct DependencyGraph {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl DependencyGraph {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        DependencyGraph {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!(
+            "/repos/{}/{}/dependency-graph{}",
+            self.owner, self.repo, more
+        )
+    }
+
+    /// export the software bill of materials of this repository's default
+    /// branch as an SPDX document
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/dependency-graph/sboms#export-a-software-bill-of-materials-sbom-for-a-repository)
+    /// for more information.
+    pub fn sbom(&self) -> Future<Sbom> {
+        self.github.get(&self.path("/sbom"))
+    }
+
+    /// like `sbom` but resolves the SPDX document as github returned it, for
+    /// handing on to other SPDX tooling unchanged
+    pub fn sbom_json(&self) -> Future<serde_json::Value> {
+        self.github.get(&self.path("/sbom"))
+    }
+
+    /// list the dependencies added and removed between two refs, like
+    /// `main` and a feature branch, along with their known vulnerabilities.
+    /// pass a manifest path as `manifest` to only compare the dependencies it
+    /// declares
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/dependency-graph/dependency-review#get-a-diff-of-the-dependencies-between-commits)
+    /// for more information.
+    pub fn compare<M>(&self, base: &str, head: &str, manifest: M) -> Future<Vec<DependencyChange>>
+    where
+        M: Into<Option<String>>,
+    {
+        let mut uri = self.path(&format!("/compare/{}...{}", base, head));
+        if let Some(manifest) = manifest.into() {
+            uri.push('?');
+            uri.push_str(
+                &form_urlencoded::Serializer::new(String::new())
+                    .append_pair("name", &manifest)
+                    .finish(),
+            );
+        }
+        self.github.get(&uri)
+    }
+}
+
+// representations
+
+#[derive(Debug, Deserialize)]
+pub struct Sbom {
+    pub sbom: SpdxDocument,
+}
+
+/// An SPDX document describing the packages a repository depends on
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxDocument {
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: String,
+    /// like `SPDX-2.3`
+    pub spdx_version: String,
+    pub creation_info: SpdxCreationInfo,
+    pub name: String,
+    pub data_license: String,
+    pub document_namespace: String,
+    #[serde(default)]
+    pub document_describes: Vec<String>,
+    #[serde(default)]
+    pub packages: Vec<SpdxPackage>,
+    #[serde(default)]
+    pub relationships: Vec<SpdxRelationship>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpdxCreationInfo {
+    pub created: DateTime,
+    pub creators: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxPackage {
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: String,
+    pub name: String,
+    pub version_info: Option<String>,
+    pub download_location: Option<String>,
+    pub files_analyzed: Option<bool>,
+    pub license_concluded: Option<String>,
+    pub license_declared: Option<String>,
+    pub supplier: Option<String>,
+    pub copyright_text: Option<String>,
+    #[serde(default)]
+    pub external_refs: Vec<SpdxExternalRef>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxExternalRef {
+    /// like `PACKAGE-MANAGER`
+    pub reference_category: String,
+    /// like `pkg:cargo/serde@1.0.84`
+    pub reference_locator: String,
+    /// like `purl`
+    pub reference_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpdxRelationship {
+    pub relationship_type: String,
+    pub spdx_element_id: String,
+    pub related_spdx_element: String,
+}
+
+/// Whether a dependency was added or removed between two refs
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeType {
+    Added,
+    Removed,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DependencyChange {
+    pub change_type: ChangeType,
+    /// the path of the manifest declaring the dependency
+    pub manifest: String,
+    pub ecosystem: String,
+    pub name: String,
+    pub version: String,
+    pub package_url: Option<String>,
+    pub license: Option<String>,
+    pub source_repository_url: Option<String>,
+    /// `runtime`, `development`, or `unknown`
+    pub scope: Option<String>,
+    #[serde(default)]
+    pub vulnerabilities: Vec<Vulnerability>,
+}
+
+/// A known vulnerability of a dependency version
+#[derive(Debug, Deserialize)]
+pub struct Vulnerability {
+    pub severity: String,
+    pub advisory_ghsa_id: String,
+    pub advisory_summary: String,
+    pub advisory_url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_sbom() {
+        let sbom: Sbom = serde_json::from_value(serde_json::json!({
+            "sbom": {
+                "SPDXID": "SPDXRef-DOCUMENT",
+                "spdxVersion": "SPDX-2.3",
+                "creationInfo": {
+                    "created": "2023-06-01T10:00:00Z",
+                    "creators": ["Tool: GitHub.com-Dependency-Graph"]
+                },
+                "name": "com.github.softprops/hubcaps",
+                "dataLicense": "CC0-1.0",
+                "documentNamespace": "https://github.com/softprops/hubcaps/dependency_graph/sbom-1",
+                "packages": [{
+                    "SPDXID": "SPDXRef-cargo-serde-1.0.84",
+                    "name": "cargo:serde",
+                    "versionInfo": "1.0.84",
+                    "downloadLocation": "NOASSERTION",
+                    "filesAnalyzed": false,
+                    "externalRefs": [{
+                        "referenceCategory": "PACKAGE-MANAGER",
+                        "referenceLocator": "pkg:cargo/serde@1.0.84",
+                        "referenceType": "purl"
+                    }]
+                }]
+            }
+        }))
+        .unwrap();
+        assert_eq!(sbom.sbom.spdx_version, "SPDX-2.3");
+        assert_eq!(
+            sbom.sbom.packages[0].external_refs[0].reference_locator,
+            "pkg:cargo/serde@1.0.84"
+        );
+    }
+}
@@ -4,9 +4,14 @@ use std::collections::HashMap;
 use url::form_urlencoded;
 use serde::{Deserialize, Serialize};
 
-use crate::statuses::State;
 use crate::users::User;
-use crate::{Future, Github};
+use crate::{AuthenticationConstraint, DateTime, Future, Github, MediaType, Stream};
+
+/// the preview required for the `inactive` state, `environment_url`,
+/// `log_url`, and `auto_inactive`
+const ANT_MAN_PREVIEW: MediaType = MediaType::Preview("ant-man");
+/// the preview required for the `in_progress` and `queued` states
+const FLASH_PREVIEW: MediaType = MediaType::Preview("flash");
 
 /// Interface for repository deployments
 pub struct Deployments {
@@ -50,10 +55,20 @@ impl DeploymentStatuses {
         self.github.get(&self.path(""))
     }
 
+    /// provides a stream over all pages of statuses associated with a deployment
+    pub fn iter(&self) -> Stream<DeploymentStatus> {
+        self.github.get_stream(&self.path(""))
+    }
+
     /// creates a new deployment status. For convenience, a DeploymentStatusOptions.builder
     /// interface is required for building up a request
     pub fn create(&self, status: &DeploymentStatusOptions) -> Future<DeploymentStatus> {
-        self.github.post(&self.path(""), json!(status))
+        self.github.post_media(
+            &self.path(""),
+            json!(status),
+            status.media(),
+            AuthenticationConstraint::Unconstrained,
+        )
     }
 }
 
@@ -84,6 +99,15 @@ impl Deployments {
         self.github.get(&uri.join("?"))
     }
 
+    /// provides a stream over all pages of deployments for a repository
+    pub fn iter(&self, opts: &DeploymentListOptions) -> Stream<Deployment> {
+        let mut uri = vec![self.path("")];
+        if let Some(query) = opts.serialize() {
+            uri.push(query);
+        }
+        self.github.get_stream(&uri.join("?"))
+    }
+
     /// creates a new deployment for this repository
     pub fn create(&self, dep: &DeploymentOptions) -> Future<Deployment> {
         self.github.post(&self.path(""), json!(dep))
@@ -115,12 +139,19 @@ pub struct Deployment {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     pub creator: User,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
     pub statuses_url: String,
     pub repository_url: String,
 }
 
+impl Deployment {
+    /// List the statuses of this deployment by following its `statuses_url`.
+    pub fn statuses(&self, github: &Github) -> Future<Vec<DeploymentStatus>> {
+        github.get_url(&self.statuses_url)
+    }
+}
+
 #[derive(Debug, Default, Serialize)]
 pub struct DeploymentOptions {
     #[serde(rename = "ref")]
@@ -218,14 +249,34 @@ impl DeploymentOptionsBuilder {
     }
 }
 
+/// The states of a deployment
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentState {
+    Error,
+    Failure,
+    /// superseded by a later successful deployment to the same environment
+    Inactive,
+    InProgress,
+    Queued,
+    #[default]
+    Pending,
+    Success,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct DeploymentStatus {
     pub url: String,
-    pub created_at: String,
-    pub updated_at: String,
-    pub state: State,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub state: DeploymentState,
     pub target_url: Option<String>,
     pub description: Option<String>,
+    pub environment: Option<String>,
+    /// the url of the deployed environment
+    pub environment_url: Option<String>,
+    /// the url of the deployment's output
+    pub log_url: Option<String>,
     pub id: u64,
     pub deployment_url: String,
     pub repository_url: String,
@@ -235,7 +286,7 @@ pub struct DeploymentStatus {
 pub struct DeploymentStatusOptionsBuilder(DeploymentStatusOptions);
 
 impl DeploymentStatusOptionsBuilder {
-    pub(crate) fn new(state: State) -> DeploymentStatusOptionsBuilder {
+    pub(crate) fn new(state: DeploymentState) -> DeploymentStatusOptionsBuilder {
         DeploymentStatusOptionsBuilder(DeploymentStatusOptions {
             state,
             ..Default::default()
@@ -258,28 +309,77 @@ impl DeploymentStatusOptionsBuilder {
         self
     }
 
+    /// the url of the deployed environment
+    pub fn environment_url<U>(&mut self, url: U) -> &mut DeploymentStatusOptionsBuilder
+    where
+        U: Into<String>,
+    {
+        self.0.environment_url = Some(url.into());
+        self
+    }
+
+    /// the url of the deployment's output. supersedes `target_url`
+    pub fn log_url<U>(&mut self, url: U) -> &mut DeploymentStatusOptionsBuilder
+    where
+        U: Into<String>,
+    {
+        self.0.log_url = Some(url.into());
+        self
+    }
+
+    /// whether a `success` status marks the previous deployments to the same
+    /// environment `inactive`. Default: `true`
+    pub fn auto_inactive(&mut self, auto_inactive: bool) -> &mut DeploymentStatusOptionsBuilder {
+        self.0.auto_inactive = Some(auto_inactive);
+        self
+    }
+
     pub fn build(&self) -> DeploymentStatusOptions {
         DeploymentStatusOptions {
             state: self.0.state.clone(),
             target_url: self.0.target_url.clone(),
             description: self.0.description.clone(),
+            environment_url: self.0.environment_url.clone(),
+            log_url: self.0.log_url.clone(),
+            auto_inactive: self.0.auto_inactive,
         }
     }
 }
 
 #[derive(Debug, Default, Serialize)]
 pub struct DeploymentStatusOptions {
-    state: State,
+    state: DeploymentState,
     #[serde(skip_serializing_if = "Option::is_none")]
     target_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    environment_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    log_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_inactive: Option<bool>,
 }
 
 impl DeploymentStatusOptions {
-    pub fn builder(state: State) -> DeploymentStatusOptionsBuilder {
+    pub fn builder(state: DeploymentState) -> DeploymentStatusOptionsBuilder {
         DeploymentStatusOptionsBuilder::new(state)
     }
+
+    /// the media type that enables the state and fields of these options
+    fn media(&self) -> MediaType {
+        match self.state {
+            DeploymentState::InProgress | DeploymentState::Queued => FLASH_PREVIEW,
+            DeploymentState::Inactive => ANT_MAN_PREVIEW,
+            _ if self.environment_url.is_some()
+                || self.log_url.is_some()
+                || self.auto_inactive.is_some() =>
+            {
+                ANT_MAN_PREVIEW
+            }
+            _ => MediaType::Json,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -353,14 +453,11 @@ impl DeploymentListOptionsBuilder {
 mod tests {
     use std::collections::BTreeMap;
     use serde::ser::Serialize;
-    use super::{DeploymentOptions, DeploymentStatusOptions};
-    use crate::statuses::State;
+    use super::{DeploymentOptions, DeploymentState, DeploymentStatusOptions};
 
     fn test_encoding<E: Serialize>(tests: Vec<(E, &str)>) {
-        for test in tests {
-            match test {
-                (k, v) => assert_eq!(serde_json::to_string(&k).unwrap(), v),
-            }
+        for (k, v) in tests {
+            assert_eq!(serde_json::to_string(&k).unwrap(), v);
         }
     }
 
@@ -399,22 +496,35 @@ mod tests {
     fn deployment_status_reqs() {
         let tests = vec![
             (
-                DeploymentStatusOptions::builder(State::Pending).build(),
+                DeploymentStatusOptions::builder(DeploymentState::Pending).build(),
                 r#"{"state":"pending"}"#,
             ),
             (
-                DeploymentStatusOptions::builder(State::Pending)
+                DeploymentStatusOptions::builder(DeploymentState::Pending)
                     .target_url("http://host.com")
                     .build(),
                 r#"{"state":"pending","target_url":"http://host.com"}"#,
             ),
             (
-                DeploymentStatusOptions::builder(State::Pending)
+                DeploymentStatusOptions::builder(DeploymentState::Pending)
                     .target_url("http://host.com")
                     .description("desc")
                     .build(),
                 r#"{"state":"pending","target_url":"http://host.com","description":"desc"}"#,
             ),
+            (
+                DeploymentStatusOptions::builder(DeploymentState::InProgress)
+                    .log_url("http://host.com/log")
+                    .environment_url("http://staging.host.com")
+                    .auto_inactive(false)
+                    .build(),
+                concat!(
+                    "{",
+                    r#""state":"in_progress","environment_url":"http://staging.host.com","#,
+                    r#""log_url":"http://host.com/log","auto_inactive":false"#,
+                    "}"
+                ),
+            ),
         ];
         test_encoding(tests)
     }
@@ -0,0 +1,447 @@
+//! Discussions interface
+//!
+//! Repository discussions are only exposed through github's
+//! [graphql api](https://developer.github.com/v4/object/discussion/), so
+//! discussions and their comments are identified by graphql node ids.
+use futures::Future as StdFuture;
+use serde::Deserialize;
+
+use crate::{DateTime, Future, Github, PageInfo};
+
+macro_rules! comment_fields {
+    () => {
+        "id databaseId url body author { login url avatarUrl } createdAt updatedAt isAnswer replyTo { id }"
+    };
+}
+
+const DISCUSSION_QUERY: &str = "
+query($owner: String!, $repo: String!, $number: Int!) {
+  repository(owner: $owner, name: $repo) {
+    discussion(number: $number) {
+      id number title body url author { login url avatarUrl }
+      createdAt updatedAt isAnswered answerChosenAt
+    }
+  }
+}";
+
+const COMMENTS_QUERY: &str = concat!(
+    "
+query($owner: String!, $repo: String!, $number: Int!, $first: Int!, $after: String) {
+  repository(owner: $owner, name: $repo) {
+    discussion(number: $number) {
+      comments(first: $first, after: $after) {
+        totalCount
+        pageInfo { hasNextPage endCursor }
+        nodes { ",
+    comment_fields!(),
+    " }
+      }
+    }
+  }
+}"
+);
+
+const ADD_COMMENT_MUTATION: &str = concat!(
+    "
+mutation($discussion: ID!, $body: String!, $replyTo: ID) {
+  addDiscussionComment(input: {discussionId: $discussion, body: $body, replyToId: $replyTo}) {
+    comment { ",
+    comment_fields!(),
+    " }
+  }
+}"
+);
+
+const UPDATE_COMMENT_MUTATION: &str = concat!(
+    "
+mutation($comment: ID!, $body: String!) {
+  updateDiscussionComment(input: {commentId: $comment, body: $body}) {
+    comment { ",
+    comment_fields!(),
+    " }
+  }
+}"
+);
+
+const DELETE_COMMENT_MUTATION: &str = "
+mutation($comment: ID!) {
+  deleteDiscussionComment(input: {id: $comment}) { clientMutationId }
+}";
+
+const MARK_ANSWER_MUTATION: &str = "
+mutation($comment: ID!) {
+  markDiscussionCommentAsAnswer(input: {id: $comment}) { clientMutationId }
+}";
+
+const UNMARK_ANSWER_MUTATION: &str = "
+mutation($comment: ID!) {
+  unmarkDiscussionCommentAsAnswer(input: {id: $comment}) { clientMutationId }
+}";
+
+/// A reference to a repository discussion
+pub struct DiscussionRef {
+    github: Github,
+    owner: String,
+    repo: String,
+    number: u64,
+}
+
+impl DiscussionRef {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R, number: u64) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        DiscussionRef {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+            number,
+        }
+    }
+
+    /// get this discussion
+    pub fn get(&self) -> Future<Discussion> {
+        Box::new(
+            self.github
+                .graphql::<RepositoryData<DiscussionData>>(
+                    DISCUSSION_QUERY,
+                    serde_json::json!({
+                        "owner": self.owner,
+                        "repo": self.repo,
+                        "number": self.number
+                    }),
+                )
+                .map(|data| data.repository.discussion),
+        )
+    }
+
+    /// Return a reference to comment operations available for this discussion
+    pub fn comments(&self) -> DiscussionComments {
+        DiscussionComments::new(
+            self.github.clone(),
+            self.owner.clone(),
+            self.repo.clone(),
+            self.number,
+        )
+    }
+}
+
+/// A structure for interfacing with discussion comments
+pub struct DiscussionComments {
+    github: Github,
+    owner: String,
+    repo: String,
+    number: u64,
+}
+
+impl DiscussionComments {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R, number: u64) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        DiscussionComments {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+            number,
+        }
+    }
+
+    /// list a page of this discussion's top level comments
+    pub fn list(&self, options: &DiscussionCommentListOptions) -> Future<DiscussionCommentPage> {
+        Box::new(
+            self.github
+                .graphql::<RepositoryData<CommentsData>>(
+                    COMMENTS_QUERY,
+                    serde_json::json!({
+                        "owner": self.owner,
+                        "repo": self.repo,
+                        "number": self.number,
+                        "first": options.per_page.unwrap_or(30),
+                        "after": options.after
+                    }),
+                )
+                .map(|data| data.repository.discussion.comments),
+        )
+    }
+
+    /// add a new comment, optionally in reply to an existing top level comment
+    pub fn create(&self, comment: &DiscussionCommentOptions) -> Future<DiscussionComment> {
+        let github = self.github.clone();
+        let body = comment.body.clone();
+        let reply_to = comment.reply_to.clone();
+        Box::new(
+            DiscussionRef::new(
+                self.github.clone(),
+                self.owner.as_str(),
+                self.repo.as_str(),
+                self.number,
+            )
+            .get()
+            .and_then(move |discussion| {
+                github.graphql::<AddCommentData>(
+                    ADD_COMMENT_MUTATION,
+                    serde_json::json!({
+                        "discussion": discussion.id,
+                        "body": body,
+                        "replyTo": reply_to
+                    }),
+                )
+            })
+            .map(|data| data.add_discussion_comment.comment),
+        )
+    }
+
+    /// update the body of a comment
+    pub fn edit<I, B>(&self, id: I, body: B) -> Future<DiscussionComment>
+    where
+        I: Into<String>,
+        B: Into<String>,
+    {
+        Box::new(
+            self.github
+                .graphql::<UpdateCommentData>(
+                    UPDATE_COMMENT_MUTATION,
+                    serde_json::json!({ "comment": id.into(), "body": body.into() }),
+                )
+                .map(|data| data.update_discussion_comment.comment),
+        )
+    }
+
+    /// delete a comment
+    pub fn delete<I>(&self, id: I) -> Future<()>
+    where
+        I: Into<String>,
+    {
+        self.mutate(DELETE_COMMENT_MUTATION, id.into())
+    }
+
+    /// mark a comment as the answer to this discussion. the discussion must
+    /// belong to a category which accepts answers
+    pub fn mark_answer<I>(&self, id: I) -> Future<()>
+    where
+        I: Into<String>,
+    {
+        self.mutate(MARK_ANSWER_MUTATION, id.into())
+    }
+
+    /// unmark a comment as the answer to this discussion
+    pub fn unmark_answer<I>(&self, id: I) -> Future<()>
+    where
+        I: Into<String>,
+    {
+        self.mutate(UNMARK_ANSWER_MUTATION, id.into())
+    }
+
+    fn mutate(&self, mutation: &str, id: String) -> Future<()> {
+        Box::new(
+            self.github
+                .graphql::<serde_json::Value>(mutation, serde_json::json!({ "comment": id }))
+                .map(|_| ()),
+        )
+    }
+}
+
+// representations
+
+/// The author of a discussion or comment
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Actor {
+    pub login: String,
+    pub url: String,
+    pub avatar_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Discussion {
+    /// the graphql node id of this discussion
+    pub id: String,
+    pub number: u64,
+    pub title: String,
+    pub body: String,
+    pub url: String,
+    /// `None` when the author's account has been deleted
+    pub author: Option<Actor>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    /// `None` when the discussion's category doesn't accept answers
+    pub is_answered: Option<bool>,
+    pub answer_chosen_at: Option<DateTime>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscussionComment {
+    /// the graphql node id of this comment
+    pub id: String,
+    pub database_id: Option<u64>,
+    pub url: String,
+    pub body: String,
+    /// `None` when the author's account has been deleted
+    pub author: Option<Actor>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub is_answer: bool,
+    /// the top level comment this comment replies to
+    pub reply_to: Option<CommentRef>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CommentRef {
+    pub id: String,
+}
+
+/// A page of discussion comments
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscussionCommentPage {
+    pub total_count: u64,
+    pub page_info: PageInfo,
+    pub nodes: Vec<DiscussionComment>,
+}
+
+#[derive(Debug, Default)]
+pub struct DiscussionCommentOptions {
+    pub body: String,
+    /// the node id of the top level comment to reply to
+    pub reply_to: Option<String>,
+}
+
+impl DiscussionCommentOptions {
+    pub fn new<B>(body: B) -> Self
+    where
+        B: Into<String>,
+    {
+        DiscussionCommentOptions {
+            body: body.into(),
+            reply_to: None,
+        }
+    }
+
+    pub fn reply<I, B>(reply_to: I, body: B) -> Self
+    where
+        I: Into<String>,
+        B: Into<String>,
+    {
+        DiscussionCommentOptions {
+            body: body.into(),
+            reply_to: Some(reply_to.into()),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct DiscussionCommentListOptions {
+    per_page: Option<u32>,
+    after: Option<String>,
+}
+
+impl DiscussionCommentListOptions {
+    pub fn builder() -> DiscussionCommentListOptionsBuilder {
+        DiscussionCommentListOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct DiscussionCommentListOptionsBuilder(DiscussionCommentListOptions);
+
+impl DiscussionCommentListOptionsBuilder {
+    /// the number of comments to list, up to 100. defaults to 30
+    pub fn per_page(&mut self, n: u32) -> &mut Self {
+        self.0.per_page = Some(n);
+        self
+    }
+
+    /// list the comments after a page's `end_cursor`
+    pub fn after<C>(&mut self, cursor: C) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        self.0.after = Some(cursor.into());
+        self
+    }
+
+    pub fn build(&self) -> DiscussionCommentListOptions {
+        DiscussionCommentListOptions {
+            per_page: self.0.per_page,
+            after: self.0.after.clone(),
+        }
+    }
+}
+
+// graphql response wrappers
+
+#[derive(Deserialize)]
+struct RepositoryData<D> {
+    repository: D,
+}
+
+#[derive(Deserialize)]
+struct DiscussionData {
+    discussion: Discussion,
+}
+
+#[derive(Deserialize)]
+struct CommentsData {
+    discussion: CommentsConnection,
+}
+
+#[derive(Deserialize)]
+struct CommentsConnection {
+    comments: DiscussionCommentPage,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AddCommentData {
+    add_discussion_comment: CommentPayload,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateCommentData {
+    update_discussion_comment: CommentPayload,
+}
+
+#[derive(Deserialize)]
+struct CommentPayload {
+    comment: DiscussionComment,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_comment_page() {
+        let data: RepositoryData<CommentsData> = serde_json::from_str(
+            r#"{"repository": {"discussion": {"comments": {
+                "totalCount": 1,
+                "pageInfo": {"hasNextPage": false, "endCursor": "Y3Vyc29yOjE="},
+                "nodes": [{
+                    "id": "DC_kwDOABCD",
+                    "databaseId": 42,
+                    "url": "https://github.com/octocat/Hello-World/discussions/1#discussioncomment-42",
+                    "body": "have you tried turning it off and on again?",
+                    "author": null,
+                    "createdAt": "2021-01-01T00:00:00Z",
+                    "updatedAt": "2021-01-01T00:00:00Z",
+                    "isAnswer": true,
+                    "replyTo": null
+                }]
+            }}}}"#,
+        )
+        .unwrap();
+        let page = data.repository.discussion.comments;
+        assert_eq!(page.page_info.end_cursor, Some("Y3Vyc29yOjE=".into()));
+        assert_eq!(page.nodes.len(), 1);
+        assert!(page.nodes[0].is_answer);
+        assert_eq!(page.nodes[0].author, None);
+    }
+}
@@ -0,0 +1,452 @@
+//! Enterprise administration interface
+//!
+//! These endpoints are only available on Github Enterprise Server instances,
+//! so the `Github` instance used must be created with `Github::host`
+//! pointing at the instance's api, like `https://github.example.com/api/v3`,
+//! and credentials of a site administrator.
+//!
+//! See the [github docs](https://developer.github.com/enterprise/v3/enterprise-admin/)
+//! for more information.
+use std::collections::HashMap;
+
+use futures::Future as StdFuture;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::{AuthenticationConstraint, DateTime, ErrorKind, Future, Github, MediaType};
+
+/// pre-receive endpoints are only available with this preview
+const PRE_RECEIVE_PREVIEW: MediaType = MediaType::Preview("eye-scream");
+
+/// Provides access to site administration of a Github Enterprise Server instance
+pub struct Enterprise {
+    github: Github,
+}
+
+impl Enterprise {
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        Self { github }
+    }
+
+    /// returns a reference to an interface for instance statistics
+    pub fn stats(&self) -> AdminStats {
+        AdminStats::new(self.github.clone())
+    }
+
+    /// returns a reference to an interface for pre-receive environment operations
+    pub fn pre_receive_environments(&self) -> PreReceiveEnvironments {
+        PreReceiveEnvironments::new(self.github.clone())
+    }
+
+    /// returns a reference to an interface for pre-receive hook operations
+    pub fn pre_receive_hooks(&self) -> PreReceiveHooks {
+        PreReceiveHooks::new(self.github.clone())
+    }
+}
+
+/// Provides access to the statistics of a Github Enterprise Server instance
+///
+/// See the [github docs](https://developer.github.com/enterprise/v3/enterprise-admin/admin_stats/)
+/// for more information.
+pub struct AdminStats {
+    github: Github,
+}
+
+impl AdminStats {
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        Self { github }
+    }
+
+    /// get all statistics of the instance at once
+    pub fn all(&self) -> Future<AllStats> {
+        self.github.get("/enterprise/stats/all")
+    }
+
+    pub fn comments(&self) -> Future<CommentStats> {
+        self.get("comments")
+    }
+
+    pub fn gists(&self) -> Future<GistStats> {
+        self.get("gists")
+    }
+
+    pub fn hooks(&self) -> Future<HookStats> {
+        self.get("hooks")
+    }
+
+    pub fn issues(&self) -> Future<IssueStats> {
+        self.get("issues")
+    }
+
+    pub fn milestones(&self) -> Future<MilestoneStats> {
+        self.get("milestones")
+    }
+
+    pub fn orgs(&self) -> Future<OrgStats> {
+        self.get("orgs")
+    }
+
+    pub fn pages(&self) -> Future<PageStats> {
+        self.get("pages")
+    }
+
+    pub fn pulls(&self) -> Future<PullStats> {
+        self.get("pulls")
+    }
+
+    pub fn repos(&self) -> Future<RepoStats> {
+        self.get("repos")
+    }
+
+    pub fn users(&self) -> Future<UserStats> {
+        self.get("users")
+    }
+
+    /// statistics of a kind are nested under the name of the kind
+    fn get<D>(&self, kind: &'static str) -> Future<D>
+    where
+        D: DeserializeOwned + 'static + Send,
+    {
+        Box::new(
+            self.github
+                .get::<HashMap<String, D>>(&format!("/enterprise/stats/{}", kind))
+                .and_then(move |mut stats| {
+                    stats
+                        .remove(kind)
+                        .ok_or_else(|| ErrorKind::Msg(format!("missing {} stats", kind)).into())
+                }),
+        )
+    }
+}
+
+/// Provides access to the environments pre-receive hooks run in
+///
+/// See the [github docs](https://developer.github.com/enterprise/v3/enterprise-admin/pre_receive_environments/)
+/// for more information.
+pub struct PreReceiveEnvironments {
+    github: Github,
+}
+
+impl PreReceiveEnvironments {
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        Self { github }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/admin/pre-receive-environments{}", more)
+    }
+
+    pub fn list(&self) -> Future<Vec<PreReceiveEnvironment>> {
+        self.github.get_media(&self.path(""), PRE_RECEIVE_PREVIEW)
+    }
+
+    pub fn get(&self, id: u64) -> Future<PreReceiveEnvironment> {
+        self.github
+            .get_media(&self.path(&format!("/{}", id)), PRE_RECEIVE_PREVIEW)
+    }
+
+    pub fn create(&self, env: &PreReceiveEnvironmentOptions) -> Future<PreReceiveEnvironment> {
+        self.github.post_media(
+            &self.path(""),
+            json!(env),
+            PRE_RECEIVE_PREVIEW,
+            AuthenticationConstraint::Unconstrained,
+        )
+    }
+
+    pub fn edit(
+        &self,
+        id: u64,
+        env: &PreReceiveEnvironmentOptions,
+    ) -> Future<PreReceiveEnvironment> {
+        self.github.patch_media(
+            &self.path(&format!("/{}", id)),
+            json!(env),
+            PRE_RECEIVE_PREVIEW,
+        )
+    }
+
+    pub fn delete(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.path(&format!("/{}", id)))
+    }
+
+    /// trigger a new download of the environment's image from its `image_url`
+    pub fn download(&self, id: u64) -> Future<PreReceiveEnvironmentDownload> {
+        self.github.post_media(
+            &self.path(&format!("/{}/downloads", id)),
+            Vec::new(),
+            PRE_RECEIVE_PREVIEW,
+            AuthenticationConstraint::Unconstrained,
+        )
+    }
+
+    /// get the state of the environment's most recent image download
+    pub fn latest_download(&self, id: u64) -> Future<PreReceiveEnvironmentDownload> {
+        self.github.get_media(
+            &self.path(&format!("/{}/downloads/latest", id)),
+            PRE_RECEIVE_PREVIEW,
+        )
+    }
+}
+
+/// Provides access to pre-receive hooks
+///
+/// See the [github docs](https://developer.github.com/enterprise/v3/enterprise-admin/pre_receive_hooks/)
+/// for more information.
+pub struct PreReceiveHooks {
+    github: Github,
+}
+
+impl PreReceiveHooks {
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        Self { github }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/admin/pre-receive-hooks{}", more)
+    }
+
+    pub fn list(&self) -> Future<Vec<PreReceiveHook>> {
+        self.github.get_media(&self.path(""), PRE_RECEIVE_PREVIEW)
+    }
+
+    pub fn get(&self, id: u64) -> Future<PreReceiveHook> {
+        self.github
+            .get_media(&self.path(&format!("/{}", id)), PRE_RECEIVE_PREVIEW)
+    }
+
+    pub fn create(&self, hook: &PreReceiveHookOptions) -> Future<PreReceiveHook> {
+        self.github.post_media(
+            &self.path(""),
+            json!(hook),
+            PRE_RECEIVE_PREVIEW,
+            AuthenticationConstraint::Unconstrained,
+        )
+    }
+
+    pub fn edit(&self, id: u64, hook: &PreReceiveHookOptions) -> Future<PreReceiveHook> {
+        self.github.patch_media(
+            &self.path(&format!("/{}", id)),
+            json!(hook),
+            PRE_RECEIVE_PREVIEW,
+        )
+    }
+
+    pub fn delete(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.path(&format!("/{}", id)))
+    }
+}
+
+// representations
+
+#[derive(Debug, Deserialize)]
+pub struct AllStats {
+    pub repos: RepoStats,
+    pub hooks: HookStats,
+    pub pages: PageStats,
+    pub orgs: OrgStats,
+    pub users: UserStats,
+    pub pulls: PullStats,
+    pub issues: IssueStats,
+    pub milestones: MilestoneStats,
+    pub gists: GistStats,
+    pub comments: CommentStats,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommentStats {
+    pub total_commit_comments: u64,
+    pub total_gist_comments: u64,
+    pub total_issue_comments: u64,
+    pub total_pull_request_comments: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GistStats {
+    pub total_gists: u64,
+    pub private_gists: u64,
+    pub public_gists: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HookStats {
+    pub total_hooks: u64,
+    pub active_hooks: u64,
+    pub inactive_hooks: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueStats {
+    pub total_issues: u64,
+    pub open_issues: u64,
+    pub closed_issues: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MilestoneStats {
+    pub total_milestones: u64,
+    pub open_milestones: u64,
+    pub closed_milestones: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OrgStats {
+    pub total_orgs: u64,
+    pub disabled_orgs: u64,
+    pub total_teams: u64,
+    pub total_team_members: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PageStats {
+    pub total_pages: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PullStats {
+    pub total_pulls: u64,
+    pub merged_pulls: u64,
+    pub mergeable_pulls: u64,
+    pub unmergeable_pulls: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RepoStats {
+    pub total_repos: u64,
+    pub root_repos: u64,
+    pub fork_repos: u64,
+    pub org_repos: u64,
+    pub total_pushes: u64,
+    pub total_wikis: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserStats {
+    pub total_users: u64,
+    pub admin_users: u64,
+    pub suspended_users: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PreReceiveEnvironment {
+    pub id: u64,
+    pub name: String,
+    /// url of the tarball the environment's image is downloaded from
+    pub image_url: String,
+    pub url: String,
+    pub html_url: String,
+    /// whether this is the default environment shipped with the instance,
+    /// which can not be modified
+    pub default_environment: bool,
+    pub created_at: Option<DateTime>,
+    pub hooks_count: u64,
+    pub download: PreReceiveEnvironmentDownload,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PreReceiveEnvironmentDownload {
+    pub url: String,
+    /// one of `not_started`, `in_progress`, `success`, or `failed`
+    pub state: String,
+    pub downloaded_at: Option<DateTime>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PreReceiveEnvironmentOptions {
+    pub name: String,
+    pub image_url: String,
+}
+
+impl PreReceiveEnvironmentOptions {
+    pub fn new<N, I>(name: N, image_url: I) -> Self
+    where
+        N: Into<String>,
+        I: Into<String>,
+    {
+        PreReceiveEnvironmentOptions {
+            name: name.into(),
+            image_url: image_url.into(),
+        }
+    }
+}
+
+/// The state of a pre-receive hook
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Enforcement {
+    Enabled,
+    Disabled,
+    /// the hook runs but failures do not reject pushes
+    Testing,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PreReceiveHook {
+    pub id: u64,
+    pub name: String,
+    pub enforcement: Enforcement,
+    /// path of the hook's script within its repository
+    pub script: String,
+    pub script_repository: ScriptRepository,
+    pub environment: PreReceiveEnvironment,
+    /// whether organizations and repositories may override the enforcement
+    pub allow_downstream_configuration: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScriptRepository {
+    pub id: u64,
+    pub full_name: String,
+    pub url: String,
+    pub html_url: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PreReceiveHookOptions {
+    pub name: String,
+    pub script: String,
+    script_repository: ScriptRepositoryRef,
+    environment: EnvironmentRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforcement: Option<Enforcement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_downstream_configuration: Option<bool>,
+}
+
+impl PreReceiveHookOptions {
+    /// creates options for a hook running `script`, a path within the
+    /// `script_repository` repository, like `owner/hooks`, in the pre-receive
+    /// environment identified by `environment`
+    pub fn new<N, S, R>(name: N, script: S, script_repository: R, environment: u64) -> Self
+    where
+        N: Into<String>,
+        S: Into<String>,
+        R: Into<String>,
+    {
+        PreReceiveHookOptions {
+            name: name.into(),
+            script: script.into(),
+            script_repository: ScriptRepositoryRef {
+                full_name: script_repository.into(),
+            },
+            environment: EnvironmentRef { id: environment },
+            enforcement: None,
+            allow_downstream_configuration: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ScriptRepositoryRef {
+    full_name: String,
+}
+
+#[derive(Debug, Serialize)]
+struct EnvironmentRef {
+    id: u64,
+}
@@ -0,0 +1,363 @@
+//! Environments interface
+//!
+//! Deployment environments may be protected by rules deployments to them must
+//! pass, like a wait timer, required reviewers, or which branches may deploy
+//! to them.
+//!
+//! See the [github docs](https://docs.github.com/en/rest/deployments/environments)
+//! for more information
+use percent_encoding::{utf8_percent_encode, PATH_SEGMENT_ENCODE_SET};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "actions")]
+use crate::actions::{Secrets, Variables};
+use crate::{DateTime, Future, Github};
+
+/// Provides access to the deployment environments of a repository
+pub struct Environments {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Environments {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Environments {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    /// list the environments of this repository
+    pub fn list(&self) -> Future<EnvironmentList> {
+        self.github.get(&format!(
+            "/repos/{}/{}/environments?per_page=100",
+            self.owner, self.repo
+        ))
+    }
+
+    /// get a reference to an environment by name
+    pub fn get<N>(&self, name: N) -> EnvironmentRef
+    where
+        N: Into<String>,
+    {
+        EnvironmentRef::new(
+            self.github.clone(),
+            self.owner.as_str(),
+            self.repo.as_str(),
+            name,
+        )
+    }
+}
+
+/// A reference to a repository's deployment environment
+pub struct EnvironmentRef {
+    github: Github,
+    owner: String,
+    repo: String,
+    name: String,
+}
+
+impl EnvironmentRef {
+    #[doc(hidden)]
+    pub fn new<O, R, N>(github: Github, owner: O, repo: R, name: N) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+        N: Into<String>,
+    {
+        EnvironmentRef {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+            name: name.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!(
+            "/repos/{}/{}/environments/{}{}",
+            self.owner,
+            self.repo,
+            utf8_percent_encode(&self.name, PATH_SEGMENT_ENCODE_SET),
+            more
+        )
+    }
+
+    /// get this environment and its protection rules
+    pub fn get(&self) -> Future<Environment> {
+        self.github.get(&self.path(""))
+    }
+
+    /// create this environment, or update its protection rules if it exists
+    pub fn create_or_update(&self, options: &EnvironmentOptions) -> Future<Environment> {
+        self.github.put(&self.path(""), json!(options))
+    }
+
+    /// delete this environment
+    pub fn delete(&self) -> Future<()> {
+        self.github.delete(&self.path(""))
+    }
+
+    /// list the branch and tag name patterns which may deploy to this
+    /// environment. only applies to environments with custom branch policies
+    pub fn branch_policies(&self) -> Future<BranchPolicyList> {
+        self.github
+            .get(&self.path("/deployment-branch-policies?per_page=100"))
+    }
+
+    /// allow branches, or tags, with names matching a pattern, like
+    /// `release/*`, to deploy to this environment
+    pub fn create_branch_policy(&self, policy: &BranchPolicyOptions) -> Future<BranchPolicy> {
+        self.github
+            .post(&self.path("/deployment-branch-policies"), json!(policy))
+    }
+
+    /// change the name pattern of a branch policy
+    pub fn update_branch_policy<N>(&self, id: u64, name: N) -> Future<BranchPolicy>
+    where
+        N: Into<String>,
+    {
+        self.github.put(
+            &self.path(&format!("/deployment-branch-policies/{}", id)),
+            json_lit!({ "name": name.into() }),
+        )
+    }
+
+    /// delete a branch policy
+    pub fn delete_branch_policy(&self, id: u64) -> Future<()> {
+        self.github
+            .delete(&self.path(&format!("/deployment-branch-policies/{}", id)))
+    }
+
+    /// get a reference to the [secrets](https://docs.github.com/en/rest/actions/secrets)
+    /// scoped to this environment
+    #[cfg(feature = "actions")]
+    pub fn secrets(&self) -> Secrets {
+        Secrets::for_environment(
+            self.github.clone(),
+            self.owner.as_str(),
+            self.repo.as_str(),
+            self.name.as_str(),
+        )
+    }
+
+    /// get a reference to the [variables](https://docs.github.com/en/rest/actions/variables)
+    /// scoped to this environment
+    #[cfg(feature = "actions")]
+    pub fn variables(&self) -> Variables {
+        Variables::for_environment(
+            self.github.clone(),
+            self.owner.as_str(),
+            self.repo.as_str(),
+            self.name.as_str(),
+        )
+    }
+}
+
+// representations
+
+#[derive(Debug, Deserialize)]
+pub struct EnvironmentList {
+    pub total_count: u64,
+    pub environments: Vec<Environment>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Environment {
+    pub id: u64,
+    pub node_id: String,
+    pub name: String,
+    pub url: String,
+    pub html_url: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    #[serde(default)]
+    pub protection_rules: Vec<ProtectionRule>,
+    /// which branches may deploy to this environment. `None` when any
+    /// branch may
+    pub deployment_branch_policy: Option<DeploymentBranchPolicy>,
+}
+
+/// A rule deployments to an environment must pass
+#[derive(Debug, Deserialize)]
+pub struct ProtectionRule {
+    pub id: u64,
+    pub node_id: String,
+    /// one of `wait_timer`, `required_reviewers`, or `branch_policy`
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// the minutes deployments wait before proceeding, for `wait_timer` rules
+    pub wait_timer: Option<u32>,
+    /// whether the user triggering a deployment can't approve it, for
+    /// `required_reviewers` rules
+    pub prevent_self_review: Option<bool>,
+    /// who may approve deployments, for `required_reviewers` rules
+    #[serde(default)]
+    pub reviewers: Vec<EnvironmentReviewer>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EnvironmentReviewer {
+    #[serde(rename = "type")]
+    pub kind: ReviewerType,
+    pub reviewer: Reviewer,
+}
+
+/// A user or team which may approve deployments
+#[derive(Debug, Deserialize)]
+pub struct Reviewer {
+    pub id: u64,
+    pub html_url: String,
+    /// the login of a user
+    pub login: Option<String>,
+    /// the name of a team
+    pub name: Option<String>,
+    /// the slug of a team
+    pub slug: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub enum ReviewerType {
+    User,
+    Team,
+}
+
+/// Which branches may deploy to an environment
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub struct DeploymentBranchPolicy {
+    /// only branches with branch protection rules may deploy
+    pub protected_branches: bool,
+    /// only branches matching the environment's branch policies may deploy
+    pub custom_branch_policies: bool,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct EnvironmentOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_timer: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prevent_self_review: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reviewers: Option<Vec<ReviewerOptions>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_branch_policy: Option<DeploymentBranchPolicy>,
+}
+
+impl EnvironmentOptions {
+    pub fn builder() -> EnvironmentOptionsBuilder {
+        EnvironmentOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct EnvironmentOptionsBuilder(EnvironmentOptions);
+
+impl EnvironmentOptionsBuilder {
+    /// the minutes to wait before deployments proceed, up to 43,200 (30 days)
+    pub fn wait_timer(&mut self, minutes: u32) -> &mut Self {
+        self.0.wait_timer = Some(minutes);
+        self
+    }
+
+    pub fn prevent_self_review(&mut self, prevent: bool) -> &mut Self {
+        self.0.prevent_self_review = Some(prevent);
+        self
+    }
+
+    /// require a user or team, by id, to approve deployments. up to six
+    /// reviewers may be required
+    pub fn reviewer(&mut self, kind: ReviewerType, id: u64) -> &mut Self {
+        self.0
+            .reviewers
+            .get_or_insert_with(Vec::new)
+            .push(ReviewerOptions { kind, id });
+        self
+    }
+
+    pub fn deployment_branch_policy(&mut self, policy: DeploymentBranchPolicy) -> &mut Self {
+        self.0.deployment_branch_policy = Some(policy);
+        self
+    }
+
+    pub fn build(&self) -> EnvironmentOptions {
+        EnvironmentOptions {
+            wait_timer: self.0.wait_timer,
+            prevent_self_review: self.0.prevent_self_review,
+            reviewers: self.0.reviewers.clone(),
+            deployment_branch_policy: self.0.deployment_branch_policy,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ReviewerOptions {
+    #[serde(rename = "type")]
+    pub kind: ReviewerType,
+    pub id: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BranchPolicyList {
+    pub total_count: u64,
+    pub branch_policies: Vec<BranchPolicy>,
+}
+
+/// A pattern of branch or tag names which may deploy to an environment
+#[derive(Debug, Deserialize)]
+pub struct BranchPolicy {
+    pub id: u64,
+    pub node_id: String,
+    /// like `release/*`
+    pub name: String,
+    /// `branch` or `tag`
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BranchPolicyOptions {
+    pub name: String,
+    /// `branch` or `tag`. defaults to `branch`
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+}
+
+impl BranchPolicyOptions {
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        BranchPolicyOptions {
+            name: name.into(),
+            kind: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn environment_reqs() {
+        let options = EnvironmentOptions::builder()
+            .wait_timer(30)
+            .reviewer(ReviewerType::Team, 1)
+            .deployment_branch_policy(DeploymentBranchPolicy {
+                protected_branches: false,
+                custom_branch_policies: true,
+            })
+            .build();
+        assert_eq!(
+            serde_json::to_string(&options).unwrap(),
+            r#"{"wait_timer":30,"reviewers":[{"type":"Team","id":1}],"deployment_branch_policy":{"protected_branches":false,"custom_branch_policies":true}}"#
+        );
+    }
+}
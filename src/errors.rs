@@ -1,6 +1,8 @@
 //! Client errors
+// error_chain checks for a cfg set by its own build script, which newer
+// compilers flag as unexpected
+#![allow(unexpected_cfgs)]
 use std::io::Error as IoError;
-use std::time::Duration;
 
 use error_chain::*;
 use http::StatusCode;
@@ -10,22 +12,73 @@ use serde_json::error::Error as SerdeError;
 use url::ParseError;
 
 use crate::jwt::errors::Error as JWTError;
+use crate::DateTime;
 
 error_chain! {
     errors {
-        #[doc = "Client side error returned for faulty requests"]
+        #[doc = "Client side error returned for faulty requests. Includes the X-GitHub-Request-Id \
+                 of the failed request, which Github support can use to look into failures"]
         Fault {
             code: StatusCode,
-            error: ClientError,
+            error: Box<ClientError>,
+            request_id: Option<String>,
+            url: String,
         } {
             display("{}: '{}'", code, error.message)
             description(error.message.as_str())
           }
-        #[doc = "Error kind returned when a credential's rate limit has been exhausted. Wait for the reset duration before issuing more requests"]
+        #[doc = "Error kind returned when a credential's rate limit has been exhausted, or a request tripped one of \
+                 github's secondary rate limits. Wait until the reset time before issuing more requests. \
+                 The resource is the rate limit bucket which was exhausted, like `core`, `search`, or `graphql`"]
         RateLimit {
-            reset: Duration
+            reset: DateTime,
+            remaining: u32,
+            limit: u32,
+            resource: Option<String>
         } {
-            display("Rate limit exhausted. Will reset in {} seconds", reset.as_secs())
+            display(
+                "{} rate limit exhausted. Will reset at {}",
+                resource.as_ref().map(String::as_str).unwrap_or("API"),
+                reset
+            )
+        }
+        #[doc = "Error kind returned when a request is forbidden because the credentials lack an oauth scope \
+                 it requires, like `delete_repo` for deleting repositories, according to the `X-OAuth-Scopes` \
+                 and `X-Accepted-OAuth-Scopes` headers github answers with. Requests forbidden for other reasons, \
+                 like lacking admin rights, fail with `Fault`"]
+        MissingScope {
+            scope: String,
+            message: String,
+            request_id: Option<String>,
+            url: String
+        } {
+            display("{} (requires the {} scope)", message, scope)
+        }
+        #[doc = "Error kind returned when a conditional request's resource has not been modified"]
+        NotModified {
+            display("Not modified")
+        }
+        #[doc = "Error kind returned when a graphql query resolves with errors"]
+        GraphQL {
+            errors: Vec<GraphQLError>
+        } {
+            display(
+                "GraphQL query failed: {}",
+                errors.iter().map(|e| e.message.as_str()).collect::<Vec<_>>().join(", ")
+            )
+        }
+        #[doc = "Error kind returned when an oauth authorization fails, like when the user denies access or a device code expires"]
+        OAuth {
+            error: String,
+            description: Option<String>
+        } {
+            display("OAuth authorization failed: {}", description.as_ref().unwrap_or(error))
+        }
+        #[doc = "Error kind returned in strict deserialization mode when a response contains fields a representation doesn't define"]
+        UnknownFields {
+            fields: Vec<String>
+        } {
+            display("Response contained unknown fields: {}", fields.join(", "))
         }
     }
     foreign_links {
@@ -55,13 +108,22 @@ pub struct ClientError {
     pub documentation_url: Option<String>,
 }
 
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct GraphQLError {
+    pub message: String,
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+    /// the path of the field which failed to resolve
+    pub path: Option<Vec<serde_json::Value>>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::{ClientError, FieldErr};
     use serde_json;
     #[test]
     fn deserialize_client_field_errors() {
-        for (json, expect) in vec![
+        for (json, expect) in [
             // see https://github.com/softprops/hubcaps/issues/31
             (
                 r#"{"message": "Validation Failed","errors":
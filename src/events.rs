@@ -0,0 +1,415 @@
+//! Events interface
+//!
+//! Events are the activity feeds of github, like pushes to repositories and
+//! the issues users open. Github asks clients polling for events to wait
+//! for the interval given by the `X-Poll-Interval` header between polls,
+//! which `Events#poll` exposes along with the etag to make the next poll
+//! conditional with.
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/activity/events/)
+use std::time::Duration;
+
+use futures::Future as StdFuture;
+use http::header::{HeaderMap, HeaderValue, ETAG, IF_NONE_MATCH};
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+
+#[cfg(feature = "issues")]
+use crate::comments::Comment;
+#[cfg(feature = "issues")]
+use crate::issues::Issue;
+#[cfg(feature = "pulls")]
+use crate::pulls::Pull;
+#[cfg(feature = "releases")]
+use crate::releases::Release;
+use crate::repositories::Repo;
+use crate::{
+    poll_interval, DateTime, Error, ErrorKind, Future, Github, Stream, DEFAULT_POLL_INTERVAL,
+};
+
+/// Provides access to a feed of events
+pub struct Events {
+    github: Github,
+    path: String,
+}
+
+impl Events {
+    /// public events of all of github
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        Events {
+            github,
+            path: "/events".into(),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn for_repo<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Events {
+            github,
+            path: format!("/repos/{}/{}/events", owner.into(), repo.into()),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn for_org<O>(github: Github, org: O) -> Self
+    where
+        O: Into<String>,
+    {
+        Events {
+            github,
+            path: format!("/orgs/{}/events", org.into()),
+        }
+    }
+
+    /// events performed by a user
+    #[doc(hidden)]
+    pub fn for_user<U>(github: Github, user: U) -> Self
+    where
+        U: Into<String>,
+    {
+        Events {
+            github,
+            path: format!("/users/{}/events", user.into()),
+        }
+    }
+
+    /// events of the repositories and users a user watches and follows
+    #[doc(hidden)]
+    pub fn received_by<U>(github: Github, user: U) -> Self
+    where
+        U: Into<String>,
+    {
+        Events {
+            github,
+            path: format!("/users/{}/received_events", user.into()),
+        }
+    }
+
+    /// list the most recent events, newest first
+    pub fn list(&self) -> Future<Vec<Event>> {
+        self.github.get(&self.path)
+    }
+
+    /// provides a stream over all pages of events. github only keeps the
+    /// last 300 events of a feed
+    pub fn iter(&self) -> Stream<Event> {
+        self.github.get_stream(&self.path)
+    }
+
+    /// list the most recent events along with when to poll for more. pass
+    /// the `etag` of the previous poll to only get events if there are new
+    /// ones. conditional polls without new events yield no events and don't
+    /// count against your rate limit
+    pub fn poll<E>(&self, etag: E) -> Future<EventPoll>
+    where
+        E: Into<Option<String>>,
+    {
+        let etag = etag.into();
+        let mut headers = HeaderMap::new();
+        if let Some(value) = etag.as_ref().and_then(|etag| HeaderValue::from_str(etag).ok()) {
+            headers.insert(IF_NONE_MATCH, value);
+        }
+        Box::new(
+            self.github
+                .get_with_headers::<Vec<Event>>(&self.path, headers)
+                .map(|(headers, events)| EventPoll {
+                    events,
+                    poll_interval: poll_interval(&headers),
+                    etag: headers
+                        .get(ETAG)
+                        .and_then(|value| value.to_str().ok())
+                        .map(String::from),
+                })
+                .or_else(move |err| match err {
+                    Error(ErrorKind::NotModified, _) => Ok(EventPoll {
+                        events: Vec::new(),
+                        poll_interval: DEFAULT_POLL_INTERVAL,
+                        etag,
+                    }),
+                    otherwise => Err(otherwise),
+                }),
+        )
+    }
+}
+
+// representations
+
+/// The result of polling a feed of events
+#[derive(Debug)]
+pub struct EventPoll {
+    /// new events, newest first
+    pub events: Vec<Event>,
+    /// how long github asks to wait before polling again
+    pub poll_interval: Duration,
+    /// pass to the next poll to make it conditional
+    pub etag: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Event {
+    pub id: String,
+    pub actor: Actor,
+    pub repo: EventRepo,
+    pub org: Option<Actor>,
+    pub public: bool,
+    pub created_at: DateTime,
+    pub payload: EventPayload,
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawEvent::deserialize(deserializer)?;
+        Ok(Event {
+            payload: EventPayload::parse(&raw.kind, raw.payload).map_err(de::Error::custom)?,
+            id: raw.id,
+            actor: raw.actor,
+            repo: raw.repo,
+            org: raw.org,
+            public: raw.public,
+            created_at: raw.created_at,
+        })
+    }
+}
+
+/// an event as github represents it, with its payload described by its type
+#[derive(Deserialize)]
+struct RawEvent {
+    id: String,
+    #[serde(rename = "type")]
+    kind: String,
+    actor: Actor,
+    repo: EventRepo,
+    org: Option<Actor>,
+    public: bool,
+    created_at: DateTime,
+    payload: serde_json::Value,
+}
+
+/// The payload of an event, according to its type
+#[derive(Debug)]
+pub enum EventPayload {
+    Push(PushPayload),
+    Create(CreatePayload),
+    Delete(DeletePayload),
+    Fork(Box<ForkPayload>),
+    Watch(WatchPayload),
+    #[cfg(feature = "issues")]
+    Issues(Box<IssuesPayload>),
+    #[cfg(feature = "issues")]
+    IssueComment(Box<IssueCommentPayload>),
+    #[cfg(feature = "pulls")]
+    PullRequest(Box<PullRequestPayload>),
+    #[cfg(feature = "releases")]
+    Release(Box<ReleasePayload>),
+    /// an event hubcaps doesn't define a payload for, with its type and raw
+    /// payload
+    Other(String, serde_json::Value),
+}
+
+impl EventPayload {
+    fn parse(kind: &str, payload: serde_json::Value) -> serde_json::Result<EventPayload> {
+        Ok(match kind {
+            "PushEvent" => EventPayload::Push(serde_json::from_value(payload)?),
+            "CreateEvent" => EventPayload::Create(serde_json::from_value(payload)?),
+            "DeleteEvent" => EventPayload::Delete(serde_json::from_value(payload)?),
+            "ForkEvent" => EventPayload::Fork(serde_json::from_value(payload)?),
+            "WatchEvent" => EventPayload::Watch(serde_json::from_value(payload)?),
+            #[cfg(feature = "issues")]
+            "IssuesEvent" => EventPayload::Issues(serde_json::from_value(payload)?),
+            #[cfg(feature = "issues")]
+            "IssueCommentEvent" => EventPayload::IssueComment(serde_json::from_value(payload)?),
+            #[cfg(feature = "pulls")]
+            "PullRequestEvent" => EventPayload::PullRequest(serde_json::from_value(payload)?),
+            #[cfg(feature = "releases")]
+            "ReleaseEvent" => EventPayload::Release(serde_json::from_value(payload)?),
+            other => EventPayload::Other(other.to_string(), payload),
+        })
+    }
+}
+
+/// The user or organization behind an event
+#[derive(Debug, Deserialize)]
+pub struct Actor {
+    pub id: u64,
+    pub login: String,
+    pub display_login: Option<String>,
+    pub gravatar_id: Option<String>,
+    pub url: String,
+    pub avatar_url: String,
+}
+
+/// The repository an event happened in
+#[derive(Debug, Deserialize)]
+pub struct EventRepo {
+    pub id: u64,
+    /// the full name of the repository, like `octocat/Hello-World`
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PushPayload {
+    pub push_id: Option<u64>,
+    /// the number of commits pushed
+    pub size: u64,
+    /// the number of commits pushed that weren't pushed before
+    pub distinct_size: u64,
+    /// the full ref that was pushed, like `refs/heads/main`
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub head: String,
+    pub before: String,
+    #[serde(default)]
+    pub commits: Vec<PushPayloadCommit>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PushPayloadCommit {
+    pub sha: String,
+    pub author: PushPayloadAuthor,
+    pub message: String,
+    pub distinct: bool,
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PushPayloadAuthor {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreatePayload {
+    /// `None` when a repository was created
+    #[serde(rename = "ref")]
+    pub git_ref: Option<String>,
+    /// one of `repository`, `branch`, or `tag`
+    pub ref_type: String,
+    pub master_branch: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeletePayload {
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    /// one of `branch` or `tag`
+    pub ref_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ForkPayload {
+    /// the newly created fork
+    pub forkee: Repo,
+}
+
+/// A user starring a repository
+#[derive(Debug, Deserialize)]
+pub struct WatchPayload {
+    /// always `started`
+    pub action: String,
+}
+
+#[cfg(feature = "issues")]
+#[derive(Debug, Deserialize)]
+pub struct IssuesPayload {
+    /// like `opened`, `closed`, or `reopened`
+    pub action: String,
+    pub issue: Issue,
+}
+
+#[cfg(feature = "issues")]
+#[derive(Debug, Deserialize)]
+pub struct IssueCommentPayload {
+    /// always `created`
+    pub action: String,
+    pub issue: Issue,
+    pub comment: Comment,
+}
+
+#[cfg(feature = "pulls")]
+#[derive(Debug, Deserialize)]
+pub struct PullRequestPayload {
+    /// like `opened`, `closed`, or `reopened`
+    pub action: String,
+    pub number: u64,
+    pub pull_request: Pull,
+}
+
+#[cfg(feature = "releases")]
+#[derive(Debug, Deserialize)]
+pub struct ReleasePayload {
+    /// always `published`
+    pub action: String,
+    pub release: Release,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_events() {
+        let events: Vec<Event> = serde_json::from_value(serde_json::json!([
+            {
+                "id": "22249084947",
+                "type": "DeleteEvent",
+                "actor": {
+                    "id": 583231,
+                    "login": "octocat",
+                    "display_login": "octocat",
+                    "gravatar_id": "",
+                    "url": "https://api.github.com/users/octocat",
+                    "avatar_url": "https://avatars.githubusercontent.com/u/583231?"
+                },
+                "repo": {
+                    "id": 1296269,
+                    "name": "octocat/Hello-World",
+                    "url": "https://api.github.com/repos/octocat/Hello-World"
+                },
+                "payload": {"ref": "feature", "ref_type": "branch"},
+                "public": true,
+                "created_at": "2022-06-09T12:47:28Z"
+            },
+            {
+                "id": "22249084964",
+                "type": "MemberEvent",
+                "actor": {
+                    "id": 583231,
+                    "login": "octocat",
+                    "url": "https://api.github.com/users/octocat",
+                    "avatar_url": "https://avatars.githubusercontent.com/u/583231?"
+                },
+                "repo": {
+                    "id": 1296269,
+                    "name": "octocat/Hello-World",
+                    "url": "https://api.github.com/repos/octocat/Hello-World"
+                },
+                "payload": {"action": "added"},
+                "public": true,
+                "created_at": "2022-06-07T07:50:26Z"
+            }
+        ]))
+        .unwrap();
+        match events[0].payload {
+            EventPayload::Delete(ref delete) => assert_eq!(delete.git_ref, "feature"),
+            ref other => panic!("expected a delete event, got {:?}", other),
+        }
+        match events[1].payload {
+            EventPayload::Other(ref kind, ref payload) => {
+                assert_eq!(kind, "MemberEvent");
+                assert_eq!(payload["action"], "added");
+            }
+            ref other => panic!("expected an unknown event, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Repo forks interface
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/repos/forks/)
+use std::collections::HashMap;
+use std::fmt;
+
+use url::form_urlencoded;
+
+use crate::repositories::Repo;
+use crate::{Future, Github, Stream};
+
+/// Describes sorting options for forks
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Sort {
+    Newest,
+    Oldest,
+    Stargazers,
+}
+
+impl fmt::Display for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Sort::Newest => "newest",
+            Sort::Oldest => "oldest",
+            Sort::Stargazers => "stargazers",
+        }
+        .fmt(f)
+    }
+}
+
+/// Provides access to the forks of a repository
+pub struct Forks {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Forks {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Forks {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self) -> String {
+        format!("/repos/{}/{}/forks", self.owner, self.repo)
+    }
+
+    /// list the forks of this repository
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/forks/#list-forks)
+    /// for more information.
+    pub fn list(&self, options: &ForkListOptions) -> Future<Vec<Repo>> {
+        let mut uri = vec![self.path()];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get(&uri.join("?"))
+    }
+
+    /// provides a stream over all pages of forks of this repository
+    ///
+    /// Note: You'll typically want to use a `ForkListOptions` with a `per_page`
+    /// of 100 for maximum api credential rate limit efficency
+    pub fn iter(&self, options: &ForkListOptions) -> Stream<Repo> {
+        let mut uri = vec![self.path()];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get_stream(&uri.join("?"))
+    }
+}
+
+/// Options used to sort repository fork listings
+///
+/// See the [github docs](https://developer.github.com/v3/repos/forks/#list-forks)
+/// for more information
+#[derive(Default)]
+pub struct ForkListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl ForkListOptions {
+    pub fn builder() -> ForkListOptionsBuilder {
+        ForkListOptionsBuilder::default()
+    }
+
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+/// a mutable fork list builder
+#[derive(Default)]
+pub struct ForkListOptionsBuilder(ForkListOptions);
+
+impl ForkListOptionsBuilder {
+    pub fn sort(&mut self, sort: Sort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> ForkListOptions {
+        ForkListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
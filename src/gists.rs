@@ -2,11 +2,13 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 
+use futures::Future as StdFuture;
+use http::StatusCode;
 use url::form_urlencoded;
 use serde::{Deserialize, Serialize};
 
 use crate::users::User;
-use crate::{Future, Github};
+use crate::{DateTime, Error, ErrorKind, Future, Github, Stream};
 
 /// reference to gists associated with a github user
 pub struct UserGists {
@@ -33,6 +35,15 @@ impl UserGists {
         }
         self.github.get(&uri.join("?"))
     }
+
+    /// provides a stream over all pages of this user's gists
+    pub fn iter(&self, options: &GistListOptions) -> Stream<Gist> {
+        let mut uri = vec![format!("/users/{}/gists", self.owner)];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get_stream(&uri.join("?"))
+    }
 }
 
 pub struct Gists {
@@ -58,6 +69,26 @@ impl Gists {
         self.github.delete(&self.path(&format!("/{}/star", id)))
     }
 
+    /// returns whether the authenticated user has starred a gist
+    pub fn is_starred(&self, id: &str) -> Future<bool> {
+        Box::new(
+            self.github
+                .get::<()>(&self.path(&format!("/{}/star", id)))
+                .map(|_| true)
+                .or_else(|err| match err {
+                    Error(
+                        ErrorKind::Fault {
+                            code: StatusCode::NOT_FOUND,
+                            ..
+                        },
+                        _,
+                    ) => Ok(false),
+                    Error(ErrorKind::Codec(_), _) => Ok(true),
+                    otherwise => Err(otherwise),
+                }),
+        )
+    }
+
     pub fn fork(&self, id: &str) -> Future<Gist> {
         self.github
             .post(&self.path(&format!("/{}/forks", id)), Vec::new())
@@ -67,6 +98,12 @@ impl Gists {
         self.github.get(&self.path(&format!("/{}/forks", id)))
     }
 
+    /// provides a stream over all pages of a gist's forks
+    pub fn iter_forks(&self, id: &str) -> Stream<GistFork> {
+        self.github
+            .get_stream(&self.path(&format!("/{}/forks", id)))
+    }
+
     pub fn delete(&self, id: &str) -> Future<()> {
         self.github.delete(&self.path(&format!("/{}", id)))
     }
@@ -75,10 +112,25 @@ impl Gists {
         self.github.get(&self.path(&format!("/{}", id)))
     }
 
+    /// get a gist as of a revision, one of the versions listed by `commits`
     pub fn getrev(&self, id: &str, sha: &str) -> Future<Gist> {
         self.github.get(&self.path(&format!("/{}/{}", id, sha)))
     }
 
+    /// list the revisions of a gist, newest first
+    ///
+    /// See the [github docs](https://developer.github.com/v3/gists/#list-gist-commits)
+    /// for more information.
+    pub fn commits(&self, id: &str) -> Future<Vec<GistCommit>> {
+        self.github.get(&self.path(&format!("/{}/commits", id)))
+    }
+
+    /// provides a stream over all pages of a gist's revisions
+    pub fn iter_commits(&self, id: &str) -> Stream<GistCommit> {
+        self.github
+            .get_stream(&self.path(&format!("/{}/commits", id)))
+    }
+
     pub fn list(&self, options: &GistListOptions) -> Future<Vec<Gist>> {
         let mut uri = vec![self.path("")];
         if let Some(query) = options.serialize() {
@@ -87,19 +139,42 @@ impl Gists {
         self.github.get::<Vec<Gist>>(&uri.join("?"))
     }
 
+    /// provides a stream over all pages of the authenticated user's gists
+    pub fn iter(&self, options: &GistListOptions) -> Stream<Gist> {
+        let mut uri = vec![self.path("")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get_stream(&uri.join("?"))
+    }
+
     pub fn public(&self) -> Future<Vec<Gist>> {
         self.github.get(&self.path("/public"))
     }
 
+    /// provides a stream over all pages of public gists
+    pub fn iter_public(&self) -> Stream<Gist> {
+        self.github.get_stream(&self.path("/public"))
+    }
+
     pub fn starred(&self) -> Future<Vec<Gist>> {
         self.github.get(&self.path("/starred"))
     }
 
+    /// provides a stream over all pages of the authenticated user's starred gists
+    pub fn iter_starred(&self) -> Stream<Gist> {
+        self.github.get_stream(&self.path("/starred"))
+    }
+
     pub fn create(&self, gist: &GistOptions) -> Future<Gist> {
         self.github.post(&self.path(""), json!(gist))
     }
 
-    pub fn edit(&self, id: &str, gist: &GistOptions) -> Future<Gist> {
+    /// edit the description and files of a gist
+    ///
+    /// See the [github docs](https://developer.github.com/v3/gists/#edit-a-gist)
+    /// for more information.
+    pub fn edit(&self, id: &str, gist: &GistEditOptions) -> Future<Gist> {
         self.github
             .patch(&self.path(&format!("/{}", id)), json!(gist))
     }
@@ -163,8 +238,15 @@ pub struct Gist {
     pub html_url: String,
     pub git_pull_url: String,
     pub git_push_url: String,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+impl Gist {
+    /// List the forks of this gist by following its `forks_url`.
+    pub fn forks(&self, github: &Github) -> Future<Vec<GistFork>> {
+        github.get_url(&self.forks_url)
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -172,8 +254,29 @@ pub struct GistFork {
     pub user: User,
     pub url: String,
     pub id: String,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+/// A revision of a gist
+#[derive(Debug, Deserialize)]
+pub struct GistCommit {
+    pub url: String,
+    /// the sha of the revision, for use with `Gists#getrev`
+    pub version: String,
+    pub user: Option<User>,
+    pub change_status: GistChangeStatus,
+    pub committed_at: DateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GistChangeStatus {
+    #[serde(default)]
+    pub total: u64,
+    #[serde(default)]
+    pub additions: u64,
+    #[serde(default)]
+    pub deletions: u64,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -272,18 +375,119 @@ impl GistOptions {
     }
 }
 
+/// Options for editing a gist. Files that aren't mentioned are left as they are
+#[derive(Debug, Default, Serialize)]
+pub struct GistEditOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// deleted files are `None`, which github expects as `null`
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    files: HashMap<String, Option<FileEdit>>,
+}
+
+impl GistEditOptions {
+    pub fn builder() -> GistEditOptionsBuilder {
+        GistEditOptionsBuilder::default()
+    }
+}
+
+/// A change to a file of a gist
+#[derive(Debug, Clone, Serialize)]
+struct FileEdit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+}
+
+#[derive(Default)]
+pub struct GistEditOptionsBuilder(GistEditOptions);
+
+impl GistEditOptionsBuilder {
+    pub fn description<D>(&mut self, desc: D) -> &mut Self
+    where
+        D: Into<String>,
+    {
+        self.0.description = Some(desc.into());
+        self
+    }
+
+    /// replace the content of a file, creating it if it doesn't exist
+    pub fn update<F, C>(&mut self, filename: F, content: C) -> &mut Self
+    where
+        F: Into<String>,
+        C: Into<String>,
+    {
+        self.edit(filename.into(), None, Some(content.into()))
+    }
+
+    /// rename a file, keeping its content
+    pub fn rename<F, N>(&mut self, filename: F, new_filename: N) -> &mut Self
+    where
+        F: Into<String>,
+        N: Into<String>,
+    {
+        self.edit(filename.into(), Some(new_filename.into()), None)
+    }
+
+    /// rename a file and replace its content
+    pub fn rename_and_update<F, N, C>(
+        &mut self,
+        filename: F,
+        new_filename: N,
+        content: C,
+    ) -> &mut Self
+    where
+        F: Into<String>,
+        N: Into<String>,
+        C: Into<String>,
+    {
+        self.edit(filename.into(), Some(new_filename.into()), Some(content.into()))
+    }
+
+    /// delete a file
+    pub fn delete<F>(&mut self, filename: F) -> &mut Self
+    where
+        F: Into<String>,
+    {
+        self.0.files.insert(filename.into(), None);
+        self
+    }
+
+    fn edit(
+        &mut self,
+        filename: String,
+        new_filename: Option<String>,
+        content: Option<String>,
+    ) -> &mut Self {
+        self.0.files.insert(
+            filename,
+            Some(FileEdit {
+                filename: new_filename,
+                content,
+            }),
+        );
+        self
+    }
+
+    pub fn build(&self) -> GistEditOptions {
+        GistEditOptions {
+            description: self.0.description.clone(),
+            files: self.0.files.clone(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::GistOptions;
+    use super::{GistEditOptions, GistOptions};
     use serde::ser::Serialize;
     use serde_json;
     use std::collections::HashMap;
 
     fn test_encoding<E: Serialize>(tests: Vec<(E, &str)>) {
-        for test in tests {
-            match test {
-                (k, v) => assert_eq!(serde_json::to_string(&k).unwrap(), v),
-            }
+        for (k, v) in tests {
+            assert_eq!(serde_json::to_string(&k).unwrap(), v);
         }
     }
     #[test]
@@ -328,4 +532,27 @@ mod tests {
         ];
         test_encoding(tests)
     }
+
+    #[test]
+    fn gist_edit_req() {
+        let tests = vec![
+            (GistEditOptions::builder().build(), "{}"),
+            (
+                GistEditOptions::builder().update("a.rs", "foo").build(),
+                r#"{"files":{"a.rs":{"content":"foo"}}}"#,
+            ),
+            (
+                GistEditOptions::builder().rename("a.rs", "b.rs").build(),
+                r#"{"files":{"a.rs":{"filename":"b.rs"}}}"#,
+            ),
+            (
+                GistEditOptions::builder()
+                    .description("desc")
+                    .delete("a.rs")
+                    .build(),
+                r#"{"description":"desc","files":{"a.rs":null}}"#,
+            ),
+        ];
+        test_encoding(tests)
+    }
 }
@@ -1,10 +1,13 @@
 //! Git interface
 
 // Third party
-use serde::Deserialize;
+use futures::{future, Future as StdFuture};
+use serde::{Deserialize, Serialize};
 
 // Ours
-use crate::{Future, Github};
+use crate::commits::{CommitRef, UserStamp};
+use crate::errors::ErrorKind;
+use crate::{Future, Github, Result, Stream};
 
 /// reference to git operations associated with a github repo
 pub struct Git {
@@ -55,6 +58,11 @@ impl Git {
             .get(&self.path(&format!("/blobs/{}", sha.into())))
     }
 
+    /// returns a reference to an interface for git reference operations
+    pub fn refs(&self) -> Refs {
+        Refs::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
     /// get the git reference data of a given ref
     /// the specified reference must be formatted as as "heads/branch", not just "branch"
     /// https://developer.github.com/v3/git/refs/#get-a-reference
@@ -66,6 +74,191 @@ impl Git {
             .get(&self.path(&format!("/refs/{}", reference.into())))
     }
 
+    /// create a blob from raw file contents
+    /// https://developer.github.com/v3/git/blobs/#create-a-blob
+    pub fn create_blob<C>(&self, content: C) -> Future<BlobRef>
+    where
+        C: AsRef<[u8]>,
+    {
+        let content = base64::encode(content.as_ref());
+        self.github.post(
+            &self.path("/blobs"),
+            json_lit!({ "content": content, "encoding": "base64" }),
+        )
+    }
+
+    /// create a tree, optionally on top of an existing base tree
+    /// https://developer.github.com/v3/git/trees/#create-a-tree
+    pub fn create_tree(&self, tree: &TreeOptions) -> Future<TreeData> {
+        self.github.post(&self.path("/trees"), json!(tree))
+    }
+
+    /// get a git commit by its sha
+    /// https://developer.github.com/v3/git/commits/#get-a-commit
+    pub fn commit<S>(&self, sha: S) -> Future<GitCommit>
+    where
+        S: Into<String>,
+    {
+        self.github
+            .get(&self.path(&format!("/commits/{}", sha.into())))
+    }
+
+    /// create a git commit
+    /// https://developer.github.com/v3/git/commits/#create-a-commit
+    pub fn create_commit(&self, commit: &CommitOptions) -> Future<GitCommit> {
+        self.github.post(&self.path("/commits"), json!(commit))
+    }
+
+    /// get an annotated tag object by its sha
+    /// https://developer.github.com/v3/git/tags/#get-a-tag
+    pub fn tag<S>(&self, sha: S) -> Future<GitTag>
+    where
+        S: Into<String>,
+    {
+        self.github
+            .get(&self.path(&format!("/tags/{}", sha.into())))
+    }
+
+    /// create an annotated tag object. this only creates the object, create a
+    /// `refs/tags/<tag>` reference pointing at its sha to publish the tag
+    /// https://developer.github.com/v3/git/tags/#create-a-tag-object
+    pub fn create_tag(&self, tag: &TagOptions) -> Future<GitTag> {
+        self.github.post(&self.path("/tags"), json!(tag))
+    }
+
+    /// create a reference pointing at a given sha
+    /// the reference must be fully qualified, as in "refs/heads/branch"
+    /// https://developer.github.com/v3/git/refs/#create-a-reference
+    pub fn create_reference<R, S>(&self, reference: R, sha: S) -> Future<Reference>
+    where
+        R: Into<String>,
+        S: Into<String>,
+    {
+        self.refs().create(reference, sha)
+    }
+
+    /// point an existing reference at a given sha
+    /// the specified reference must be formatted as as "heads/branch", not just "branch"
+    /// https://developer.github.com/v3/git/refs/#update-a-reference
+    pub fn update_reference<R, S>(&self, reference: R, sha: S, force: bool) -> Future<Reference>
+    where
+        R: Into<String>,
+        S: Into<String>,
+    {
+        self.refs().update(reference, sha, force)
+    }
+
+    /// commit a set of file changes on top of an existing branch, creating the
+    /// blobs, tree and commit and then moving the branch to the new commit
+    pub fn commit_files<B, M>(
+        &self,
+        branch: B,
+        message: M,
+        files: Vec<FileChange>,
+    ) -> Future<GitCommit>
+    where
+        B: Into<String>,
+        M: Into<String>,
+    {
+        self.commit_files_onto(branch.into(), None, message.into(), files)
+    }
+
+    /// commit a set of file changes on top of the head of `base`, creating
+    /// `branch` to point at the new commit
+    pub fn commit_files_to_new_branch<B, F, M>(
+        &self,
+        branch: B,
+        base: F,
+        message: M,
+        files: Vec<FileChange>,
+    ) -> Future<GitCommit>
+    where
+        B: Into<String>,
+        F: Into<String>,
+        M: Into<String>,
+    {
+        self.commit_files_onto(branch.into(), Some(base.into()), message.into(), files)
+    }
+
+    fn commit_files_onto(
+        &self,
+        branch: String,
+        base: Option<String>,
+        message: String,
+        files: Vec<FileChange>,
+    ) -> Future<GitCommit> {
+        let git = Git::new(self.github.clone(), self.owner.as_str(), self.repo.as_str());
+        let head = base.clone().unwrap_or_else(|| branch.clone());
+        Box::new(
+            self.reference(format!("heads/{}", head))
+                .and_then(move |reference| match reference {
+                    GetReferenceResponse::Exact(reference) => Ok(reference.object.sha),
+                    GetReferenceResponse::StartWith(_) => {
+                        Err(ErrorKind::Msg(format!("branch {} not found", head)).into())
+                    }
+                })
+                .and_then(move |parent| {
+                    let entries = future::join_all(
+                        files
+                            .into_iter()
+                            .map(|file| git.tree_entry(file))
+                            .collect::<Vec<_>>(),
+                    );
+                    git.commit(parent.as_str()).join(entries).and_then(
+                        move |(head_commit, tree)| {
+                            git.create_tree(&TreeOptions {
+                                base_tree: Some(head_commit.tree.sha),
+                                tree,
+                            })
+                            .and_then(move |tree| {
+                                git.create_commit(&CommitOptions {
+                                    message,
+                                    tree: tree.sha,
+                                    parents: vec![parent],
+                                })
+                                .and_then(move |commit| {
+                                    let updated = match base {
+                                        Some(_) => git.create_reference(
+                                            format!("refs/heads/{}", branch),
+                                            commit.sha.as_str(),
+                                        ),
+                                        None => git.update_reference(
+                                            format!("heads/{}", branch),
+                                            commit.sha.as_str(),
+                                            false,
+                                        ),
+                                    };
+                                    updated.map(|_| commit)
+                                })
+                            })
+                        },
+                    )
+                }),
+        )
+    }
+
+    fn tree_entry(&self, file: FileChange) -> Future<TreeEntry> {
+        let FileChange {
+            path,
+            mode,
+            content,
+        } = file;
+        match content {
+            Some(content) => Box::new(self.create_blob(content).map(|blob| TreeEntry {
+                path,
+                mode,
+                entry_type: "blob".into(),
+                sha: Some(blob.sha),
+            })),
+            None => Box::new(future::ok(TreeEntry {
+                path,
+                mode,
+                entry_type: "blob".into(),
+                sha: None,
+            })),
+        }
+    }
+
     //// deletes a refish
     /// branches should be in the format `heads/feature-a`
     /// tags should be in the format `tags/v1.0`
@@ -73,6 +266,103 @@ impl Git {
     pub fn delete_reference<S>(&self, reference: S) -> Future<()>
     where
         S: Into<String>,
+    {
+        self.refs().delete(reference)
+    }
+}
+
+/// reference to git reference operations associated with a github repo
+///
+/// References other than `create`'s are given without their `refs/` prefix,
+/// like `heads/main` or `tags/v1.0`
+pub struct Refs {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Refs {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Refs {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/git{}", self.owner, self.repo, more)
+    }
+
+    /// get a single reference. unlike `Git#reference`, fails with a 404 fault
+    /// rather than returning similarly named references when it doesn't exist
+    /// https://developer.github.com/v3/git/refs/#get-a-reference
+    pub fn get<R>(&self, reference: R) -> Future<Reference>
+    where
+        R: Into<String>,
+    {
+        self.github
+            .get(&self.path(&format!("/ref/{}", reference.into())))
+    }
+
+    /// list the references starting with a prefix, like `heads/` for all
+    /// branches or `tags/v1.` for a release series
+    /// https://docs.github.com/en/rest/git/refs#list-matching-references
+    pub fn list<P>(&self, prefix: P) -> Future<Vec<Reference>>
+    where
+        P: Into<String>,
+    {
+        self.github
+            .get(&self.path(&format!("/matching-refs/{}", prefix.into())))
+    }
+
+    /// provides a stream over all pages of references starting with a prefix
+    pub fn iter<P>(&self, prefix: P) -> Stream<Reference>
+    where
+        P: Into<String>,
+    {
+        self.github
+            .get_stream(&self.path(&format!("/matching-refs/{}", prefix.into())))
+    }
+
+    /// create a reference pointing at a given sha
+    /// the reference must be fully qualified, as in "refs/heads/branch"
+    /// https://developer.github.com/v3/git/refs/#create-a-reference
+    pub fn create<R, S>(&self, reference: R, sha: S) -> Future<Reference>
+    where
+        R: Into<String>,
+        S: Into<String>,
+    {
+        self.github.post(
+            &self.path("/refs"),
+            json_lit!({ "ref": reference.into(), "sha": sha.into() }),
+        )
+    }
+
+    /// point an existing reference at a given sha. without `force` the update
+    /// must be a fast forward
+    /// https://developer.github.com/v3/git/refs/#update-a-reference
+    pub fn update<R, S>(&self, reference: R, sha: S, force: bool) -> Future<Reference>
+    where
+        R: Into<String>,
+        S: Into<String>,
+    {
+        self.github.patch(
+            &self.path(&format!("/refs/{}", reference.into())),
+            json_lit!({ "sha": sha.into(), "force": force }),
+        )
+    }
+
+    /// delete a reference
+    /// https://developer.github.com/v3/git/refs/#delete-a-reference
+    pub fn delete<R>(&self, reference: R) -> Future<()>
+    where
+        R: Into<String>,
     {
         self.github
             .delete(&self.path(&format!("/refs/{}", reference.into())))
@@ -113,6 +403,160 @@ pub struct Blob {
     pub size: Option<usize>,
 }
 
+impl Blob {
+    /// decode the blob's base64 encoded content
+    pub fn decode(&self) -> Result<Vec<u8>> {
+        if self.encoding != "base64" {
+            return Ok(self.content.clone().into_bytes());
+        }
+        // GitHub wraps the base64 to column 60
+        base64::decode(&self.content.replace("\n", ""))
+            .map_err(|e| ErrorKind::Msg(format!("invalid blob content: {}", e)).into())
+    }
+}
+
+/// A reference to a newly created blob
+#[derive(Debug, Deserialize)]
+pub struct BlobRef {
+    pub url: String,
+    pub sha: String,
+}
+
+/// Options for creating a tree
+#[derive(Debug, Serialize)]
+pub struct TreeOptions {
+    /// the sha of the tree the new tree's entries are applied on top of
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_tree: Option<String>,
+    pub tree: Vec<TreeEntry>,
+}
+
+/// An entry of a tree to create
+#[derive(Debug, Serialize)]
+pub struct TreeEntry {
+    pub path: String,
+    pub mode: String,
+    /// typically tree or blob
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    /// a sha of `None` removes the path from the base tree
+    pub sha: Option<String>,
+}
+
+/// A change to a file made by `Git::commit_files`
+#[derive(Debug)]
+pub struct FileChange {
+    pub path: String,
+    /// defaults to `100644`, a regular file
+    pub mode: String,
+    /// `None` removes the file
+    pub content: Option<Vec<u8>>,
+}
+
+impl FileChange {
+    /// write `content` to the file at `path`
+    pub fn write<P, C>(path: P, content: C) -> Self
+    where
+        P: Into<String>,
+        C: Into<Vec<u8>>,
+    {
+        FileChange {
+            path: path.into(),
+            mode: "100644".into(),
+            content: Some(content.into()),
+        }
+    }
+
+    /// remove the file at `path`
+    pub fn delete<P>(path: P) -> Self
+    where
+        P: Into<String>,
+    {
+        FileChange {
+            path: path.into(),
+            mode: "100644".into(),
+            content: None,
+        }
+    }
+}
+
+/// Options for creating a commit
+#[derive(Debug, Serialize)]
+pub struct CommitOptions {
+    pub message: String,
+    /// the sha of the commit's tree
+    pub tree: String,
+    /// the shas of the commit's parents
+    pub parents: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitCommit {
+    pub sha: String,
+    pub url: String,
+    pub html_url: String,
+    pub author: UserStamp,
+    pub committer: UserStamp,
+    pub message: String,
+    pub tree: CommitRef,
+    pub parents: Vec<CommitRef>,
+}
+
+/// Options for creating an annotated tag object
+#[derive(Debug, Serialize)]
+pub struct TagOptions {
+    /// the tag's name, like `v1.0`
+    pub tag: String,
+    pub message: String,
+    /// the sha of the object being tagged
+    pub object: String,
+    /// the type of the object being tagged, typically commit
+    #[serde(rename = "type")]
+    pub object_type: String,
+    /// defaults to the authenticated user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tagger: Option<Tagger>,
+}
+
+impl TagOptions {
+    /// tag a commit
+    pub fn commit<T, M, S>(tag: T, message: M, sha: S) -> Self
+    where
+        T: Into<String>,
+        M: Into<String>,
+        S: Into<String>,
+    {
+        TagOptions {
+            tag: tag.into(),
+            message: message.into(),
+            object: sha.into(),
+            object_type: "commit".into(),
+            tagger: None,
+        }
+    }
+}
+
+/// The author of a tag object
+#[derive(Debug, Serialize)]
+pub struct Tagger {
+    pub name: String,
+    pub email: String,
+    /// an ISO 8601 timestamp. defaults to the current time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+}
+
+/// An annotated tag object
+#[derive(Debug, Deserialize)]
+pub struct GitTag {
+    pub sha: String,
+    pub url: String,
+    pub tag: String,
+    pub message: String,
+    pub tagger: UserStamp,
+    pub object: Object,
+}
+
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(untagged)]
 /// The response for getting a git reference
@@ -157,6 +601,44 @@ mod tests {
         assert_eq!(incoming, expected)
     }
 
+    #[test]
+    fn serialize_tree_options() {
+        let tree = TreeOptions {
+            base_tree: Some("9fb037999f264ba9a7fc6274d15fa3ae2ab98312".into()),
+            tree: vec![TreeEntry {
+                path: "README.md".into(),
+                mode: "100644".into(),
+                entry_type: "blob".into(),
+                sha: None,
+            }],
+        };
+        assert_eq!(
+            serde_json::to_string(&tree).unwrap(),
+            r#"{"base_tree":"9fb037999f264ba9a7fc6274d15fa3ae2ab98312","tree":[{"path":"README.md","mode":"100644","type":"blob","sha":null}]}"#
+        )
+    }
+
+    #[test]
+    fn decode_blob() {
+        let blob = Blob {
+            content: "aGVsbG8g\nd29ybGQ=\n".into(),
+            encoding: "base64".into(),
+            url: "https://api.github.com/repos/octocat/Hello-World/git/blobs/abc".into(),
+            sha: "abc".into(),
+            size: Some(11),
+        };
+        assert_eq!(blob.decode().unwrap(), b"hello world".to_vec())
+    }
+
+    #[test]
+    fn serialize_tag_options() {
+        let tag = TagOptions::commit("v1.0", "initial release", "c3d0be41ecbe669545ee3e94d31ed9a4bc91ee3c");
+        assert_eq!(
+            serde_json::to_string(&tag).unwrap(),
+            r#"{"tag":"v1.0","message":"initial release","object":"c3d0be41ecbe669545ee3e94d31ed9a4bc91ee3c","type":"commit"}"#
+        )
+    }
+
     #[test]
     fn deserialize_get_ref_exact() {
         let payload = r#"{
@@ -0,0 +1,48 @@
+//! Gitignore templates interface
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/gitignore/)
+use serde::Deserialize;
+
+use crate::{Future, Github};
+
+/// Provides access to github's [gitignore templates](https://github.com/github/gitignore)
+pub struct Gitignore {
+    github: Github,
+}
+
+impl Gitignore {
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        Self { github }
+    }
+
+    /// list the names of the available templates
+    ///
+    /// See the [github docs](https://developer.github.com/v3/gitignore/#listing-available-templates)
+    /// for more information.
+    pub fn list(&self) -> Future<Vec<String>> {
+        self.github.get("/gitignore/templates")
+    }
+
+    /// get a template by name, like `Rust`
+    ///
+    /// See the [github docs](https://developer.github.com/v3/gitignore/#get-a-single-template)
+    /// for more information.
+    pub fn get<N>(&self, name: N) -> Future<GitignoreTemplate>
+    where
+        N: Into<String>,
+    {
+        self.github
+            .get(&format!("/gitignore/templates/{}", name.into()))
+    }
+}
+
+// representations
+
+#[derive(Debug, Deserialize)]
+pub struct GitignoreTemplate {
+    pub name: String,
+    /// the raw content of the template
+    pub source: String,
+}
@@ -1,31 +1,29 @@
 //! Hooks interface
 //!
 //! See the [github docs](https://developer.github.com/v3/repos/hooks/) for more information
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 
 use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
 
-use crate::{Future, Github};
+use crate::{DateTime, Future, Github, Stream};
+
+pub mod payloads;
 
 /// Content-Type web hooks will receive
 /// deliveries in
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
 pub enum WebHookContentType {
     /// application/json
     #[serde(rename = "json")]
     Json,
     /// application/x-form-url-encoded
     #[serde(rename = "form")]
+    #[default]
     Form,
 }
 
-impl Default for WebHookContentType {
-    fn default() -> WebHookContentType {
-        WebHookContentType::Form
-    }
-}
-
 impl fmt::Display for WebHookContentType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
@@ -36,11 +34,101 @@ impl fmt::Display for WebHookContentType {
     }
 }
 
-/// Interface for managing repository hooks
+/// Typed configuration for web hooks
+///
+/// See the [github docs](https://developer.github.com/v3/repos/hooks/#create-hook-config-params)
+/// for more information
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebHookConfig {
+    /// The url payloads will be delivered to
+    pub url: String,
+    /// The media type payloads will be serialized as
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<WebHookContentType>,
+    /// A secret used to sign deliveries in the `X-Hub-Signature` header.
+    /// Github returns a masked value for hooks configured with a secret
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// Whether ssl verification of the url is skipped when delivering payloads
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "insecure_ssl"
+    )]
+    pub insecure_ssl: Option<bool>,
+}
+
+impl WebHookConfig {
+    /// creates a new web hook configuration delivering payloads to a url
+    pub fn new<U>(url: U) -> Self
+    where
+        U: Into<String>,
+    {
+        WebHookConfig {
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+
+    fn entries(&self) -> BTreeMap<String, ::serde_json::Value> {
+        match ::serde_json::to_value(self) {
+            Ok(::serde_json::Value::Object(entries)) => entries.into_iter().collect(),
+            _ => BTreeMap::new(),
+        }
+    }
+}
+
+/// Hook configurations for web hooks and the legacy services
+/// listed [here](https://api.github.com/hooks)
+#[derive(Debug, Clone, PartialEq)]
+pub enum HookConfig {
+    Web(WebHookConfig),
+    /// Untyped configuration of a legacy service hook
+    Service(BTreeMap<String, ::serde_json::Value>),
+}
+
+/// Github represents `insecure_ssl` as the strings "0" and "1"
+mod insecure_ssl {
+    use serde::de::{self, Deserialize, Deserializer};
+    use serde::Serializer;
+
+    pub fn serialize<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *value {
+            Some(true) => serializer.serialize_str("1"),
+            Some(false) => serializer.serialize_str("0"),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<::serde_json::Value>::deserialize(deserializer)? {
+            None => Ok(None),
+            Some(::serde_json::Value::String(ref value)) if value == "0" => Ok(Some(false)),
+            Some(::serde_json::Value::String(ref value)) if value == "1" => Ok(Some(true)),
+            Some(::serde_json::Value::Number(ref value)) if value.as_u64() == Some(0) => {
+                Ok(Some(false))
+            }
+            Some(::serde_json::Value::Number(ref value)) if value.as_u64() == Some(1) => {
+                Ok(Some(true))
+            }
+            Some(other) => Err(de::Error::custom(format!(
+                "invalid insecure_ssl value {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Interface for managing repository and organization hooks
 pub struct Hooks {
     github: Github,
     owner: String,
-    repo: String,
 }
 
 impl Hooks {
@@ -52,15 +140,40 @@ impl Hooks {
     {
         Hooks {
             github,
-            owner: owner.into(),
-            repo: repo.into(),
+            owner: format!("/repos/{}/{}", owner.into(), repo.into()),
         }
     }
 
+    /// hooks of an organization, which receive deliveries for events of all
+    /// of its repositories
+    #[doc(hidden)]
+    pub fn for_org<O>(github: Github, org: O) -> Self
+    where
+        O: Into<String>,
+    {
+        Hooks {
+            github,
+            owner: format!("/orgs/{}", org.into()),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("{}/hooks{}", self.owner, more)
+    }
+
     /// lists hook associated with a repository
     pub fn list(&self) -> Future<Vec<Hook>> {
-        self.github
-            .get(&format!("/repos/{}/{}/hooks", self.owner, self.repo))
+        self.github.get(&self.path(""))
+    }
+
+    /// provides a stream over all pages of hooks associated with a repository
+    pub fn iter(&self) -> Stream<Hook> {
+        self.github.get_stream(&self.path(""))
+    }
+
+    /// gets a hook by id
+    pub fn get(&self, id: u64) -> Future<Hook> {
+        self.github.get(&self.path(&format!("/{}", id)))
     }
 
     /// creates a new repository hook
@@ -69,24 +182,35 @@ impl Hooks {
     /// see [github docs](https://developer.github.com/v3/repos/hooks/)
     /// for more information
     pub fn create(&self, options: &HookCreateOptions) -> Future<Hook> {
-        self.github.post(
-            &format!("/repos/{}/{}/hooks", self.owner, self.repo),
-            json!(options),
-        )
+        self.github.post(&self.path(""), json!(options))
     }
 
     /// edits an existing repository hook
     pub fn edit(&self, id: u64, options: &HookEditOptions) -> Future<Hook> {
-        self.github.patch(
-            &format!("/repos/{}/{}/hooks/{}", self.owner, self.repo, id),
-            json!(options),
-        )
+        self.github
+            .patch(&self.path(&format!("/{}", id)), json!(options))
     }
 
     /// deletes a repository hook by id
     pub fn delete(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.path(&format!("/{}", id)))
+    }
+
+    /// sends a `ping` event to a hook
+    /// see [github docs](https://developer.github.com/v3/repos/hooks/#ping-a-hook)
+    /// for more information
+    pub fn ping(&self, id: u64) -> Future<()> {
+        self.github
+            .post_no_response(&self.path(&format!("/{}/pings", id)), Vec::new())
+    }
+
+    /// redelivers the latest `push` event to a hook subscribed to pushes.
+    /// only available for repository hooks
+    /// see [github docs](https://developer.github.com/v3/repos/hooks/#test-a-push-hook)
+    /// for more information
+    pub fn test(&self, id: u64) -> Future<()> {
         self.github
-            .delete(&format!("/repos/{}/{}/hooks/{}", self.owner, self.repo, id))
+            .post_no_response(&self.path(&format!("/{}/tests", id)), Vec::new())
     }
 }
 
@@ -176,6 +300,19 @@ impl HookCreateOptionsBuilder {
         self
     }
 
+    /// web hooks can optionally disable ssl verification of their url
+    /// when delivering payloads. This is not recommended
+    pub fn insecure_ssl(&mut self, insecure: bool) -> &mut Self {
+        self.config_str_entry("insecure_ssl", if insecure { "1" } else { "0" });
+        self
+    }
+
+    /// replace this hook's configuration with a typed web hook configuration
+    pub fn config(&mut self, config: &WebHookConfig) -> &mut Self {
+        self.0.config = config.entries();
+        self
+    }
+
     pub fn config_str_entry<K, V>(&mut self, k: K, v: V) -> &mut Self
     where
         K: Into<String>,
@@ -208,11 +345,16 @@ impl HookCreateOptionsBuilder {
 /// for githubs official documentation
 #[derive(Debug, Default, Serialize)]
 pub struct HookEditOptions {
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     config: BTreeMap<String, ::serde_json::Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     events: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     add_events: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     remove_events: Vec<String>,
-    active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
 }
 
 impl HookEditOptions {
@@ -227,12 +369,12 @@ pub struct HookEditOptionsBuilder(HookEditOptions);
 
 impl HookEditOptionsBuilder {
     pub fn active(&mut self, active: bool) -> &mut Self {
-        self.0.active = active;
+        self.0.active = Some(active);
         self
     }
 
-    /// a list of github events this hook should receive deliveries for
-    /// the default is "push". for a full list, see
+    /// replaces the list of github events this hook receives deliveries for.
+    /// for a full list, see
     /// the [Github api docs](https://developer.github.com/webhooks/#events)
     pub fn events<E>(&mut self, events: Vec<E>) -> &mut Self
     where
@@ -242,6 +384,26 @@ impl HookEditOptionsBuilder {
         self
     }
 
+    /// github events to add to the events this hook receives deliveries for.
+    /// only available for repository hooks
+    pub fn add_events<E>(&mut self, events: Vec<E>) -> &mut Self
+    where
+        E: Into<String>,
+    {
+        self.0.add_events = events.into_iter().map(|e| e.into()).collect::<Vec<_>>();
+        self
+    }
+
+    /// github events to stop delivering to this hook.
+    /// only available for repository hooks
+    pub fn remove_events<E>(&mut self, events: Vec<E>) -> &mut Self
+    where
+        E: Into<String>,
+    {
+        self.0.remove_events = events.into_iter().map(|e| e.into()).collect::<Vec<_>>();
+        self
+    }
+
     /// web hooks must have an associated url
     pub fn url<U>(&mut self, url: U) -> &mut Self
     where
@@ -267,6 +429,19 @@ impl HookEditOptionsBuilder {
         self
     }
 
+    /// web hooks can optionally disable ssl verification of their url
+    /// when delivering payloads. This is not recommended
+    pub fn insecure_ssl(&mut self, insecure: bool) -> &mut Self {
+        self.config_str_entry("insecure_ssl", if insecure { "1" } else { "0" });
+        self
+    }
+
+    /// replace this hook's configuration with a typed web hook configuration
+    pub fn config(&mut self, config: &WebHookConfig) -> &mut Self {
+        self.0.config = config.entries();
+        self
+    }
+
     pub fn config_str_entry<K, V>(&mut self, k: K, v: V) -> &mut Self
     where
         K: Into<String>,
@@ -304,8 +479,8 @@ pub struct Hook {
     pub name: String,
     pub events: Vec<String>,
     pub config: ::serde_json::Value,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
     pub active: bool,
 }
 
@@ -328,11 +503,122 @@ impl Hook {
     pub fn content_type(&self) -> Option<String> {
         self.config_string("content_type")
     }
+
+    /// returns a typed representation of this hook's configuration
+    pub fn typed_config(&self) -> HookConfig {
+        if self.name == "web" {
+            if let Ok(config) = ::serde_json::from_value(self.config.clone()) {
+                return HookConfig::Web(config);
+            }
+        }
+        match self.config {
+            ::serde_json::Value::Object(ref entries) => HookConfig::Service(
+                entries
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect(),
+            ),
+            _ => HookConfig::Service(BTreeMap::new()),
+        }
+    }
+}
+
+/// A delivery of a hook's payload
+#[derive(Debug, Deserialize)]
+pub struct HookDelivery {
+    pub id: u64,
+    /// identifies the event that triggered the delivery. redeliveries share
+    /// the guid of the original delivery
+    pub guid: String,
+    pub delivered_at: DateTime,
+    pub redelivery: bool,
+    /// seconds the delivery took
+    pub duration: f64,
+    /// description of the delivery's outcome, like `OK`
+    pub status: String,
+    pub status_code: u16,
+    pub event: String,
+    pub action: Option<String>,
+    pub installation_id: Option<u64>,
+    pub repository_id: Option<u64>,
+}
+
+/// A delivery of a hook's payload, including the request and response
+#[derive(Debug, Deserialize)]
+pub struct HookDeliveryDetail {
+    #[serde(flatten)]
+    pub delivery: HookDelivery,
+    pub url: Option<String>,
+    pub request: HookDeliveryRequest,
+    pub response: HookDeliveryResponse,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HookDeliveryRequest {
+    pub headers: Option<BTreeMap<String, String>>,
+    pub payload: Option<::serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HookDeliveryResponse {
+    pub headers: Option<BTreeMap<String, String>>,
+    pub payload: Option<String>,
+}
+
+/// Options used to page through hook deliveries
+///
+/// Deliveries are paged with a cursor rather than page numbers. The cursor
+/// of the next page is found in the `Link` header of the response
+#[derive(Default)]
+pub struct HookDeliveryListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl HookDeliveryListOptions {
+    pub fn builder() -> HookDeliveryListOptionsBuilder {
+        HookDeliveryListOptionsBuilder::default()
+    }
+
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+/// a mutable hook delivery list builder
+#[derive(Default)]
+pub struct HookDeliveryListOptionsBuilder(HookDeliveryListOptions);
+
+impl HookDeliveryListOptionsBuilder {
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn cursor<C>(&mut self, cursor: C) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        self.0.params.insert("cursor", cursor.into());
+        self
+    }
+
+    pub fn build(&self) -> HookDeliveryListOptions {
+        HookDeliveryListOptions {
+            params: self.0.params.clone(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::WebHookContentType;
+    use super::{HookCreateOptions, HookEditOptions, WebHookConfig, WebHookContentType};
 
     #[test]
     fn webhook_content_type_display() {
@@ -344,6 +630,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn webhook_config() {
+        let config = WebHookConfig {
+            content_type: Some(WebHookContentType::Json),
+            insecure_ssl: Some(false),
+            ..WebHookConfig::new("https://example.com/hook")
+        };
+        let options = HookCreateOptions::web().config(&config).build();
+        assert_eq!(
+            serde_json::to_string(&options).unwrap(),
+            r#"{"name":"web","config":{"content_type":"json","insecure_ssl":"0","url":"https://example.com/hook"},"events":[],"active":true}"#
+        );
+        let parsed: WebHookConfig = serde_json::from_str(
+            r#"{"url":"https://example.com/hook","content_type":"json","insecure_ssl":"0"}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed, config)
+    }
+
+    #[test]
+    fn hook_edit_options_only_send_changes() {
+        let options = HookEditOptions::builder()
+            .add_events(vec!["pull_request"])
+            .build();
+        assert_eq!(
+            serde_json::to_string(&options).unwrap(),
+            r#"{"add_events":["pull_request"]}"#
+        );
+    }
+
     #[test]
     fn webhook_content_type_default() {
         let default: WebHookContentType = Default::default();
@@ -0,0 +1,265 @@
+//! Webhook payloads
+//!
+//! Typed representations of the payloads github delivers to web hooks, and
+//! verification of the `X-Hub-Signature-256` header github signs them with.
+//!
+//! See the [github docs](https://docs.github.com/en/webhooks/webhook-events-and-payloads)
+//! for more information.
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+
+#[cfg(feature = "checks")]
+use crate::checks::CheckRun;
+#[cfg(feature = "issues")]
+use crate::comments::Comment;
+#[cfg(feature = "issues")]
+use crate::issues::Issue;
+#[cfg(feature = "pulls")]
+use crate::pulls::Pull;
+#[cfg(feature = "releases")]
+use crate::releases::Release;
+#[cfg(any(
+    feature = "checks",
+    feature = "issues",
+    feature = "pulls",
+    feature = "releases"
+))]
+use crate::repositories::Repo;
+use crate::users::User;
+use crate::{DateTime, Result};
+
+/// Verify the `X-Hub-Signature-256` header of a delivery, `sha256=` prefix and
+/// all, is the HMAC of its raw `body` keyed with the hook's `secret`
+///
+/// ```
+/// # use hubcaps::hooks::payloads::verify_signature;
+/// assert!(verify_signature(
+///     b"It's a Secret to Everybody",
+///     b"Hello, World!",
+///     "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17",
+/// ));
+/// assert!(!verify_signature(b"wrong secret", b"Hello, World!", "sha256=757107ea"));
+/// ```
+pub fn verify_signature(secret: &[u8], body: &[u8], signature: &str) -> bool {
+    let expected = match signature.strip_prefix("sha256=").and_then(decode_hex) {
+        Some(expected) => expected,
+        None => return false,
+    };
+    let mut mac = match Hmac::<Sha256>::new_from_slice(secret) {
+        Ok(mac) => mac,
+        Err(_) => return false,
+    };
+    mac.update(body);
+    // compares in constant time
+    mac.verify_slice(&expected).is_ok()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// A webhook delivery, parsed according to its `X-GitHub-Event` header
+#[derive(Debug)]
+pub enum Event {
+    Push(Box<PushEvent>),
+    #[cfg(feature = "pulls")]
+    PullRequest(Box<PullRequestEvent>),
+    #[cfg(feature = "issues")]
+    Issues(Box<IssuesEvent>),
+    #[cfg(feature = "issues")]
+    IssueComment(Box<IssueCommentEvent>),
+    #[cfg(feature = "checks")]
+    CheckRun(Box<CheckRunEvent>),
+    #[cfg(feature = "releases")]
+    Release(Box<ReleaseEvent>),
+    /// an event hubcaps doesn't define a payload for, with its event name
+    /// and raw payload
+    Other(String, serde_json::Value),
+}
+
+impl Event {
+    /// Parse a delivery's body given the value of its `X-GitHub-Event` header
+    pub fn parse(event: &str, body: &[u8]) -> Result<Event> {
+        Ok(match event {
+            "push" => Event::Push(serde_json::from_slice(body)?),
+            #[cfg(feature = "pulls")]
+            "pull_request" => Event::PullRequest(serde_json::from_slice(body)?),
+            #[cfg(feature = "issues")]
+            "issues" => Event::Issues(serde_json::from_slice(body)?),
+            #[cfg(feature = "issues")]
+            "issue_comment" => Event::IssueComment(serde_json::from_slice(body)?),
+            #[cfg(feature = "checks")]
+            "check_run" => Event::CheckRun(serde_json::from_slice(body)?),
+            #[cfg(feature = "releases")]
+            "release" => Event::Release(serde_json::from_slice(body)?),
+            other => Event::Other(other.to_string(), serde_json::from_slice(body)?),
+        })
+    }
+}
+
+// representations
+
+/// The installation of the github app a delivery was sent to
+#[derive(Debug, Deserialize)]
+pub struct InstallationRef {
+    pub id: u64,
+}
+
+/// https://docs.github.com/en/webhooks/webhook-events-and-payloads#push
+#[derive(Debug, Deserialize)]
+pub struct PushEvent {
+    /// the full ref that was pushed, like `refs/heads/main`
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub before: String,
+    pub after: String,
+    pub created: bool,
+    pub deleted: bool,
+    pub forced: bool,
+    pub compare: String,
+    pub commits: Vec<PushCommit>,
+    pub head_commit: Option<PushCommit>,
+    pub pusher: PushAuthor,
+    pub repository: PushRepository,
+    pub sender: User,
+    pub installation: Option<InstallationRef>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PushCommit {
+    pub id: String,
+    pub tree_id: String,
+    pub distinct: bool,
+    pub message: String,
+    pub timestamp: DateTime,
+    pub url: String,
+    pub author: PushAuthor,
+    pub committer: PushAuthor,
+    #[serde(default)]
+    pub added: Vec<String>,
+    #[serde(default)]
+    pub removed: Vec<String>,
+    #[serde(default)]
+    pub modified: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PushAuthor {
+    pub name: String,
+    pub email: Option<String>,
+    pub username: Option<String>,
+}
+
+/// The repository of a push event. Unlike other events, its `pushed_at` and
+/// `created_at` timestamps are unix times so it doesn't decode as a `Repo`
+#[derive(Debug, Deserialize)]
+pub struct PushRepository {
+    pub id: u64,
+    pub name: String,
+    pub full_name: String,
+    pub owner: PushAuthor,
+    pub private: bool,
+    pub html_url: String,
+    pub default_branch: String,
+}
+
+/// https://docs.github.com/en/webhooks/webhook-events-and-payloads#pull_request
+#[cfg(feature = "pulls")]
+#[derive(Debug, Deserialize)]
+pub struct PullRequestEvent {
+    /// like `opened`, `closed`, `synchronize`, or `labeled`
+    pub action: String,
+    pub number: u64,
+    pub pull_request: Pull,
+    pub repository: Repo,
+    pub sender: User,
+    pub installation: Option<InstallationRef>,
+}
+
+/// https://docs.github.com/en/webhooks/webhook-events-and-payloads#issues
+#[cfg(feature = "issues")]
+#[derive(Debug, Deserialize)]
+pub struct IssuesEvent {
+    /// like `opened`, `edited`, `closed`, or `assigned`
+    pub action: String,
+    pub issue: Issue,
+    pub repository: Repo,
+    pub sender: User,
+    pub installation: Option<InstallationRef>,
+}
+
+/// https://docs.github.com/en/webhooks/webhook-events-and-payloads#issue_comment
+#[cfg(feature = "issues")]
+#[derive(Debug, Deserialize)]
+pub struct IssueCommentEvent {
+    /// one of `created`, `edited`, or `deleted`
+    pub action: String,
+    pub issue: Issue,
+    pub comment: Comment,
+    pub repository: Repo,
+    pub sender: User,
+    pub installation: Option<InstallationRef>,
+}
+
+/// https://docs.github.com/en/webhooks/webhook-events-and-payloads#check_run
+#[cfg(feature = "checks")]
+#[derive(Debug, Deserialize)]
+pub struct CheckRunEvent {
+    /// one of `created`, `completed`, `rerequested`, or `requested_action`
+    pub action: String,
+    pub check_run: CheckRun,
+    pub repository: Repo,
+    pub sender: User,
+    pub installation: Option<InstallationRef>,
+}
+
+/// https://docs.github.com/en/webhooks/webhook-events-and-payloads#release
+#[cfg(feature = "releases")]
+#[derive(Debug, Deserialize)]
+pub struct ReleaseEvent {
+    /// like `published`, `created`, `edited`, or `deleted`
+    pub action: String,
+    pub release: Release,
+    pub repository: Repo,
+    pub sender: User,
+    pub installation: Option<InstallationRef>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_hex_rejects_invalid_input() {
+        assert_eq!(decode_hex("00ff10"), Some(vec![0, 255, 16]));
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("zz"), None);
+    }
+
+    #[test]
+    fn verify_signature_requires_sha256_prefix() {
+        assert!(!verify_signature(
+            b"It's a Secret to Everybody",
+            b"Hello, World!",
+            "757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17",
+        ));
+    }
+
+    #[test]
+    fn parse_unknown_event() {
+        match Event::parse("ping", br#"{"zen": "Keep it logically awesome."}"#).unwrap() {
+            Event::Other(event, payload) => {
+                assert_eq!(event, "ping");
+                assert_eq!(payload["zen"], "Keep it logically awesome.");
+            }
+            other => panic!("expected an unknown event, got {:?}", other),
+        }
+    }
+}
@@ -1,11 +1,13 @@
 //! Implements <https://tools.ietf.org/html/rfc7232> Conditional Requests
 use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::fs;
 use std::hash::{Hash, Hasher};
 use std::io;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 use http::Uri;
 use log::trace;
@@ -15,16 +17,29 @@ use crate::{Error, Result};
 pub type BoxedHttpCache = Box<dyn HttpCache + Send>;
 
 pub trait HttpCache: HttpCacheClone + Debug {
+    /// Store the body of a response for `uri`, along with its `ETag` header
+    /// when it had one. Responses with only a `Last-Modified` header are cached
+    /// too, without an etag
     fn cache_response(
         &self,
         uri: &str,
         body: &[u8],
-        etag: &[u8],
+        etag: Option<&[u8]>,
         next_link: &Option<String>,
     ) -> Result<()>;
     fn lookup_etag(&self, uri: &str) -> Result<String>;
     fn lookup_body(&self, uri: &str) -> Result<String>;
     fn lookup_next_link(&self, uri: &str) -> Result<Option<String>>;
+    /// Store the `Last-Modified` header of the response for `uri`, or drop a
+    /// previously stored one when the response had none
+    fn cache_last_modified(&self, _uri: &str, _last_modified: Option<&[u8]>) -> Result<()> {
+        Ok(())
+    }
+    /// The `Last-Modified` value of the response cached for `uri`, sent back
+    /// as `If-Modified-Since`
+    fn lookup_last_modified(&self, _uri: &str) -> Result<String> {
+        no_read("No last modified cached")
+    }
 }
 
 impl dyn HttpCache {
@@ -37,6 +52,10 @@ impl dyn HttpCache {
         dir.push(".hubcaps/cache");
         Box::new(FileBasedCache::new(dir))
     }
+
+    pub fn in_memory() -> BoxedHttpCache {
+        Box::new(InMemoryCache::default())
+    }
 }
 
 impl Clone for BoxedHttpCache {
@@ -49,7 +68,13 @@ impl Clone for BoxedHttpCache {
 pub struct NoCache;
 
 impl HttpCache for NoCache {
-    fn cache_response(&self, _: &str, _: &[u8], _: &[u8], _: &Option<String>) -> Result<()> {
+    fn cache_response(
+        &self,
+        _: &str,
+        _: &[u8],
+        _: Option<&[u8]>,
+        _: &Option<String>,
+    ) -> Result<()> {
         Ok(())
     }
     fn lookup_etag(&self, _uri: &str) -> Result<String> {
@@ -80,17 +105,22 @@ impl HttpCache for FileBasedCache {
         &self,
         uri: &str,
         body: &[u8],
-        etag: &[u8],
+        etag: Option<&[u8]>,
         next_link: &Option<String>,
     ) -> Result<()> {
-        let mut path = cache_path(&self.root, &uri, "json");
+        let mut path = cache_path(&self.root, uri, "json");
         trace!("caching body at path: {}", path.display());
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
         fs::write(&path, body)?;
         path.set_extension("etag");
-        fs::write(&path, etag)?;
+        match etag {
+            Some(etag) => fs::write(&path, etag)?,
+            // don't send back the etag of an earlier response
+            None if path.exists() => fs::remove_file(&path)?,
+            None => (),
+        }
         if let Some(next_link) = next_link {
             path.set_extension("next_link");
             fs::write(&path, next_link)?;
@@ -114,6 +144,102 @@ impl HttpCache for FileBasedCache {
             Ok(None)
         }
     }
+
+    fn cache_last_modified(&self, uri: &str, last_modified: Option<&[u8]>) -> Result<()> {
+        let path = cache_path(&self.root, uri, "last_modified");
+        match last_modified {
+            Some(last_modified) => {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(&path, last_modified)?
+            }
+            None if path.exists() => fs::remove_file(&path)?,
+            None => (),
+        }
+        Ok(())
+    }
+
+    fn lookup_last_modified(&self, uri: &str) -> Result<String> {
+        read_to_string(cache_path(&self.root, uri, "last_modified"))
+    }
+}
+
+/// A cache which keeps responses in memory for the life of the process.
+/// Clones share the same entries
+///
+/// Entries are never evicted, so the cache grows with every distinct url
+/// requested. It suits short lived processes and clients requesting a bounded
+/// set of urls. Long running processes polling many urls should prefer the
+/// disk backed `FileBasedCache`
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryCache {
+    entries: Arc<Mutex<HashMap<String, CacheEntry>>>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct CacheEntry {
+    body: Vec<u8>,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    next_link: Option<String>,
+}
+
+impl InMemoryCache {
+    fn lookup<T, F>(&self, uri: &str, f: F) -> Result<T>
+    where
+        F: FnOnce(&CacheEntry) -> Option<T>,
+    {
+        let entries = self.entries.lock().unwrap();
+        match entries.get(uri).and_then(f) {
+            Some(value) => Ok(value),
+            None => no_read(format!("Nothing cached for {}", uri)),
+        }
+    }
+}
+
+impl HttpCache for InMemoryCache {
+    fn cache_response(
+        &self,
+        uri: &str,
+        body: &[u8],
+        etag: Option<&[u8]>,
+        next_link: &Option<String>,
+    ) -> Result<()> {
+        trace!("caching body in memory for uri: {}", uri);
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.entry(uri.to_string()).or_default();
+        entry.body = body.to_vec();
+        entry.etag = etag.map(|etag| String::from_utf8_lossy(etag).into_owned());
+        entry.next_link = next_link.clone();
+        Ok(())
+    }
+
+    fn lookup_etag(&self, uri: &str) -> Result<String> {
+        self.lookup(uri, |entry| entry.etag.clone())
+    }
+
+    fn lookup_body(&self, uri: &str) -> Result<String> {
+        self.lookup(uri, |entry| {
+            Some(String::from_utf8_lossy(&entry.body).into_owned())
+        })
+    }
+
+    fn lookup_next_link(&self, uri: &str) -> Result<Option<String>> {
+        self.lookup(uri, |entry| Some(entry.next_link.clone()))
+    }
+
+    fn cache_last_modified(&self, uri: &str, last_modified: Option<&[u8]>) -> Result<()> {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.entry(uri.to_string()).or_default();
+        entry.last_modified =
+            last_modified.map(|last_modified| String::from_utf8_lossy(last_modified).into_owned());
+        Ok(())
+    }
+
+    fn lookup_last_modified(&self, uri: &str) -> Result<String> {
+        self.lookup(uri, |entry| entry.last_modified.clone())
+    }
 }
 
 /// Construct the cache path for the given URI and extension, from an initial directory.
@@ -195,3 +321,43 @@ fn hash1<A: Hash, H: Hasher>(x: A, mut hasher: H) -> String {
 pub fn u64_to_padded_hex(x: u64) -> String {
     format!("{:016x}", x)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_memory_cache_round_trip() {
+        let cache = InMemoryCache::default();
+        let uri = "https://api.github.com/users/octocat/repos";
+        assert!(cache.lookup_etag(uri).is_err());
+
+        let next_link = Some(format!("{}?page=2", uri));
+        cache
+            .cache_response(uri, b"[]", Some(b"\"abc\""), &next_link)
+            .unwrap();
+        cache
+            .cache_last_modified(uri, Some(b"Wed, 21 Oct 2015 07:28:00 GMT"))
+            .unwrap();
+
+        // clones share entries
+        let cache = cache.clone();
+        assert_eq!(cache.lookup_body(uri).unwrap(), "[]");
+        assert_eq!(cache.lookup_etag(uri).unwrap(), "\"abc\"");
+        assert_eq!(
+            cache.lookup_last_modified(uri).unwrap(),
+            "Wed, 21 Oct 2015 07:28:00 GMT"
+        );
+        assert_eq!(cache.lookup_next_link(uri).unwrap(), next_link);
+
+        // a later response without an etag drops the earlier one
+        cache.cache_response(uri, b"[1]", None, &None).unwrap();
+        assert!(cache.lookup_etag(uri).is_err());
+        assert_eq!(cache.lookup_body(uri).unwrap(), "[1]");
+
+        // as does a later response without a last modified header
+        cache.cache_last_modified(uri, None).unwrap();
+        assert!(cache.lookup_last_modified(uri).is_err());
+        assert_eq!(cache.lookup_body(uri).unwrap(), "[1]");
+    }
+}
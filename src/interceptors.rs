@@ -0,0 +1,137 @@
+//! Interceptors interface
+//!
+//! Interceptors registered with `Github#add_interceptor` see every request
+//! hubcaps sends before it goes out and every response before it is decoded.
+//! They're useful for adding headers of your own, like correlation ids, or
+//! for recording metrics, like the latency of each endpoint
+//!
+//! ```no_run
+//! use hubcaps::{interceptors, Credentials, Github};
+//! use hubcaps::reqwest::header::HeaderValue;
+//!
+//! let mut github = Github::new(
+//!     "my-cool-user-agent/0.1.0",
+//!     Credentials::Token("personal-access-token".into()),
+//! ).unwrap();
+//! github.add_interceptor(interceptors::on_request(|request| {
+//!     request
+//!         .headers_mut()
+//!         .insert("x-correlation-id", HeaderValue::from_static("abc123"));
+//! }));
+//! github.add_interceptor(interceptors::on_response(|method, response, elapsed| {
+//!     println!("{} {} took {:?}", method, response.url().path(), elapsed);
+//! }));
+//! ```
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use http::Method;
+use reqwest::r#async::{Request, Response};
+
+/// Inspects requests before they are sent and responses before they are
+/// decoded. Both methods do nothing by default
+pub trait Interceptor: Send + Sync {
+    /// called with each request before it is sent. changes made to the
+    /// request, like added headers, are sent along with it
+    fn on_request(&self, _request: &mut Request) {}
+
+    /// called with each response, and the method and time taken of the
+    /// request it answers, before its body is read
+    fn on_response(&self, _method: &Method, _response: &Response, _elapsed: Duration) {}
+}
+
+/// Create an interceptor which calls `f` with each request before it is sent
+pub fn on_request<F>(f: F) -> OnRequest<F>
+where
+    F: Fn(&mut Request) + Send + Sync,
+{
+    OnRequest(f)
+}
+
+/// Create an interceptor which calls `f` with each response before it is
+/// decoded
+pub fn on_response<F>(f: F) -> OnResponse<F>
+where
+    F: Fn(&Method, &Response, Duration) + Send + Sync,
+{
+    OnResponse(f)
+}
+
+/// An interceptor of requests created with `interceptors::on_request`
+pub struct OnRequest<F>(F);
+
+impl<F> Interceptor for OnRequest<F>
+where
+    F: Fn(&mut Request) + Send + Sync,
+{
+    fn on_request(&self, request: &mut Request) {
+        (self.0)(request)
+    }
+}
+
+/// An interceptor of responses created with `interceptors::on_response`
+pub struct OnResponse<F>(F);
+
+impl<F> Interceptor for OnResponse<F>
+where
+    F: Fn(&Method, &Response, Duration) + Send + Sync,
+{
+    fn on_response(&self, method: &Method, response: &Response, elapsed: Duration) {
+        (self.0)(method, response, elapsed)
+    }
+}
+
+/// The interceptors registered with a `Github` client, in the order they were
+/// added
+#[derive(Clone, Default)]
+pub(crate) struct Interceptors(Vec<Arc<dyn Interceptor>>);
+
+impl Interceptors {
+    pub(crate) fn push(&mut self, interceptor: Arc<dyn Interceptor>) {
+        self.0.push(interceptor)
+    }
+
+    pub(crate) fn on_request(&self, request: &mut Request) {
+        for interceptor in &self.0 {
+            interceptor.on_request(request)
+        }
+    }
+
+    pub(crate) fn on_response(&self, method: &Method, response: &Response, elapsed: Duration) {
+        for interceptor in &self.0 {
+            interceptor.on_response(method, response, elapsed)
+        }
+    }
+}
+
+impl fmt::Debug for Interceptors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Interceptors({})", self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn interceptors_run_in_order() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut interceptors = Interceptors::default();
+        for expected in 0..2 {
+            let calls = calls.clone();
+            interceptors.push(Arc::new(on_request(move |_| {
+                assert_eq!(calls.fetch_add(1, Ordering::SeqCst), expected);
+            })));
+        }
+        let mut request = reqwest::r#async::Client::new()
+            .get("https://api.github.com/")
+            .build()
+            .unwrap();
+        interceptors.on_request(&mut request);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(format!("{:?}", interceptors), "Interceptors(2)");
+    }
+}
@@ -0,0 +1,241 @@
+//! Issue events and timeline interface
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/issues/events/)
+use serde::Deserialize;
+
+use crate::issues::Issue;
+use crate::users::User;
+use crate::{DateTime, Future, Github, Stream};
+
+/// Provides access to the events of an individual issue
+pub struct IssueEvents {
+    github: Github,
+    owner: String,
+    repo: String,
+    number: u64,
+}
+
+impl IssueEvents {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R, number: u64) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        IssueEvents {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+            number,
+        }
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "/repos/{}/{}/issues/{}/events",
+            self.owner, self.repo, self.number
+        )
+    }
+
+    /// list the first page of events of this issue
+    pub fn list(&self) -> Future<Vec<IssueEvent>> {
+        self.github.get(&self.path())
+    }
+
+    /// provides a stream over all pages of events of this issue
+    pub fn iter(&self) -> Stream<IssueEvent> {
+        self.github.get_stream(&self.path())
+    }
+}
+
+/// Provides access to the timeline of an individual issue, its events along
+/// with comments, commits, and cross references from other issues
+pub struct IssueTimeline {
+    github: Github,
+    owner: String,
+    repo: String,
+    number: u64,
+}
+
+impl IssueTimeline {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R, number: u64) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        IssueTimeline {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+            number,
+        }
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "/repos/{}/{}/issues/{}/timeline",
+            self.owner, self.repo, self.number
+        )
+    }
+
+    /// list the first page of this issue's timeline
+    ///
+    /// See the [github docs](https://developer.github.com/v3/issues/timeline/)
+    /// for more information.
+    pub fn list(&self) -> Future<Vec<IssueEvent>> {
+        self.github.get(&self.path())
+    }
+
+    /// provides a stream over all pages of this issue's timeline
+    pub fn iter(&self) -> Stream<IssueEvent> {
+        self.github.get_stream(&self.path())
+    }
+}
+
+// representations
+
+/// An event in the history of an issue. Fields common to every kind of event
+/// are optional because some timeline events, like `cross-referenced` and
+/// `committed`, don't have them
+#[derive(Debug, Deserialize)]
+pub struct IssueEvent {
+    pub id: Option<u64>,
+    pub actor: Option<User>,
+    pub created_at: Option<DateTime>,
+    #[serde(flatten)]
+    pub kind: IssueEventKind,
+}
+
+/// The kind of an issue event, along with the fields specific to it
+#[derive(Debug, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum IssueEventKind {
+    Assigned {
+        assignee: User,
+    },
+    Unassigned {
+        assignee: User,
+    },
+    Labeled {
+        label: EventLabel,
+    },
+    Unlabeled {
+        label: EventLabel,
+    },
+    Milestoned {
+        milestone: EventMilestone,
+    },
+    Demilestoned {
+        milestone: EventMilestone,
+    },
+    Renamed {
+        rename: EventRename,
+    },
+    Closed {
+        /// the commit that closed the issue, if any
+        commit_id: Option<String>,
+    },
+    Reopened,
+    Locked {
+        lock_reason: Option<String>,
+    },
+    Unlocked,
+    /// the issue was referenced from a commit message
+    Referenced {
+        commit_id: Option<String>,
+    },
+    Merged {
+        commit_id: Option<String>,
+    },
+    ReviewRequested {
+        requested_reviewer: Option<User>,
+    },
+    ReviewRequestRemoved {
+        requested_reviewer: Option<User>,
+    },
+    HeadRefDeleted,
+    HeadRefRestored,
+    /// a comment on the issue. timeline only
+    Commented {
+        body: String,
+        html_url: String,
+        user: User,
+    },
+    /// a commit pushed to a pull request. timeline only
+    Committed {
+        sha: String,
+        message: String,
+    },
+    /// the issue was mentioned from another issue or pull request. timeline only
+    #[serde(rename = "cross-referenced")]
+    CrossReferenced {
+        source: Box<CrossReferenceSource>,
+    },
+    /// an event hubcaps doesn't define fields for
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EventLabel {
+    pub name: String,
+    pub color: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EventMilestone {
+    pub title: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EventRename {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CrossReferenceSource {
+    /// currently always `issue`
+    #[serde(rename = "type")]
+    pub source_type: String,
+    /// the issue or pull request the reference was made from
+    pub issue: Option<Issue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_issue_events() {
+        let events: Vec<IssueEvent> = serde_json::from_str(
+            r#"[
+                {"id": 1, "event": "renamed", "created_at": "2011-04-14T16:00:49Z",
+                 "rename": {"from": "old title", "to": "new title"}},
+                {"id": 2, "event": "closed", "created_at": "2011-04-14T16:00:49Z",
+                 "commit_id": null},
+                {"event": "committed", "sha": "d4e5f6", "message": "fix the bug"},
+                {"id": 3, "event": "connected", "created_at": "2011-04-14T16:00:49Z"}
+            ]"#,
+        )
+        .unwrap();
+        match &events[0].kind {
+            IssueEventKind::Renamed { rename } => {
+                assert_eq!(rename.from, "old title");
+                assert_eq!(rename.to, "new title");
+            }
+            other => panic!("expected a rename, got {:?}", other),
+        }
+        match &events[1].kind {
+            IssueEventKind::Closed { commit_id } => assert_eq!(*commit_id, None),
+            other => panic!("expected a close, got {:?}", other),
+        }
+        assert_eq!(events[2].id, None);
+        match &events[3].kind {
+            IssueEventKind::Other => assert_eq!(events[3].id, Some(3)),
+            other => panic!("expected an unknown event, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,455 @@
+//! Issue and pull request templates interface
+//!
+//! Discovers a repository's [issue templates](https://help.github.com/en/articles/configuring-issue-templates-for-your-repository),
+//! both markdown templates and issue forms, its template chooser `config.yml`,
+//! and its pull request template through the contents api.
+use futures::future::{self, Loop};
+use futures::{Future as StdFuture, Stream as StdStream};
+use http::StatusCode;
+use serde::de::Deserializer;
+use serde::Deserialize;
+
+use crate::content::Content;
+use crate::errors::{Error, ErrorKind, Result};
+use crate::Future;
+use crate::Github;
+
+const TEMPLATE_DIR: &str = "/.github/ISSUE_TEMPLATE";
+
+/// the locations github looks for a pull request template in, in order
+const PULL_REQUEST_TEMPLATES: &[&str] = &[
+    "/.github/pull_request_template.md",
+    "/.github/PULL_REQUEST_TEMPLATE.md",
+    "/pull_request_template.md",
+    "/PULL_REQUEST_TEMPLATE.md",
+    "/docs/pull_request_template.md",
+    "/docs/PULL_REQUEST_TEMPLATE.md",
+];
+
+/// Provides access to the issue and pull request templates of a repository
+pub struct IssueTemplates {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl IssueTemplates {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        IssueTemplates {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn content(&self) -> Content {
+        Content::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// list and parse the issue templates of this repository. empty when the
+    /// repository has no `.github/ISSUE_TEMPLATE` directory
+    pub fn list(&self) -> Future<Vec<IssueTemplate>> {
+        let content = self.content();
+        Box::new(
+            not_found_as_none(self.content().iter(TEMPLATE_DIR).collect()).and_then(move |items| {
+                future::join_all(
+                    items
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter(|item| item._type == "file" && is_template(&item.name))
+                        .map(|item| {
+                            content
+                                .file(&format!("/{}", item.path))
+                                .and_then(|file| IssueTemplate::parse(&file.path, &file.content))
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            }),
+        )
+    }
+
+    /// get the template chooser configuration of this repository, if any
+    pub fn config(&self) -> Future<Option<IssueTemplateConfig>> {
+        let content = self.content();
+        Box::new(
+            not_found_as_none(content.file(&format!("{}/config.yml", TEMPLATE_DIR))).and_then(
+                |file| match file {
+                    Some(file) => serde_yaml::from_slice(&file.content)
+                        .map(Some)
+                        .map_err(|e| yaml_error(&file.path, &e)),
+                    None => Ok(None),
+                },
+            ),
+        )
+    }
+
+    /// get the pull request template of this repository, if any
+    pub fn pull_request_template(&self) -> Future<Option<PullRequestTemplate>> {
+        let content = self.content();
+        Box::new(future::loop_fn(
+            0,
+            move |index| match PULL_REQUEST_TEMPLATES.get(index) {
+                Some(location) => Box::new(not_found_as_none(content.file(location)).map(
+                    move |file| match file {
+                        Some(file) => Loop::Break(Some(PullRequestTemplate {
+                            path: file.path,
+                            body: String::from_utf8_lossy(&file.content).into_owned(),
+                        })),
+                        None => Loop::Continue(index + 1),
+                    },
+                )) as Future<_>,
+                None => Box::new(future::ok(Loop::Break(None))),
+            },
+        ))
+    }
+}
+
+fn is_template(name: &str) -> bool {
+    let name = name.to_lowercase();
+    name != "config.yml"
+        && name != "config.yaml"
+        && (name.ends_with(".md") || name.ends_with(".yml") || name.ends_with(".yaml"))
+}
+
+fn not_found_as_none<T>(
+    future: impl StdFuture<Item = T, Error = Error> + Send + 'static,
+) -> Future<Option<T>>
+where
+    T: Send + 'static,
+{
+    Box::new(future.map(Some).or_else(|err| match err {
+        Error(
+            ErrorKind::Fault {
+                code: StatusCode::NOT_FOUND,
+                ..
+            },
+            _,
+        ) => Ok(None),
+        otherwise => Err(otherwise),
+    }))
+}
+
+fn yaml_error(path: &str, err: &serde_yaml::Error) -> Error {
+    ErrorKind::Msg(format!("invalid template {}: {}", path, err)).into()
+}
+
+// representations
+
+/// An issue template
+#[derive(Debug, PartialEq)]
+pub struct IssueTemplate {
+    /// the path of the template in the repository
+    pub path: String,
+    pub name: String,
+    /// the `about` of markdown templates or `description` of issue forms
+    pub description: String,
+    /// the default title of issues opened with the template
+    pub title: Option<String>,
+    pub labels: Vec<String>,
+    pub assignees: Vec<String>,
+    pub body: TemplateBody,
+}
+
+impl IssueTemplate {
+    /// parse a template from its path and contents. files ending in `.md` are
+    /// parsed as markdown templates with yaml front matter, all others as
+    /// issue forms
+    pub fn parse(path: &str, contents: &[u8]) -> Result<IssueTemplate> {
+        if path.to_lowercase().ends_with(".md") {
+            let contents = String::from_utf8_lossy(contents);
+            let (front_matter, body) = split_front_matter(&contents);
+            let front: MarkdownFrontMatter =
+                serde_yaml::from_str(front_matter).map_err(|e| yaml_error(path, &e))?;
+            Ok(IssueTemplate {
+                path: path.into(),
+                name: front.name,
+                description: front.about,
+                title: front.title,
+                labels: front.labels,
+                assignees: front.assignees,
+                body: TemplateBody::Markdown(body.into()),
+            })
+        } else {
+            let form: IssueForm =
+                serde_yaml::from_slice(contents).map_err(|e| yaml_error(path, &e))?;
+            Ok(IssueTemplate {
+                path: path.into(),
+                name: form.name,
+                description: form.description,
+                title: form.title,
+                labels: form.labels,
+                assignees: form.assignees,
+                body: TemplateBody::Form(form.body),
+            })
+        }
+    }
+}
+
+/// split markdown into its `---` delimited yaml front matter and body
+fn split_front_matter(contents: &str) -> (&str, &str) {
+    let contents = contents.trim_start_matches('\u{feff}');
+    if let Some(rest) = contents.strip_prefix("---") {
+        if let Some(end) = rest.find("\n---") {
+            let body = &rest[end + 4..];
+            let body = match body.find('\n') {
+                Some(newline) => &body[newline + 1..],
+                None => "",
+            };
+            return (&rest[..end], body);
+        }
+    }
+    ("{}", contents)
+}
+
+/// The body of an issue template
+#[derive(Debug, PartialEq)]
+pub enum TemplateBody {
+    /// the markdown an issue's body is prefilled with
+    Markdown(String),
+    /// the elements of an issue form
+    Form(Vec<FormElement>),
+}
+
+/// An element of an issue form
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum FormElement {
+    /// text displayed to the user, not included in the issue
+    Markdown { attributes: MarkdownAttributes },
+    Textarea {
+        id: Option<String>,
+        attributes: TextAttributes,
+        #[serde(default)]
+        validations: Validations,
+    },
+    Input {
+        id: Option<String>,
+        attributes: TextAttributes,
+        #[serde(default)]
+        validations: Validations,
+    },
+    Dropdown {
+        id: Option<String>,
+        attributes: DropdownAttributes,
+        #[serde(default)]
+        validations: Validations,
+    },
+    Checkboxes {
+        id: Option<String>,
+        attributes: CheckboxesAttributes,
+        #[serde(default)]
+        validations: Validations,
+    },
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct MarkdownAttributes {
+    pub value: String,
+}
+
+/// The attributes of `textarea` and `input` elements
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct TextAttributes {
+    pub label: String,
+    pub description: Option<String>,
+    pub placeholder: Option<String>,
+    pub value: Option<String>,
+    /// the language textarea values are rendered as code in
+    pub render: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct DropdownAttributes {
+    pub label: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub multiple: bool,
+    pub options: Vec<String>,
+    /// the index of the option selected by default
+    pub default: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CheckboxesAttributes {
+    pub label: String,
+    pub description: Option<String>,
+    pub options: Vec<CheckboxOption>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CheckboxOption {
+    pub label: String,
+    #[serde(default)]
+    pub required: bool,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+pub struct Validations {
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// The template chooser configuration of a repository
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct IssueTemplateConfig {
+    /// whether issues may be opened without a template. true by default
+    #[serde(default = "default_blank_issues_enabled")]
+    pub blank_issues_enabled: bool,
+    #[serde(default)]
+    pub contact_links: Vec<ContactLink>,
+}
+
+fn default_blank_issues_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ContactLink {
+    pub name: String,
+    pub url: String,
+    pub about: String,
+}
+
+/// A pull request template
+#[derive(Debug, PartialEq)]
+pub struct PullRequestTemplate {
+    /// the path of the template in the repository
+    pub path: String,
+    pub body: String,
+}
+
+#[derive(Deserialize)]
+struct MarkdownFrontMatter {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    about: String,
+    title: Option<String>,
+    #[serde(default, deserialize_with = "comma_separated")]
+    labels: Vec<String>,
+    #[serde(default, deserialize_with = "comma_separated")]
+    assignees: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct IssueForm {
+    name: String,
+    description: String,
+    title: Option<String>,
+    #[serde(default, deserialize_with = "comma_separated")]
+    labels: Vec<String>,
+    #[serde(default, deserialize_with = "comma_separated")]
+    assignees: Vec<String>,
+    body: Vec<FormElement>,
+}
+
+/// labels and assignees may be either a list or a comma separated string
+fn comma_separated<'de, D>(deserializer: D) -> std::result::Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Values {
+        List(Vec<String>),
+        Joined(String),
+    }
+    match Option::<Values>::deserialize(deserializer)? {
+        Some(Values::List(values)) => Ok(values),
+        Some(Values::Joined(values)) => Ok(values
+            .split(',')
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .map(String::from)
+            .collect()),
+        None => Ok(Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_markdown_template() {
+        let template = IssueTemplate::parse(
+            ".github/ISSUE_TEMPLATE/bug_report.md",
+            b"---\nname: Bug report\nabout: Create a report to help us improve\ntitle: '[bug] '\nlabels: bug, triage\nassignees: ''\n---\n\n**Describe the bug**\n",
+        )
+        .unwrap();
+        assert_eq!(template.name, "Bug report");
+        assert_eq!(template.description, "Create a report to help us improve");
+        assert_eq!(template.title, Some("[bug] ".into()));
+        assert_eq!(template.labels, vec!["bug", "triage"]);
+        assert!(template.assignees.is_empty());
+        assert_eq!(
+            template.body,
+            TemplateBody::Markdown("\n**Describe the bug**\n".into())
+        );
+    }
+
+    #[test]
+    fn parse_issue_form() {
+        let template = IssueTemplate::parse(
+            ".github/ISSUE_TEMPLATE/bug.yml",
+            br#"
+name: Bug Report
+description: File a bug report
+labels: ["bug"]
+body:
+  - type: markdown
+    attributes:
+      value: Thanks for taking the time!
+  - type: input
+    id: version
+    attributes:
+      label: Version
+      placeholder: "0.5.0"
+    validations:
+      required: true
+  - type: dropdown
+    id: os
+    attributes:
+      label: Operating system
+      options: [linux, macos, windows]
+"#,
+        )
+        .unwrap();
+        assert_eq!(template.labels, vec!["bug"]);
+        match template.body {
+            TemplateBody::Form(elements) => {
+                assert_eq!(elements.len(), 3);
+                assert_eq!(
+                    elements[1],
+                    FormElement::Input {
+                        id: Some("version".into()),
+                        attributes: TextAttributes {
+                            label: "Version".into(),
+                            description: None,
+                            placeholder: Some("0.5.0".into()),
+                            value: None,
+                            render: None,
+                        },
+                        validations: Validations { required: true },
+                    }
+                );
+            }
+            body => panic!("expected a form, got {:?}", body),
+        }
+    }
+
+    #[test]
+    fn parse_config() {
+        let config: IssueTemplateConfig = serde_yaml::from_str(
+            "contact_links:\n  - name: Community support\n    url: https://example.com\n    about: Ask questions here\n",
+        )
+        .unwrap();
+        assert!(config.blank_issues_enabled);
+        assert_eq!(config.contact_links.len(), 1);
+    }
+}
@@ -2,18 +2,23 @@
 use std::collections::HashMap;
 use std::fmt;
 
+use futures::Future as StdFuture;
+use http::StatusCode;
 use url::form_urlencoded;
 use serde::{Deserialize, Serialize};
 
-use crate::comments::Comments;
-use crate::labels::Label;
+use crate::comments::{Comment, CommentListOptions, Comments};
+use crate::issue_events::{IssueEvents, IssueTimeline};
+use crate::labels::{self, Label};
+use crate::milestones::Milestone;
 use crate::users::User;
-use crate::{Future, Github, SortDirection, Stream};
+use crate::{DateTime, Error, ErrorKind, Future, Github, SortDirection, Stream};
 
 /// enum representation of github pull and issue state
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub enum State {
     /// Only open issues
+    #[default]
     Open,
     /// Only closed issues
     Closed,
@@ -32,16 +37,42 @@ impl fmt::Display for State {
     }
 }
 
-impl Default for State {
-    fn default() -> State {
-        State::Open
+/// The state of an issue
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueState {
+    Open,
+    Closed,
+}
+
+impl fmt::Display for IssueState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            IssueState::Open => "open",
+            IssueState::Closed => "closed",
+        }
+        .fmt(f)
     }
 }
 
+/// Why an issue's conversation was locked
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub enum LockReason {
+    #[serde(rename = "off-topic")]
+    OffTopic,
+    #[serde(rename = "too heated")]
+    TooHeated,
+    #[serde(rename = "resolved")]
+    Resolved,
+    #[serde(rename = "spam")]
+    Spam,
+}
+
 /// Sort options available for github issues
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub enum Sort {
     /// sort by creation time of issue
+    #[default]
     Created,
     /// sort by the last time issue was updated
     Updated,
@@ -60,9 +91,31 @@ impl fmt::Display for Sort {
     }
 }
 
-impl Default for Sort {
-    fn default() -> Sort {
-        Sort::Created
+/// Which of the authenticated user's issues to list
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IssueFilter {
+    /// issues assigned to the user (the default)
+    Assigned,
+    /// issues created by the user
+    Created,
+    /// issues mentioning the user
+    Mentioned,
+    /// issues the user is subscribed to
+    Subscribed,
+    /// all issues the user can see
+    All,
+}
+
+impl fmt::Display for IssueFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            IssueFilter::Assigned => "assigned",
+            IssueFilter::Created => "created",
+            IssueFilter::Mentioned => "mentioned",
+            IssueFilter::Subscribed => "subscribed",
+            IssueFilter::All => "all",
+        }
+        .fmt(f)
     }
 }
 
@@ -100,6 +153,41 @@ impl IssueAssignees {
     pub fn add(&self, assignees: Vec<&str>) -> Future<Issue> {
         self.github.post(&self.path(""), json_lit!({ "assignees": assignees }))
     }
+
+    /// remove a set of assignees
+    ///
+    /// See the [github docs](https://developer.github.com/v3/issues/assignees/#remove-assignees-from-an-issue)
+    /// for more information.
+    pub fn remove(&self, assignees: Vec<&str>) -> Future<()> {
+        self.github
+            .delete_message(&self.path(""), json_lit!({ "assignees": assignees }))
+    }
+
+    /// check whether a user may be assigned to this issue
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/issues/assignees#check-if-a-user-can-be-assigned-to-a-issue)
+    /// for more information.
+    pub fn check<U>(&self, user: U) -> Future<bool>
+    where
+        U: Into<String>,
+    {
+        Box::new(
+            self.github
+                .get::<()>(&self.path(&format!("/{}", user.into())))
+                .map(|_| true)
+                .or_else(|err| match err {
+                    Error(
+                        ErrorKind::Fault {
+                            code: StatusCode::NOT_FOUND,
+                            ..
+                        },
+                        _,
+                    ) => Ok(false),
+                    Error(ErrorKind::Codec(_), _) => Ok(true),
+                    otherwise => Err(otherwise),
+                }),
+        )
+    }
 }
 
 /// Provides access to label operations available for an individual issue
@@ -132,6 +220,16 @@ impl IssueLabels {
         )
     }
 
+    /// list the labels of this issue
+    pub fn list(&self) -> Future<Vec<Label>> {
+        self.github.get(&self.path(""))
+    }
+
+    /// provides a stream over all pages of the labels of this issue
+    pub fn iter(&self) -> Stream<Label> {
+        self.github.get_stream(&self.path(""))
+    }
+
     /// add a set of labels to this issue ref
     #[allow(clippy::needless_pass_by_value)] // shipped public API
     pub fn add(&self, labels: Vec<&str>) -> Future<Vec<Label>> {
@@ -140,7 +238,8 @@ impl IssueLabels {
 
     /// remove a label from this issue
     pub fn remove(&self, label: &str) -> Future<()> {
-        self.github.delete(&self.path(&format!("/{}", label)))
+        self.github
+            .delete(&self.path(&format!("/{}", labels::encode(label))))
     }
 
     /// replace all labels associated with this issue with a new set.
@@ -218,6 +317,56 @@ impl IssueRef {
         self.github.patch(&self.path(""), json!(is))
     }
 
+    /// Set the milestone of this issue by its number, or clear it with `None`
+    pub fn set_milestone(&self, milestone: Option<u64>) -> Future<Issue> {
+        self.github
+            .patch(&self.path(""), json_lit!({ "milestone": milestone }))
+    }
+
+    /// Open or close this issue
+    pub fn set_state(&self, state: IssueState) -> Future<Issue> {
+        self.github
+            .patch(&self.path(""), json_lit!({ "state": state }))
+    }
+
+    /// Lock this issue's conversation so only collaborators can comment on
+    /// it, optionally giving a reason
+    pub fn lock<R>(&self, reason: R) -> Future<()>
+    where
+        R: Into<Option<LockReason>>,
+    {
+        let body = match reason.into() {
+            Some(reason) => json_lit!({ "lock_reason": reason }),
+            None => Vec::new(),
+        };
+        self.github.put_no_response(&self.path("/lock"), body)
+    }
+
+    /// Unlock this issue's conversation
+    pub fn unlock(&self) -> Future<()> {
+        self.github.delete(&self.path("/lock"))
+    }
+
+    /// Return a reference to the events of this issue
+    pub fn events(&self) -> IssueEvents {
+        IssueEvents::new(
+            self.github.clone(),
+            self.owner.as_str(),
+            self.repo.as_str(),
+            self.number,
+        )
+    }
+
+    /// Return a reference to the timeline of this issue
+    pub fn timeline(&self) -> IssueTimeline {
+        IssueTimeline::new(
+            self.github.clone(),
+            self.owner.as_str(),
+            self.repo.as_str(),
+            self.number,
+        )
+    }
+
     /// Return a reference to comment operations available for this issue
     pub fn comments(&self) -> Comments {
         Comments::new(
@@ -293,6 +442,76 @@ impl Issues {
         }
         self.github.get_stream(&uri.join("?"))
     }
+
+    /// Return the first page of comments on all issues of this repository
+    ///
+    /// See the [github docs](https://developer.github.com/v3/issues/comments/#list-comments-in-a-repository)
+    /// for more information
+    pub fn comments(&self, options: &CommentListOptions) -> Future<Vec<Comment>> {
+        let mut uri = vec![self.path("/comments")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get(&uri.join("?"))
+    }
+
+    /// Return a stream of comments on all issues of this repository
+    pub fn iter_comments(&self, options: &CommentListOptions) -> Stream<Comment> {
+        let mut uri = vec![self.path("/comments")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get_stream(&uri.join("?"))
+    }
+}
+
+/// Provides access to the issues of the authenticated user across all of
+/// the repositories they can see, including pull requests.
+/// Typically accessed via `github.issues()`
+pub struct UserIssues {
+    github: Github,
+}
+
+impl UserIssues {
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        UserIssues { github }
+    }
+
+    fn uri(path: &str, options: &IssueListOptions) -> String {
+        let mut uri = vec![path.to_owned()];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        uri.join("?")
+    }
+
+    /// Return the first page of issues of all repositories the authenticated
+    /// user can see, filtered by `IssueListOptionsBuilder#filter`
+    ///
+    /// See the [github docs](https://developer.github.com/v3/issues/#list-issues)
+    /// for more information
+    pub fn list(&self, options: &IssueListOptions) -> Future<Vec<Issue>> {
+        self.github.get(&Self::uri("/issues", options))
+    }
+
+    /// Return a stream of all issues of all repositories the authenticated
+    /// user can see
+    pub fn iter(&self, options: &IssueListOptions) -> Stream<Issue> {
+        self.github.get_stream(&Self::uri("/issues", options))
+    }
+
+    /// Return the first page of issues of the repositories the authenticated
+    /// user owns or is a member of
+    pub fn owned(&self, options: &IssueListOptions) -> Future<Vec<Issue>> {
+        self.github.get(&Self::uri("/user/issues", options))
+    }
+
+    /// Return a stream of all issues of the repositories the authenticated
+    /// user owns or is a member of
+    pub fn iter_owned(&self, options: &IssueListOptions) -> Stream<Issue> {
+        self.github.get_stream(&Self::uri("/user/issues", options))
+    }
 }
 
 // representations
@@ -332,6 +551,12 @@ impl IssueListOptions {
 pub struct IssueListOptionsBuilder(IssueListOptions);
 
 impl IssueListOptionsBuilder {
+    /// only applies to the authenticated user's issues
+    pub fn filter(&mut self, filter: IssueFilter) -> &mut Self {
+        self.0.params.insert("filter", filter.to_string());
+        self
+    }
+
     pub fn state(&mut self, state: State) -> &mut Self {
         self.0.params.insert("state", state.to_string());
         self
@@ -394,6 +619,16 @@ impl IssueListOptionsBuilder {
         self
     }
 
+    /// only list issues in the milestone with this number. `*` lists issues
+    /// with any milestone and `none` issues without one
+    pub fn milestone<M>(&mut self, milestone: M) -> &mut Self
+    where
+        M: Into<String>,
+    {
+        self.0.params.insert("milestone", milestone.into());
+        self
+    }
+
     pub fn since<S>(&mut self, since: S) -> &mut Self
     where
         S: Into<String>,
@@ -407,6 +642,11 @@ impl IssueListOptionsBuilder {
         self
     }
 
+    pub fn page(&mut self, n: u32) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
     pub fn build(&self) -> IssueListOptions {
         IssueListOptions {
             params: self.0.params.clone(),
@@ -462,21 +702,31 @@ pub struct Issue {
     pub events_url: String,
     pub html_url: String,
     pub number: u64,
-    pub state: String,
+    pub state: IssueState,
     pub title: String,
     pub body: Option<String>,
     pub user: User,
     pub labels: Vec<Label>,
     pub assignee: Option<User>,
+    pub milestone: Option<Milestone>,
     pub locked: bool,
+    /// why the conversation was locked, if it is locked with a reason
+    pub active_lock_reason: Option<LockReason>,
     pub comments: u64,
     pub pull_request: Option<PullRef>,
-    pub closed_at: Option<String>,
-    pub created_at: String,
-    pub updated_at: String,
+    pub closed_at: Option<DateTime>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
     pub assignees: Vec<User>,
 }
 
+impl Issue {
+    /// List the comments on this issue by following its `comments_url`.
+    pub fn comments(&self, github: &Github) -> Future<Vec<Comment>> {
+        github.get_url(&self.comments_url)
+    }
+}
+
 /// A reference to a pull request.
 #[derive(Debug, Deserialize)]
 pub struct PullRef {
@@ -496,13 +746,23 @@ mod tests {
         assert_eq!(default, State::Open)
     }
 
+    #[test]
+    fn lock_reasons_serialize_as_github_names() {
+        assert_eq!(
+            serde_json::to_string(&LockReason::TooHeated).unwrap(),
+            "\"too heated\""
+        );
+        assert_eq!(
+            serde_json::to_string(&LockReason::OffTopic).unwrap(),
+            "\"off-topic\""
+        );
+    }
+
     #[test]
     fn issue_list_reqs() {
         fn test_serialize(tests: Vec<(IssueListOptions, Option<String>)>) {
-            for test in tests {
-                match test {
-                    (k, v) => assert_eq!(k.serialize(), v),
-                }
+            for (k, v) in tests {
+                assert_eq!(k.serialize(), v);
             }
         }
         let tests = vec![
@@ -517,6 +777,12 @@ mod tests {
                     .build(),
                 Some("labels=foo%2Cbar".to_owned()),
             ),
+            (
+                IssueListOptions::builder()
+                    .filter(IssueFilter::Mentioned)
+                    .build(),
+                Some("filter=mentioned".to_owned()),
+            ),
         ];
         test_serialize(tests)
     }
@@ -1,11 +1,12 @@
-//! Deploy keys interface
+//! Deploy keys and user SSH keys interface
 //!
 //! This [this document](https://developer.github.com/guides/managing-deploy-keys/)
 //! for motivation and use
 use serde::{Deserialize, Serialize};
 
-use crate::{Future, Github};
+use crate::{DateTime, Future, Github, Stream};
 
+/// Provides access to the deploy keys of a repository
 pub struct Keys {
     github: Github,
     owner: String,
@@ -38,6 +39,11 @@ impl Keys {
         self.github.get(&self.path(""))
     }
 
+    /// provides a stream over all pages of this repo's deploy keys
+    pub fn iter(&self) -> Stream<Key> {
+        self.github.get_stream(&self.path(""))
+    }
+
     pub fn get(&self, id: u64) -> Future<Key> {
         self.github.get(&self.path(&format!("/{}", id)))
     }
@@ -47,6 +53,47 @@ impl Keys {
     }
 }
 
+/// Provides access to the SSH keys of the authenticated user
+pub struct UserKeys {
+    github: Github,
+}
+
+impl UserKeys {
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        UserKeys { github }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/user/keys{}", more)
+    }
+
+    /// adds an SSH key to the authenticated user's account
+    ///
+    /// See the [github docs](https://developer.github.com/v3/users/keys/#create-a-public-key)
+    /// for more information.
+    pub fn create(&self, key: &UserKeyOptions) -> Future<UserKey> {
+        self.github.post(&self.path(""), json!(key))
+    }
+
+    pub fn list(&self) -> Future<Vec<UserKey>> {
+        self.github.get(&self.path(""))
+    }
+
+    /// provides a stream over all pages of the authenticated user's SSH keys
+    pub fn iter(&self) -> Stream<UserKey> {
+        self.github.get_stream(&self.path(""))
+    }
+
+    pub fn get(&self, id: u64) -> Future<UserKey> {
+        self.github.get(&self.path(&format!("/{}", id)))
+    }
+
+    pub fn delete(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.path(&format!("/{}", id)))
+    }
+}
+
 // representations
 
 #[derive(Debug, Deserialize)]
@@ -55,7 +102,7 @@ pub struct Key {
     pub key: String,
     pub title: String,
     pub verified: bool,
-    pub created_at: String,
+    pub created_at: DateTime,
     pub read_only: bool,
 }
 
@@ -65,3 +112,30 @@ pub struct KeyOptions {
     pub key: String,
     pub read_only: bool,
 }
+
+/// An SSH key of the authenticated user
+#[derive(Debug, Deserialize)]
+pub struct UserKey {
+    pub id: u64,
+    pub key: String,
+    pub title: String,
+    pub url: String,
+    pub created_at: DateTime,
+    #[serde(default)]
+    pub verified: bool,
+    #[serde(default)]
+    pub read_only: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserKeyOptions {
+    pub title: String,
+    pub key: String,
+}
+
+/// A public SSH key of any user
+#[derive(Debug, Deserialize)]
+pub struct PublicKey {
+    pub id: u64,
+    pub key: String,
+}
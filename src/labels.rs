@@ -1,4 +1,5 @@
 //! Labels interface
+use percent_encoding::{utf8_percent_encode, PATH_SEGMENT_ENCODE_SET};
 use serde::{Deserialize, Serialize};
 
 use crate::{Future, Github, Stream};
@@ -27,17 +28,27 @@ impl Labels {
         format!("/repos/{}/{}/labels{}", self.owner, self.repo, more)
     }
 
+    fn label_path(&self, name: &str) -> String {
+        self.path(&format!("/{}", encode(name)))
+    }
+
     pub fn create(&self, lab: &LabelOptions) -> Future<Label> {
         self.github.post(&self.path(""), json!(lab))
     }
 
+    /// get a label by its name
+    pub fn get(&self, name: &str) -> Future<Label> {
+        self.github.get(&self.label_path(name))
+    }
+
+    /// update the label named `prevname`. the label is renamed when `lab`'s
+    /// name differs from `prevname`
     pub fn update(&self, prevname: &str, lab: &LabelOptions) -> Future<Label> {
-        self.github
-            .patch(&self.path(&format!("/{}", prevname)), json!(lab))
+        self.github.patch(&self.label_path(prevname), json!(lab))
     }
 
     pub fn delete(&self, name: &str) -> Future<()> {
-        self.github.delete(&self.path(&format!("/{}", name)))
+        self.github.delete(&self.label_path(name))
     }
 
     pub fn list(&self) -> Future<Vec<Label>> {
@@ -50,12 +61,21 @@ impl Labels {
     }
 }
 
+/// encodes a label name as a path segment. label names often contain spaces,
+/// like `good first issue`
+pub(crate) fn encode(name: &str) -> String {
+    utf8_percent_encode(name, PATH_SEGMENT_ENCODE_SET).to_string()
+}
+
 // representations
 
 #[derive(Debug, Serialize)]
 pub struct LabelOptions {
     pub name: String,
+    /// the hex color of the label, without the leading `#`
     pub color: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 impl LabelOptions {
@@ -67,8 +87,18 @@ impl LabelOptions {
         LabelOptions {
             name: name.into(),
             color: color.into(),
+            description: None,
         }
     }
+
+    /// describe the label, up to 100 characters
+    pub fn description<D>(mut self, description: D) -> Self
+    where
+        D: Into<String>,
+    {
+        self.description = Some(description.into());
+        self
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -76,4 +106,27 @@ pub struct Label {
     pub url: String,
     pub name: String,
     pub color: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// whether the label is one of the labels github gives new repositories
+    #[serde(default)]
+    pub default: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn label_paths_encode_names() {
+        let labels = Labels::new(
+            Github::new("agent", None).unwrap(),
+            "softprops",
+            "hubcaps",
+        );
+        assert_eq!(
+            labels.label_path("good first issue"),
+            "/repos/softprops/hubcaps/labels/good%20first%20issue"
+        );
+    }
 }
@@ -0,0 +1,135 @@
+//! Licenses interface
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/licenses/)
+use serde::Deserialize;
+
+use crate::repositories::License;
+use crate::{ErrorKind, Future, Github, Result};
+
+/// Provides access to the open source licenses github knows about
+pub struct Licenses {
+    github: Github,
+}
+
+impl Licenses {
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        Self { github }
+    }
+
+    /// list commonly used licenses
+    ///
+    /// See the [github docs](https://developer.github.com/v3/licenses/#list-commonly-used-licenses)
+    /// for more information.
+    pub fn list(&self) -> Future<Vec<License>> {
+        self.github.get("/licenses")
+    }
+
+    /// get a license by its key, like `mit`
+    ///
+    /// See the [github docs](https://developer.github.com/v3/licenses/#get-an-individual-license)
+    /// for more information.
+    pub fn get<K>(&self, key: K) -> Future<LicenseDetails>
+    where
+        K: Into<String>,
+    {
+        self.github.get(&format!("/licenses/{}", key.into()))
+    }
+
+    /// get the license detected in a repository along with the content of its
+    /// license file
+    ///
+    /// See the [github docs](https://developer.github.com/v3/licenses/#get-the-contents-of-a-repositorys-license)
+    /// for more information.
+    pub fn repo<O, R>(&self, owner: O, repo: R) -> Future<RepoLicense>
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        self.github
+            .get(&format!("/repos/{}/{}/license", owner.into(), repo.into()))
+    }
+}
+
+// representations
+
+/// The full text and terms of a license
+#[derive(Debug, Deserialize)]
+pub struct LicenseDetails {
+    pub key: String,
+    pub name: String,
+    pub spdx_id: Option<String>,
+    pub url: Option<String>,
+    pub node_id: Option<String>,
+    pub html_url: String,
+    pub description: String,
+    pub implementation: String,
+    /// things the license allows, like `commercial-use`
+    pub permissions: Vec<String>,
+    /// things the license requires, like `include-copyright`
+    pub conditions: Vec<String>,
+    /// things the license doesn't allow, like `liability`
+    pub limitations: Vec<String>,
+    /// the text of the license, with placeholders like `[year]`
+    pub body: String,
+    pub featured: bool,
+}
+
+/// The license file of a repository
+#[derive(Debug, Deserialize)]
+pub struct RepoLicense {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub size: u64,
+    pub url: String,
+    pub html_url: Option<String>,
+    pub git_url: Option<String>,
+    pub download_url: Option<String>,
+    pub content: String,
+    pub encoding: String,
+    pub license: License,
+}
+
+impl RepoLicense {
+    /// decode the license file's base64 encoded content
+    pub fn decode(&self) -> Result<Vec<u8>> {
+        if self.encoding != "base64" {
+            return Ok(self.content.clone().into_bytes());
+        }
+        base64::decode(&self.content.replace("\n", ""))
+            .map_err(|e| ErrorKind::Msg(format!("invalid license content: {}", e)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_repo_license() {
+        let license: RepoLicense = serde_json::from_value(serde_json::json!({
+            "name": "LICENSE",
+            "path": "LICENSE",
+            "sha": "401c59dcc4570b954dd6d345e76199e1f4e76266",
+            "size": 11,
+            "url": "https://api.github.com/repos/octocat/Hello-World/contents/LICENSE",
+            "html_url": null,
+            "git_url": null,
+            "download_url": null,
+            "content": "aGVsbG8g\nd29ybGQ=\n",
+            "encoding": "base64",
+            "license": {
+                "key": "mit",
+                "name": "MIT License",
+                "spdx_id": "MIT",
+                "url": "https://api.github.com/licenses/mit",
+                "node_id": "MDc6TGljZW5zZW1pdA=="
+            }
+        }))
+        .unwrap();
+        assert_eq!(license.license.key, "mit");
+        assert_eq!(license.decode().unwrap(), b"hello world".to_vec())
+    }
+}
@@ -0,0 +1,407 @@
+//! Organization members interface
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/orgs/members/)
+use std::collections::HashMap;
+use std::fmt;
+
+use futures::Future as StdFuture;
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
+
+use crate::organizations::Org;
+use crate::users::User;
+use crate::{DateTime, Error, ErrorKind, Future, Github, Stream};
+
+/// Provides access to the members, outside collaborators, and invitations of
+/// an organization
+pub struct OrgMembers {
+    github: Github,
+    org: String,
+}
+
+impl OrgMembers {
+    #[doc(hidden)]
+    pub fn new<O>(github: Github, org: O) -> Self
+    where
+        O: Into<String>,
+    {
+        OrgMembers {
+            github,
+            org: org.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/orgs/{}{}", self.org, more)
+    }
+
+    fn list_uri(&self, more: &str, options: &MemberListOptions) -> String {
+        let mut uri = vec![self.path(more)];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        uri.join("?")
+    }
+
+    /// list the members of this organization
+    ///
+    /// See the [github docs](https://developer.github.com/v3/orgs/members/#members-list)
+    /// for more information.
+    pub fn list(&self, options: &MemberListOptions) -> Future<Vec<User>> {
+        self.github.get(&self.list_uri("/members", options))
+    }
+
+    /// provides a stream over all pages of this organization's members
+    pub fn iter(&self, options: &MemberListOptions) -> Stream<User> {
+        self.github.get_stream(&self.list_uri("/members", options))
+    }
+
+    /// list the outside collaborators of this organization, users with access
+    /// to its repositories who aren't members
+    ///
+    /// See the [github docs](https://developer.github.com/v3/orgs/outside_collaborators/#list-outside-collaborators)
+    /// for more information.
+    pub fn outside_collaborators(&self, options: &MemberListOptions) -> Future<Vec<User>> {
+        self.github
+            .get(&self.list_uri("/outside_collaborators", options))
+    }
+
+    /// provides a stream over all pages of this organization's outside
+    /// collaborators
+    pub fn iter_outside_collaborators(&self, options: &MemberListOptions) -> Stream<User> {
+        self.github
+            .get_stream(&self.list_uri("/outside_collaborators", options))
+    }
+
+    /// check whether a user is a member of this organization
+    ///
+    /// See the [github docs](https://developer.github.com/v3/orgs/members/#check-membership)
+    /// for more information.
+    pub fn is_member<U>(&self, user: U) -> Future<bool>
+    where
+        U: Into<String>,
+    {
+        Box::new(
+            self.github
+                .get::<()>(&self.path(&format!("/members/{}", user.into())))
+                .map(|_| true)
+                .or_else(|err| match err {
+                    Error(
+                        ErrorKind::Fault {
+                            code: StatusCode::NOT_FOUND,
+                            ..
+                        },
+                        _,
+                    ) => Ok(false),
+                    Error(ErrorKind::Codec(_), _) => Ok(true),
+                    otherwise => Err(otherwise),
+                }),
+        )
+    }
+
+    /// get a user's membership of this organization, including their role and
+    /// whether they have accepted their invitation yet
+    ///
+    /// See the [github docs](https://developer.github.com/v3/orgs/members/#get-organization-membership)
+    /// for more information.
+    pub fn membership<U>(&self, user: U) -> Future<Membership>
+    where
+        U: Into<String>,
+    {
+        self.github
+            .get(&self.path(&format!("/memberships/{}", user.into())))
+    }
+
+    /// set a member's role, inviting the user to this organization if they
+    /// aren't a member yet
+    ///
+    /// See the [github docs](https://developer.github.com/v3/orgs/members/#add-or-update-organization-membership)
+    /// for more information.
+    pub fn set_role<U>(&self, user: U, role: MemberRole) -> Future<Membership>
+    where
+        U: Into<String>,
+    {
+        self.github.put(
+            &self.path(&format!("/memberships/{}", user.into())),
+            json_lit!({ "role": role }),
+        )
+    }
+
+    /// remove a user from this organization, and from all of its teams
+    ///
+    /// See the [github docs](https://developer.github.com/v3/orgs/members/#remove-a-member)
+    /// for more information.
+    pub fn remove<U>(&self, user: U) -> Future<()>
+    where
+        U: Into<String>,
+    {
+        self.github
+            .delete(&self.path(&format!("/members/{}", user.into())))
+    }
+
+    /// list the pending invitations to this organization
+    ///
+    /// See the [github docs](https://developer.github.com/v3/orgs/members/#list-pending-organization-invitations)
+    /// for more information.
+    pub fn invitations(&self) -> Future<Vec<Invitation>> {
+        self.github.get(&self.path("/invitations"))
+    }
+
+    /// provides a stream over all pages of pending invitations to this
+    /// organization
+    pub fn iter_invitations(&self) -> Stream<Invitation> {
+        self.github.get_stream(&self.path("/invitations"))
+    }
+
+    /// invite a user to this organization by their user id or email address
+    ///
+    /// See the [github docs](https://developer.github.com/v3/orgs/members/#create-organization-invitation)
+    /// for more information.
+    pub fn invite(&self, options: &InvitationOptions) -> Future<Invitation> {
+        self.github.post(&self.path("/invitations"), json!(options))
+    }
+
+    /// cancel a pending invitation to this organization
+    ///
+    /// See the [github docs](https://docs.github.com/en/rest/orgs/members#cancel-an-organization-invitation)
+    /// for more information.
+    pub fn cancel_invitation(&self, id: u64) -> Future<()> {
+        self.github
+            .delete(&self.path(&format!("/invitations/{}", id)))
+    }
+}
+
+// representations
+
+/// Filters members by whether they have two factor authentication enabled
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MemberFilter {
+    /// members without two factor authentication enabled. Only available to
+    /// organization owners
+    TwoFactorDisabled,
+    All,
+}
+
+impl fmt::Display for MemberFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MemberFilter::TwoFactorDisabled => "2fa_disabled",
+            MemberFilter::All => "all",
+        }
+        .fmt(f)
+    }
+}
+
+/// Filters members by their role in the organization
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MemberRoleFilter {
+    All,
+    Admin,
+    Member,
+}
+
+impl fmt::Display for MemberRoleFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MemberRoleFilter::All => "all",
+            MemberRoleFilter::Admin => "admin",
+            MemberRoleFilter::Member => "member",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Default)]
+pub struct MemberListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl MemberListOptions {
+    pub fn builder() -> MemberListOptionsBuilder {
+        MemberListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct MemberListOptionsBuilder(MemberListOptions);
+
+impl MemberListOptionsBuilder {
+    pub fn filter(&mut self, filter: MemberFilter) -> &mut Self {
+        self.0.params.insert("filter", filter.to_string());
+        self
+    }
+
+    /// only applies to members, outside collaborators have no role
+    pub fn role(&mut self, role: MemberRoleFilter) -> &mut Self {
+        self.0.params.insert("role", role.to_string());
+        self
+    }
+
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> MemberListOptions {
+        MemberListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// A member's role in an organization
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MemberRole {
+    Admin,
+    Member,
+    /// billing managers are added with invitations rather than `set_role`
+    BillingManager,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MembershipState {
+    Active,
+    Pending,
+}
+
+/// A user's membership of an organization
+#[derive(Debug, Deserialize)]
+pub struct Membership {
+    pub url: String,
+    pub state: MembershipState,
+    pub role: MemberRole,
+    pub organization_url: String,
+    pub organization: Org,
+    pub user: Option<User>,
+}
+
+/// The role a user is invited to an organization with
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum InvitationRole {
+    Admin,
+    DirectMember,
+    BillingManager,
+}
+
+/// A pending invitation to an organization
+#[derive(Debug, Deserialize)]
+pub struct Invitation {
+    pub id: u64,
+    pub login: Option<String>,
+    pub email: Option<String>,
+    /// like `direct_member`, `admin`, `billing_manager`, or `reinstate`
+    pub role: String,
+    pub created_at: DateTime,
+    pub inviter: User,
+    pub team_count: u64,
+    pub invitation_teams_url: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct InvitationOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitee_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    role: Option<InvitationRole>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    team_ids: Vec<u64>,
+}
+
+impl InvitationOptions {
+    /// invite an existing github user by their user id
+    pub fn user(invitee_id: u64) -> InvitationOptionsBuilder {
+        let mut builder = InvitationOptionsBuilder::default();
+        builder.0.invitee_id = Some(invitee_id);
+        builder
+    }
+
+    /// invite someone by email address, whether or not they have a github
+    /// account yet
+    pub fn email<E>(email: E) -> InvitationOptionsBuilder
+    where
+        E: Into<String>,
+    {
+        let mut builder = InvitationOptionsBuilder::default();
+        builder.0.email = Some(email.into());
+        builder
+    }
+}
+
+#[derive(Default)]
+pub struct InvitationOptionsBuilder(InvitationOptions);
+
+impl InvitationOptionsBuilder {
+    /// defaults to `direct_member`
+    pub fn role(&mut self, role: InvitationRole) -> &mut Self {
+        self.0.role = Some(role);
+        self
+    }
+
+    /// teams the user joins when they accept the invitation
+    pub fn team_ids(&mut self, team_ids: Vec<u64>) -> &mut Self {
+        self.0.team_ids = team_ids;
+        self
+    }
+
+    pub fn build(&self) -> InvitationOptions {
+        InvitationOptions {
+            invitee_id: self.0.invitee_id,
+            email: self.0.email.clone(),
+            role: self.0.role,
+            team_ids: self.0.team_ids.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_invitation_options() {
+        let options = InvitationOptions::email("octocat@github.com")
+            .role(InvitationRole::DirectMember)
+            .team_ids(vec![12, 26])
+            .build();
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({
+                "email": "octocat@github.com",
+                "role": "direct_member",
+                "team_ids": [12, 26],
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(InvitationOptions::user(1).build()).unwrap(),
+            serde_json::json!({ "invitee_id": 1 })
+        );
+    }
+
+    #[test]
+    fn member_list_options_serialize() {
+        let options = MemberListOptions::builder()
+            .filter(MemberFilter::TwoFactorDisabled)
+            .build();
+        assert_eq!(options.serialize(), Some("filter=2fa_disabled".into()));
+        assert_eq!(MemberListOptions::default().serialize(), None);
+    }
+}
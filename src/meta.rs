@@ -0,0 +1,109 @@
+//! Meta interface
+//!
+//! Information about github itself, like the ip addresses it serves and
+//! delivers web hooks from, and the emoji it renders.
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/meta/)
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::{Future, Github};
+
+pub struct Meta {
+    github: Github,
+}
+
+impl Meta {
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        Self { github }
+    }
+
+    /// https://developer.github.com/v3/meta/#meta
+    pub fn get(&self) -> Future<GithubMeta> {
+        self.github.get("/meta")
+    }
+}
+
+pub struct Emojis {
+    github: Github,
+}
+
+impl Emojis {
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        Self { github }
+    }
+
+    /// the names of the emoji github renders mapped to the urls of their
+    /// images
+    ///
+    /// https://developer.github.com/v3/emojis/#emojis
+    pub fn list(&self) -> Future<HashMap<String, String>> {
+        self.github.get("/emojis")
+    }
+}
+
+// representations
+
+/// The ip addresses github serves and connects from, as CIDR ranges like
+/// `192.30.252.0/22`. Not every instance lists every service, so missing
+/// lists are empty
+#[derive(Debug, Deserialize)]
+pub struct GithubMeta {
+    #[serde(default)]
+    pub verifiable_password_authentication: bool,
+    /// the fingerprints of github's SSH host keys, keyed by their algorithm
+    /// like `SHA256_ED25519`
+    #[serde(default)]
+    pub ssh_key_fingerprints: HashMap<String, String>,
+    /// github's SSH host public keys
+    #[serde(default)]
+    pub ssh_keys: Vec<String>,
+    /// the addresses web hooks are delivered from
+    #[serde(default)]
+    pub hooks: Vec<String>,
+    #[serde(default)]
+    pub web: Vec<String>,
+    #[serde(default)]
+    pub api: Vec<String>,
+    #[serde(default)]
+    pub git: Vec<String>,
+    #[serde(default)]
+    pub packages: Vec<String>,
+    #[serde(default)]
+    pub pages: Vec<String>,
+    #[serde(default)]
+    pub importer: Vec<String>,
+    #[serde(default)]
+    pub actions: Vec<String>,
+    #[serde(default)]
+    pub dependabot: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_meta() {
+        let meta: GithubMeta = serde_json::from_value(serde_json::json!({
+            "verifiable_password_authentication": true,
+            "ssh_key_fingerprints": {
+                "SHA256_ED25519": "+DiY3wvvV6TuJJhbpZisF/zLDA0zPMSvHdkr4UvCOqU"
+            },
+            "hooks": ["192.30.252.0/22"],
+            "git": ["192.30.252.0/22", "2a0a:a440::/29"]
+        }))
+        .unwrap();
+        assert_eq!(meta.hooks, vec!["192.30.252.0/22"]);
+        assert_eq!(meta.git.len(), 2);
+        assert!(meta.web.is_empty());
+        assert_eq!(
+            meta.ssh_key_fingerprints["SHA256_ED25519"],
+            "+DiY3wvvV6TuJJhbpZisF/zLDA0zPMSvHdkr4UvCOqU"
+        );
+    }
+}
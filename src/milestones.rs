@@ -0,0 +1,281 @@
+//! Milestones interface
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/issues/milestones/)
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
+
+use crate::issues::State;
+use crate::users::User;
+use crate::{DateTime, Future, Github, SortDirection, Stream};
+
+/// Sort options available for milestones
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum MilestoneSort {
+    /// sort by due date
+    #[default]
+    DueOn,
+    /// sort by the share of issues that are closed
+    Completeness,
+}
+
+impl fmt::Display for MilestoneSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            MilestoneSort::DueOn => "due_on",
+            MilestoneSort::Completeness => "completeness",
+        }
+        .fmt(f)
+    }
+}
+
+/// Provides access to the milestones of a repository
+/// Typically accessed via `github.repo(..., ...).milestones()`
+pub struct Milestones {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Milestones {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Milestones {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/milestones{}", self.owner, self.repo, more)
+    }
+
+    fn list_uri(&self, options: &MilestoneListOptions) -> String {
+        let mut uri = vec![self.path("")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        uri.join("?")
+    }
+
+    /// list the milestones of this repository
+    ///
+    /// See the [github docs](https://developer.github.com/v3/issues/milestones/#list-milestones-for-a-repository)
+    /// for more information.
+    pub fn list(&self, options: &MilestoneListOptions) -> Future<Vec<Milestone>> {
+        self.github.get(&self.list_uri(options))
+    }
+
+    /// provides a stream over all pages of this repository's milestones
+    pub fn iter(&self, options: &MilestoneListOptions) -> Stream<Milestone> {
+        self.github.get_stream(&self.list_uri(options))
+    }
+
+    pub fn get(&self, number: u64) -> Future<Milestone> {
+        self.github.get(&self.path(&format!("/{}", number)))
+    }
+
+    /// create a milestone. `MilestoneOptions#title` is required
+    pub fn create(&self, milestone: &MilestoneOptions) -> Future<Milestone> {
+        self.github.post(&self.path(""), json!(milestone))
+    }
+
+    pub fn update(&self, number: u64, milestone: &MilestoneOptions) -> Future<Milestone> {
+        self.github
+            .patch(&self.path(&format!("/{}", number)), json!(milestone))
+    }
+
+    pub fn delete(&self, number: u64) -> Future<()> {
+        self.github.delete(&self.path(&format!("/{}", number)))
+    }
+}
+
+// representations
+
+/// Options used to filter repository milestone listings
+#[derive(Default)]
+pub struct MilestoneListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl MilestoneListOptions {
+    pub fn builder() -> MilestoneListOptionsBuilder {
+        MilestoneListOptionsBuilder::default()
+    }
+
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct MilestoneListOptionsBuilder(MilestoneListOptions);
+
+impl MilestoneListOptionsBuilder {
+    pub fn state(&mut self, state: State) -> &mut Self {
+        self.0.params.insert("state", state.to_string());
+        self
+    }
+
+    pub fn sort(&mut self, sort: MilestoneSort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    pub fn asc(&mut self) -> &mut Self {
+        self.direction(SortDirection::Asc)
+    }
+
+    pub fn desc(&mut self) -> &mut Self {
+        self.direction(SortDirection::Desc)
+    }
+
+    pub fn direction(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("direction", direction.to_string());
+        self
+    }
+
+    pub fn per_page(&mut self, n: u32) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> MilestoneListOptions {
+        MilestoneListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// The state of a milestone
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MilestoneState {
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct MilestoneOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MilestoneState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_on: Option<String>,
+}
+
+impl MilestoneOptions {
+    pub fn builder() -> MilestoneOptionsBuilder {
+        MilestoneOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct MilestoneOptionsBuilder(MilestoneOptions);
+
+impl MilestoneOptionsBuilder {
+    pub fn title<T>(&mut self, title: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.0.title = Some(title.into());
+        self
+    }
+
+    pub fn state(&mut self, state: MilestoneState) -> &mut Self {
+        self.0.state = Some(state);
+        self
+    }
+
+    pub fn description<D>(&mut self, description: D) -> &mut Self
+    where
+        D: Into<String>,
+    {
+        self.0.description = Some(description.into());
+        self
+    }
+
+    /// an ISO 8601 timestamp, like `2012-10-09T23:39:01Z`
+    pub fn due_on<D>(&mut self, due_on: D) -> &mut Self
+    where
+        D: Into<String>,
+    {
+        self.0.due_on = Some(due_on.into());
+        self
+    }
+
+    pub fn build(&self) -> MilestoneOptions {
+        MilestoneOptions {
+            title: self.0.title.clone(),
+            state: self.0.state,
+            description: self.0.description.clone(),
+            due_on: self.0.due_on.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Milestone {
+    pub id: u64,
+    pub number: u64,
+    pub url: String,
+    pub html_url: String,
+    pub labels_url: String,
+    pub state: MilestoneState,
+    pub title: String,
+    pub description: Option<String>,
+    pub creator: Option<User>,
+    pub open_issues: u64,
+    pub closed_issues: u64,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub closed_at: Option<DateTime>,
+    pub due_on: Option<DateTime>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn milestone_list_reqs() {
+        assert_eq!(MilestoneListOptions::builder().build().serialize(), None);
+        assert_eq!(
+            MilestoneListOptions::builder()
+                .sort(MilestoneSort::Completeness)
+                .build()
+                .serialize(),
+            Some("sort=completeness".to_owned())
+        );
+    }
+
+    #[test]
+    fn serialize_milestone_options() {
+        let options = MilestoneOptions::builder()
+            .title("v1.0")
+            .state(MilestoneState::Closed)
+            .build();
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({ "title": "v1.0", "state": "closed" })
+        );
+    }
+}
@@ -1,12 +1,20 @@
 //! Notifications interface
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 
-use url::form_urlencoded;
+use chrono::Utc;
+use futures::{future, stream, Future as StdFuture, Stream as StdStream};
+use http::header::{HeaderMap, HeaderValue, IF_MODIFIED_SINCE, LAST_MODIFIED};
 use serde::Deserialize;
+use tokio_timer::Delay;
+use url::form_urlencoded;
 
+use crate::errors::{Error, ErrorKind};
 use crate::users::User;
-use crate::Future;
-use crate::Github;
+use crate::{
+    next_link, poll_interval, unfold, DateTime, Future, Github, MediaType, Stream,
+    DEFAULT_POLL_INTERVAL,
+};
 
 /// Provides access to notifications.
 /// See the [github docs](https://developer.github.com/v3/activity/notifications/)
@@ -33,6 +41,100 @@ impl Notifications {
         self.github.get(&uri.join("?"))
     }
 
+    /// Provides a stream over all pages of the authenticated user's notifications.
+    pub fn iter(&self, options: &ThreadListOptions) -> Stream<Thread> {
+        let mut uri = vec!["/notifications".into()];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get_stream(&uri.join("?"))
+    }
+
+    /// Poll the authenticated user's notifications, yielding threads as they
+    /// arrive or are updated.
+    ///
+    /// Polls wait for the interval github asks for with the `X-Poll-Interval`
+    /// header and are made conditional with `If-Modified-Since`, so unchanged
+    /// polls do not count against your rate limit. The first poll yields all
+    /// threads matching `options`, following every page. Later polls follow
+    /// pages until they reach threads yielded before. Failed polls, like those
+    /// github answers with server errors, yield their error and polling
+    /// continues after the poll interval, or once an exhausted rate limit
+    /// resets.
+    ///
+    /// The returned stream must be driven by a tokio runtime.
+    ///
+    /// See the [github docs](https://developer.github.com/v3/activity/notifications/)
+    /// for more information.
+    pub fn poll(&self, options: &ThreadListOptions) -> Stream<Thread> {
+        let mut uri = vec!["/notifications".into()];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        let uri = uri.join("?");
+        let github = self.github.clone();
+        Box::new(
+            stream::unfold(PollState::default(), move |mut state| {
+                let github = github.clone();
+                let uri = uri.clone();
+                let wait: Future<()> = match state.retry_in.take().or(state.interval) {
+                    Some(wait) => Box::new(
+                        Delay::new(Instant::now() + wait)
+                            .map_err(|e| ErrorKind::Msg(e.to_string()).into()),
+                    ),
+                    None => Box::new(future::ok(())),
+                };
+                Some(wait.and_then(move |_| {
+                    let mut headers = HeaderMap::new();
+                    if let Some(value) = state.last_modified.clone() {
+                        headers.insert(IF_MODIFIED_SINCE, value);
+                    }
+                    let seen = state.seen;
+                    github
+                        .get_if_modified::<Vec<Thread>>(&uri, headers)
+                        .and_then(move |(headers, link, threads)| {
+                            let threads: Future<Option<Vec<Thread>>> = match threads {
+                                // not modified since the last poll
+                                None => Box::new(future::ok(None)),
+                                Some(threads) => {
+                                    let rest: Stream<Thread> =
+                                        match link.as_ref().and_then(next_link) {
+                                            Some(url) => unfold(
+                                                github.clone(),
+                                                github.get_pages_url(&url),
+                                                |threads| threads,
+                                                MediaType::Json,
+                                            ),
+                                            None => Box::new(stream::empty()),
+                                        };
+                                    // threads are listed most recently updated first, so
+                                    // later pages are only fetched until they reach
+                                    // threads updated before the last poll. threads
+                                    // updated in the same second are deduplicated later
+                                    Box::new(
+                                        stream::iter_ok(threads)
+                                            .chain(rest)
+                                            .take_while(move |thread| {
+                                                Ok(seen
+                                                    .is_none_or(|seen| thread.updated_at >= seen))
+                                            })
+                                            .collect()
+                                            .map(Some),
+                                    )
+                                }
+                            };
+                            threads.map(move |threads| (headers, threads))
+                        })
+                        // failed polls are yielded as errors without ending the stream
+                        .then(move |result| Ok::<_, Error>(state.next(result)))
+                }))
+            })
+            .and_then(|result| result)
+            .map(stream::iter_ok)
+            .flatten(),
+        )
+    }
+
     /// List the authenticated user's notifications for a repository.
     ///
     /// See the [github docs](https://developer.github.com/v3/activity/notifications/#list-your-notifications-in-a-repository)
@@ -184,14 +286,87 @@ impl Notifications {
     }
 }
 
+/// book keeping between notification polls
+#[derive(Default)]
+struct PollState {
+    interval: Option<Duration>,
+    /// how long to wait before retrying a failed poll
+    retry_in: Option<Duration>,
+    last_modified: Option<HeaderValue>,
+    /// when the most recently updated thread yielded was updated
+    seen: Option<DateTime>,
+    /// the ids of the threads yielded which were updated at `seen`
+    seen_ids: HashSet<String>,
+}
+
+impl PollState {
+    fn next(
+        mut self,
+        result: Result<(HeaderMap, Option<Vec<Thread>>), Error>,
+    ) -> (Result<Vec<Thread>, Error>, PollState) {
+        match result {
+            Ok((headers, threads)) => {
+                // github sends the interval with 304 Not Modified responses too
+                self.interval = Some(poll_interval(&headers));
+                match threads {
+                    Some(threads) => {
+                        if let Some(value) = headers.get(LAST_MODIFIED) {
+                            self.last_modified = Some(value.clone());
+                        }
+                        let fresh = self.fresh(threads);
+                        (Ok(fresh), self)
+                    }
+                    None => (Ok(Vec::new()), self),
+                }
+            }
+            Err(err) => {
+                self.retry_in = Some(match err.kind() {
+                    ErrorKind::RateLimit { reset, .. } => (*reset - Utc::now())
+                        .to_std()
+                        .unwrap_or_else(|_| Duration::from_secs(0)),
+                    _ => self.interval.unwrap_or(DEFAULT_POLL_INTERVAL),
+                });
+                (Err(err), self)
+            }
+        }
+    }
+
+    /// retains threads updated since the last poll. timestamps only have a
+    /// precision of seconds, so threads updated in the same second as the
+    /// last thread yielded are retained unless they were yielded already
+    fn fresh(&mut self, threads: Vec<Thread>) -> Vec<Thread> {
+        let fresh: Vec<Thread> = threads
+            .into_iter()
+            .filter(|thread| match self.seen {
+                Some(seen) if thread.updated_at == seen => !self.seen_ids.contains(&thread.id),
+                Some(seen) => thread.updated_at > seen,
+                None => true,
+            })
+            .collect();
+        if let Some(latest) = fresh.iter().map(|thread| thread.updated_at).max() {
+            if self.seen != Some(latest) {
+                self.seen = Some(latest);
+                self.seen_ids.clear();
+            }
+            self.seen_ids.extend(
+                fresh
+                    .iter()
+                    .filter(|thread| thread.updated_at == latest)
+                    .map(|thread| thread.id.clone()),
+            );
+        }
+        fresh
+    }
+}
+
 // representations
 
 #[derive(Debug, Deserialize)]
 pub struct Thread {
     pub id: String,
     pub unread: bool,
-    pub updated_at: String,
-    pub last_read_at: Option<String>,
+    pub updated_at: DateTime,
+    pub last_read_at: Option<DateTime>,
     pub reason: String,
     pub subject: Subject,
     pub repository: Repository,
@@ -266,11 +441,11 @@ impl ThreadListOptionsBuilder {
 
 #[derive(Debug, Deserialize)]
 pub struct Subject {
-    title: String,
-    url: String,
-    latest_comment_url: String,
+    pub title: String,
+    pub url: String,
+    pub latest_comment_url: String,
     #[serde(rename = "type")]
-    kind: String,
+    pub kind: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -288,7 +463,111 @@ pub struct Subscription {
     pub subscribed: bool,
     pub ignored: bool,
     pub reason: String,
-    pub created_at: String,
+    pub created_at: DateTime,
     pub url: String,
     pub thread_url: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn poll_state_not_modified_refreshes_interval() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-poll-interval", HeaderValue::from_static("120"));
+        let (threads, state) = PollState::default().next(Ok((headers, None)));
+        assert!(threads.unwrap().is_empty());
+        assert_eq!(state.interval, Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn poll_state_keeps_polling_after_errors() {
+        let state = PollState {
+            interval: Some(Duration::from_secs(90)),
+            ..PollState::default()
+        };
+        let (threads, state) = state.next(Err(ErrorKind::Msg("timed out".into()).into()));
+        assert!(threads.is_err());
+        assert_eq!(state.retry_in, Some(Duration::from_secs(90)));
+        assert_eq!(state.interval, Some(Duration::from_secs(90)));
+    }
+
+    fn thread(id: &str, updated_at: &str) -> Thread {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "unread": true,
+            "updated_at": updated_at,
+            "last_read_at": null,
+            "reason": "subscribed",
+            "subject": {
+                "title": "Greetings",
+                "url": "",
+                "latest_comment_url": "",
+                "type": "Issue"
+            },
+            "repository": {
+                "id": 1,
+                "node_id": "",
+                "name": "hubcaps",
+                "full_name": "softprops/hubcaps",
+                "owner": {
+                    "login": "softprops",
+                    "id": 1,
+                    "avatar_url": "",
+                    "gravatar_id": "",
+                    "url": "",
+                    "html_url": "",
+                    "followers_url": "",
+                    "following_url": "",
+                    "gists_url": "",
+                    "starred_url": "",
+                    "subscriptions_url": "",
+                    "organizations_url": "",
+                    "repos_url": "",
+                    "events_url": "",
+                    "received_events_url": "",
+                    "type": "User",
+                    "site_admin": false
+                },
+                "html_url": ""
+            },
+            "url": "",
+            "subscription_url": ""
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn poll_state_yields_threads_updated_in_the_same_second_once() {
+        let ids = |threads: Result<Vec<Thread>, Error>| {
+            threads
+                .unwrap()
+                .into_iter()
+                .map(|thread| thread.id)
+                .collect::<Vec<_>>()
+        };
+        let (threads, state) = PollState::default().next(Ok((
+            HeaderMap::new(),
+            Some(vec![thread("1", "2019-01-01T00:00:00Z")]),
+        )));
+        assert_eq!(ids(threads), vec!["1"]);
+        // a second thread updated in the same second shows up on the next poll
+        let (threads, state) = state.next(Ok((
+            HeaderMap::new(),
+            Some(vec![
+                thread("2", "2019-01-01T00:00:00Z"),
+                thread("1", "2019-01-01T00:00:00Z"),
+            ]),
+        )));
+        assert_eq!(ids(threads), vec!["2"]);
+        let (threads, _) = state.next(Ok((
+            HeaderMap::new(),
+            Some(vec![
+                thread("1", "2019-01-01T00:00:01Z"),
+                thread("2", "2019-01-01T00:00:00Z"),
+            ]),
+        )));
+        assert_eq!(ids(threads), vec!["1"]);
+    }
+}
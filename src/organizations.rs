@@ -1,9 +1,22 @@
 //! Organizations interface
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "actions")]
+use crate::actions::OrgActions;
+use crate::custom_properties::OrgCustomProperties;
+#[cfg(feature = "activity")]
+use crate::events::Events;
+#[cfg(feature = "hooks")]
+use crate::hooks::Hooks;
+use crate::members::OrgMembers;
+#[cfg(feature = "packages")]
+use crate::packages::Packages;
+#[cfg(feature = "projects")]
+use crate::projects::Projects;
 use crate::repositories::OrgRepositories;
+use crate::scim::Scim;
 use crate::teams::OrgTeams;
-use crate::{Future, Github};
+use crate::{DateTime, Future, Github, Stream};
 
 /// Provides access to label operations available for an individual organization
 pub struct Organization {
@@ -23,15 +36,81 @@ impl Organization {
         }
     }
 
+    fn path(&self, more: &str) -> String {
+        format!("/orgs/{}{}", self.org, more)
+    }
+
+    /// get this organization's details
+    ///
+    /// See the [github docs](https://developer.github.com/v3/orgs/#get-an-organization)
+    /// for more information.
+    pub fn get(&self) -> Future<OrgDetails> {
+        self.github.get(&self.path(""))
+    }
+
+    /// edit this organization's profile and member settings
+    ///
+    /// See the [github docs](https://developer.github.com/v3/orgs/#edit-an-organization)
+    /// for more information.
+    pub fn edit(&self, options: &OrgOptions) -> Future<OrgDetails> {
+        self.github.patch(&self.path(""), json!(options))
+    }
+
     /// returns a reference to an interface for team operations
     pub fn teams(&self) -> OrgTeams {
         OrgTeams::new(self.github.clone(), self.org.clone())
     }
 
+    /// returns a reference to an interface for member, outside collaborator,
+    /// and invitation operations
+    pub fn members(&self) -> OrgMembers {
+        OrgMembers::new(self.github.clone(), self.org.clone())
+    }
+
     /// returns a reference to an interface for repo operations
     pub fn repos(&self) -> OrgRepositories {
         OrgRepositories::new(self.github.clone(), self.org.clone())
     }
+
+    /// returns a reference to an interface for custom property operations
+    pub fn custom_properties(&self) -> OrgCustomProperties {
+        OrgCustomProperties::new(self.github.clone(), self.org.clone())
+    }
+
+    /// returns a reference to an interface for actions settings
+    #[cfg(feature = "actions")]
+    pub fn actions(&self) -> OrgActions {
+        OrgActions::new(self.github.clone(), self.org.clone())
+    }
+
+    /// returns a reference to the public events of this organization
+    #[cfg(feature = "activity")]
+    pub fn events(&self) -> Events {
+        Events::for_org(self.github.clone(), self.org.clone())
+    }
+
+    /// returns a reference to an interface for organization webhook operations
+    #[cfg(feature = "hooks")]
+    pub fn hooks(&self) -> Hooks {
+        Hooks::for_org(self.github.clone(), self.org.clone())
+    }
+
+    /// returns a reference to an interface for package operations
+    #[cfg(feature = "packages")]
+    pub fn packages(&self) -> Packages {
+        Packages::for_org(self.github.clone(), self.org.clone())
+    }
+
+    /// returns a reference to an interface for project operations
+    #[cfg(feature = "projects")]
+    pub fn projects(&self) -> Projects {
+        Projects::for_org(self.github.clone(), self.org.clone())
+    }
+
+    /// returns a reference to an interface for scim provisioning operations
+    pub fn scim(&self) -> Scim {
+        Scim::new(self.github.clone(), self.org.clone())
+    }
 }
 
 pub struct Organizations {
@@ -53,6 +132,11 @@ impl Organizations {
     pub fn list(&self) -> Future<Vec<Org>> {
         self.github.get(&self.path(""))
     }
+
+    /// provides a stream over all pages of the authenticated user's organizations
+    pub fn iter(&self) -> Stream<Org> {
+        self.github.get_stream(&self.path(""))
+    }
 }
 
 pub struct UserOrganizations {
@@ -80,6 +164,12 @@ impl UserOrganizations {
     pub fn list(&self) -> Future<Vec<Org>> {
         self.github.get(&self.path(""))
     }
+
+    /// provides a stream over all pages of the organizations this user is publicly
+    /// associated with
+    pub fn iter(&self) -> Stream<Org> {
+        self.github.get_stream(&self.path(""))
+    }
 }
 
 // representations
@@ -98,3 +188,208 @@ pub struct Org {
     pub avatar_url: String,
     pub description: Option<String>,
 }
+
+/// An organization's full details
+#[derive(Debug, Deserialize)]
+pub struct OrgDetails {
+    pub login: String,
+    pub id: u64,
+    pub url: String,
+    pub repos_url: String,
+    pub events_url: String,
+    pub hooks_url: String,
+    pub issues_url: String,
+    pub members_url: String,
+    pub public_members_url: String,
+    pub avatar_url: String,
+    pub description: Option<String>,
+    pub name: Option<String>,
+    pub company: Option<String>,
+    pub blog: Option<String>,
+    pub location: Option<String>,
+    pub email: Option<String>,
+    pub twitter_username: Option<String>,
+    pub is_verified: Option<bool>,
+    pub has_organization_projects: bool,
+    pub has_repository_projects: bool,
+    pub public_repos: u64,
+    pub public_gists: u64,
+    pub followers: u64,
+    pub following: u64,
+    pub html_url: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    /// only visible to members of the organization
+    pub total_private_repos: Option<u64>,
+    /// only visible to members of the organization
+    pub owned_private_repos: Option<u64>,
+    /// only visible to owners of the organization
+    pub billing_email: Option<String>,
+    /// only visible to owners of the organization
+    pub default_repository_permission: Option<DefaultRepositoryPermission>,
+    /// only visible to owners of the organization
+    pub members_can_create_repositories: Option<bool>,
+    /// only visible to owners of the organization
+    pub two_factor_requirement_enabled: Option<bool>,
+}
+
+/// The permission organization members have on the organization's repositories
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DefaultRepositoryPermission {
+    Read,
+    Write,
+    Admin,
+    None,
+}
+
+/// Options for editing an organization
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct OrgOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blog: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub twitter_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_organization_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_repository_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_repository_permission: Option<DefaultRepositoryPermission>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members_can_create_repositories: Option<bool>,
+}
+
+impl OrgOptions {
+    pub fn builder() -> OrgOptionsBuilder {
+        OrgOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct OrgOptionsBuilder(OrgOptions);
+
+impl OrgOptionsBuilder {
+    /// the organization's display name
+    pub fn name<N>(&mut self, name: N) -> &mut Self
+    where
+        N: Into<String>,
+    {
+        self.0.name = Some(name.into());
+        self
+    }
+
+    pub fn description<D>(&mut self, description: D) -> &mut Self
+    where
+        D: Into<String>,
+    {
+        self.0.description = Some(description.into());
+        self
+    }
+
+    pub fn company<C>(&mut self, company: C) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        self.0.company = Some(company.into());
+        self
+    }
+
+    pub fn blog<B>(&mut self, blog: B) -> &mut Self
+    where
+        B: Into<String>,
+    {
+        self.0.blog = Some(blog.into());
+        self
+    }
+
+    pub fn location<L>(&mut self, location: L) -> &mut Self
+    where
+        L: Into<String>,
+    {
+        self.0.location = Some(location.into());
+        self
+    }
+
+    /// the organization's publicly visible email address
+    pub fn email<E>(&mut self, email: E) -> &mut Self
+    where
+        E: Into<String>,
+    {
+        self.0.email = Some(email.into());
+        self
+    }
+
+    pub fn twitter_username<T>(&mut self, twitter_username: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.0.twitter_username = Some(twitter_username.into());
+        self
+    }
+
+    pub fn billing_email<E>(&mut self, billing_email: E) -> &mut Self
+    where
+        E: Into<String>,
+    {
+        self.0.billing_email = Some(billing_email.into());
+        self
+    }
+
+    pub fn has_organization_projects(&mut self, has_organization_projects: bool) -> &mut Self {
+        self.0.has_organization_projects = Some(has_organization_projects);
+        self
+    }
+
+    pub fn has_repository_projects(&mut self, has_repository_projects: bool) -> &mut Self {
+        self.0.has_repository_projects = Some(has_repository_projects);
+        self
+    }
+
+    /// the permission members have on the organization's repositories
+    pub fn default_repository_permission(
+        &mut self,
+        permission: DefaultRepositoryPermission,
+    ) -> &mut Self {
+        self.0.default_repository_permission = Some(permission);
+        self
+    }
+
+    pub fn members_can_create_repositories(&mut self, can_create: bool) -> &mut Self {
+        self.0.members_can_create_repositories = Some(can_create);
+        self
+    }
+
+    pub fn build(&self) -> OrgOptions {
+        self.0.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_org_options() {
+        let options = OrgOptions::builder()
+            .description("the octo org")
+            .default_repository_permission(DefaultRepositoryPermission::None)
+            .build();
+        assert_eq!(
+            serde_json::to_string(&options).unwrap(),
+            r#"{"description":"the octo org","default_repository_permission":"none"}"#
+        );
+    }
+}
@@ -0,0 +1,551 @@
+//! Packages interface
+//!
+//! See the [github docs](https://developer.github.com/v3/packages/) for more information
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt;
+
+use futures::{stream, Future as StdFuture, Stream as StdStream};
+use serde::Deserialize;
+use url::form_urlencoded;
+
+use crate::users::User;
+use crate::{DateTime, Future, Github, Stream};
+
+/// Describes the ecosystem of a package
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageType {
+    Npm,
+    Maven,
+    Rubygems,
+    Docker,
+    Nuget,
+    Container,
+}
+
+impl fmt::Display for PackageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PackageType::Npm => "npm",
+            PackageType::Maven => "maven",
+            PackageType::Rubygems => "rubygems",
+            PackageType::Docker => "docker",
+            PackageType::Nuget => "nuget",
+            PackageType::Container => "container",
+        }
+        .fmt(f)
+    }
+}
+
+/// Describes the visibility of a package
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageVisibility {
+    Public,
+    Private,
+    Internal,
+}
+
+impl fmt::Display for PackageVisibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PackageVisibility::Public => "public",
+            PackageVisibility::Private => "private",
+            PackageVisibility::Internal => "internal",
+        }
+        .fmt(f)
+    }
+}
+
+/// Describes whether listed package versions are active or deleted
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PackageVersionState {
+    Active,
+    Deleted,
+}
+
+impl fmt::Display for PackageVersionState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PackageVersionState::Active => "active",
+            PackageVersionState::Deleted => "deleted",
+        }
+        .fmt(f)
+    }
+}
+
+/// Provides access to the packages of the authenticated user, another user, or
+/// an organization
+pub struct Packages {
+    github: Github,
+    owner: String,
+}
+
+impl Packages {
+    /// packages of the authenticated user
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        Packages {
+            github,
+            owner: "/user".into(),
+        }
+    }
+
+    /// packages of another user
+    #[doc(hidden)]
+    pub fn for_user<U>(github: Github, user: U) -> Self
+    where
+        U: Into<String>,
+    {
+        Packages {
+            github,
+            owner: format!("/users/{}", user.into()),
+        }
+    }
+
+    /// packages of an organization
+    #[doc(hidden)]
+    pub fn for_org<O>(github: Github, org: O) -> Self
+    where
+        O: Into<String>,
+    {
+        Packages {
+            github,
+            owner: format!("/orgs/{}", org.into()),
+        }
+    }
+
+    /// list packages of an ecosystem
+    ///
+    /// See the [github docs](https://developer.github.com/v3/packages/#list-packages-for-the-authenticated-users-namespace)
+    /// for more information.
+    pub fn list(&self, options: &PackageListOptions) -> Future<Vec<Package>> {
+        self.github.get(&self.list_uri(options))
+    }
+
+    /// provides a stream over all pages of packages of an ecosystem
+    pub fn iter(&self, options: &PackageListOptions) -> Stream<Package> {
+        self.github.get_stream(&self.list_uri(options))
+    }
+
+    fn list_uri(&self, options: &PackageListOptions) -> String {
+        format!("{}/packages?{}", self.owner, options.serialize())
+    }
+
+    /// returns a reference to an interface for operations on a single package
+    pub fn get<N>(&self, package_type: PackageType, name: N) -> PackageRef
+    where
+        N: Into<String>,
+    {
+        PackageRef {
+            github: self.github.clone(),
+            path: format!(
+                "{}/packages/{}/{}",
+                self.owner,
+                package_type,
+                form_urlencoded::byte_serialize(name.into().as_bytes()).collect::<String>()
+            ),
+        }
+    }
+}
+
+/// Provides access to a single package and its versions
+pub struct PackageRef {
+    github: Github,
+    path: String,
+}
+
+impl PackageRef {
+    fn path(&self, more: &str) -> String {
+        format!("{}{}", self.path, more)
+    }
+
+    /// get the package information
+    pub fn get(&self) -> Future<Package> {
+        self.github.get(&self.path(""))
+    }
+
+    /// delete the package. packages with more than 5000 downloads can not be
+    /// deleted
+    pub fn delete(&self) -> Future<()> {
+        self.github.delete(&self.path(""))
+    }
+
+    /// restore a package deleted within the last 30 days
+    pub fn restore(&self) -> Future<()> {
+        self.github
+            .post_no_response(&self.path("/restore"), Vec::new())
+    }
+
+    /// list the versions of the package
+    pub fn versions(&self, options: &PackageVersionListOptions) -> Future<Vec<PackageVersion>> {
+        self.github.get(&self.versions_uri(options))
+    }
+
+    /// provides a stream over all pages of versions of the package
+    pub fn iter_versions(&self, options: &PackageVersionListOptions) -> Stream<PackageVersion> {
+        self.github.get_stream(&self.versions_uri(options))
+    }
+
+    fn versions_uri(&self, options: &PackageVersionListOptions) -> String {
+        let mut uri = vec![self.path("/versions")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        uri.join("?")
+    }
+
+    pub fn version(&self, id: u64) -> Future<PackageVersion> {
+        self.github.get(&self.path(&format!("/versions/{}", id)))
+    }
+
+    /// delete a version of the package. the last version of a public package
+    /// with more than 5000 downloads can not be deleted
+    pub fn delete_version(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.path(&format!("/versions/{}", id)))
+    }
+
+    /// restore a version of the package deleted within the last 30 days
+    pub fn restore_version(&self, id: u64) -> Future<()> {
+        self.github
+            .post_no_response(&self.path(&format!("/versions/{}/restore", id)), Vec::new())
+    }
+
+    /// delete the versions of the package selected by a retention policy,
+    /// resolving the versions deleted. with `dry_run` enabled, resolves the
+    /// versions that would be deleted without deleting them
+    pub fn prune(&self, policy: &RetentionPolicy) -> Future<Vec<PackageVersion>> {
+        let github = self.github.clone();
+        let path = self.path.clone();
+        let policy = policy.clone();
+        let options = PackageVersionListOptions::builder()
+            .state(PackageVersionState::Active)
+            .per_page(100)
+            .build();
+        Box::new(
+            self.iter_versions(&options)
+                .collect()
+                .and_then(move |versions| {
+                    let selected = policy.select(versions);
+                    let deletions: Future<Vec<PackageVersion>> = if policy.dry_run {
+                        Box::new(futures::future::ok(selected))
+                    } else {
+                        // deleted one at a time to stay clear of secondary rate limits
+                        Box::new(
+                            stream::iter_ok(selected)
+                                .and_then(move |version| {
+                                    github
+                                        .delete(&format!("{}/versions/{}", path, version.id))
+                                        .map(move |_| version)
+                                })
+                                .collect(),
+                        )
+                    };
+                    deletions
+                }),
+        )
+    }
+}
+
+/// Selects the versions of a package to delete, typically old or untagged
+/// container images
+///
+/// Versions are selected when they match every configured rule. The newest
+/// `keep_latest` versions are never selected. `keep_latest` only protects
+/// versions, so a policy selects nothing unless `older_than` or `untagged`
+/// is configured too, and an unconfigured policy never deletes
+#[derive(Debug, Clone, Default)]
+pub struct RetentionPolicy {
+    older_than: Option<DateTime>,
+    untagged: bool,
+    keep_latest: usize,
+    dry_run: bool,
+}
+
+impl RetentionPolicy {
+    pub fn builder() -> RetentionPolicyBuilder {
+        RetentionPolicyBuilder::default()
+    }
+
+    /// selects the versions this policy would delete, newest first
+    pub fn select(&self, mut versions: Vec<PackageVersion>) -> Vec<PackageVersion> {
+        if self.older_than.is_none() && !self.untagged {
+            return Vec::new();
+        }
+        versions.sort_by_key(|version| Reverse(version.created_at));
+        versions
+            .into_iter()
+            .skip(self.keep_latest)
+            .filter(|version| match self.older_than {
+                Some(cutoff) => version.created_at < cutoff,
+                None => true,
+            })
+            .filter(|version| !self.untagged || version.tags().is_empty())
+            .collect()
+    }
+}
+
+/// a mutable retention policy builder
+#[derive(Default)]
+pub struct RetentionPolicyBuilder(RetentionPolicy);
+
+impl RetentionPolicyBuilder {
+    /// only select versions created before this time
+    pub fn older_than(&mut self, cutoff: DateTime) -> &mut Self {
+        self.0.older_than = Some(cutoff);
+        self
+    }
+
+    /// only select versions without tags
+    pub fn untagged(&mut self, untagged: bool) -> &mut Self {
+        self.0.untagged = untagged;
+        self
+    }
+
+    /// never select the newest `n` versions. on its own this selects
+    /// nothing; combine it with `older_than` or `untagged`
+    pub fn keep_latest(&mut self, n: usize) -> &mut Self {
+        self.0.keep_latest = n;
+        self
+    }
+
+    /// report the versions that would be deleted without deleting them
+    pub fn dry_run(&mut self, dry_run: bool) -> &mut Self {
+        self.0.dry_run = dry_run;
+        self
+    }
+
+    pub fn build(&self) -> RetentionPolicy {
+        self.0.clone()
+    }
+}
+
+// representations
+
+#[derive(Debug, Deserialize)]
+pub struct Package {
+    pub id: u64,
+    pub name: String,
+    pub package_type: PackageType,
+    pub owner: Option<User>,
+    pub version_count: u64,
+    pub visibility: PackageVisibility,
+    pub url: String,
+    pub html_url: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PackageVersion {
+    pub id: u64,
+    pub name: String,
+    pub url: String,
+    pub package_html_url: String,
+    pub html_url: Option<String>,
+    pub license: Option<String>,
+    pub description: Option<String>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub deleted_at: Option<DateTime>,
+    pub metadata: Option<PackageVersionMetadata>,
+}
+
+impl PackageVersion {
+    /// the tags of a container or docker version
+    pub fn tags(&self) -> Vec<&str> {
+        match self.metadata {
+            Some(PackageVersionMetadata {
+                container: Some(ref container),
+                ..
+            }) => container.tags.iter().map(String::as_str).collect(),
+            Some(PackageVersionMetadata {
+                docker: Some(ref docker),
+                ..
+            }) => docker.tag.iter().map(String::as_str).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PackageVersionMetadata {
+    pub package_type: PackageType,
+    /// tags of container versions
+    pub container: Option<ContainerMetadata>,
+    /// tags of docker versions
+    pub docker: Option<DockerMetadata>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ContainerMetadata {
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DockerMetadata {
+    pub tag: Vec<String>,
+}
+
+/// Options used to filter package listings
+///
+/// See the [github docs](https://developer.github.com/v3/packages/#list-packages-for-the-authenticated-users-namespace)
+/// for more information
+pub struct PackageListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl PackageListOptions {
+    /// packages must be listed one ecosystem at a time
+    pub fn builder(package_type: PackageType) -> PackageListOptionsBuilder {
+        PackageListOptionsBuilder::new(package_type)
+    }
+
+    pub fn serialize(&self) -> String {
+        form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(&self.params)
+            .finish()
+    }
+}
+
+/// a mutable package list builder
+pub struct PackageListOptionsBuilder(PackageListOptions);
+
+impl PackageListOptionsBuilder {
+    pub(crate) fn new(package_type: PackageType) -> Self {
+        let mut params = HashMap::new();
+        params.insert("package_type", package_type.to_string());
+        PackageListOptionsBuilder(PackageListOptions { params })
+    }
+
+    pub fn visibility(&mut self, visibility: PackageVisibility) -> &mut Self {
+        self.0.params.insert("visibility", visibility.to_string());
+        self
+    }
+
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> PackageListOptions {
+        PackageListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// Options used to filter package version listings
+#[derive(Default)]
+pub struct PackageVersionListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl PackageVersionListOptions {
+    pub fn builder() -> PackageVersionListOptionsBuilder {
+        PackageVersionListOptionsBuilder::default()
+    }
+
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+/// a mutable package version list builder
+#[derive(Default)]
+pub struct PackageVersionListOptionsBuilder(PackageVersionListOptions);
+
+impl PackageVersionListOptionsBuilder {
+    pub fn state(&mut self, state: PackageVersionState) -> &mut Self {
+        self.0.params.insert("state", state.to_string());
+        self
+    }
+
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> PackageVersionListOptions {
+        PackageVersionListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(id: u64, created_at: &str, tags: &[&str]) -> PackageVersion {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": format!("sha256:{}", id),
+            "url": "https://api.github.com/user/packages/container/app/versions",
+            "package_html_url": "https://github.com/users/octocat/packages/container/package/app",
+            "created_at": created_at,
+            "updated_at": created_at,
+            "metadata": {
+                "package_type": "container",
+                "container": { "tags": tags }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn retention_policy_select() {
+        let versions = || {
+            vec![
+                version(1, "2019-01-01T00:00:00Z", &[]),
+                version(2, "2019-06-01T00:00:00Z", &["v1"]),
+                version(3, "2020-01-01T00:00:00Z", &[]),
+                version(4, "2020-06-01T00:00:00Z", &["latest"]),
+            ]
+        };
+        let ids = |policy: &RetentionPolicy| {
+            policy
+                .select(versions())
+                .iter()
+                .map(|v| v.id)
+                .collect::<Vec<_>>()
+        };
+        assert!(ids(&RetentionPolicy::default()).is_empty());
+        assert!(ids(&RetentionPolicy::builder().build()).is_empty());
+        // keeping the newest versions doesn't select the rest, tagged ones
+        // included
+        assert!(ids(&RetentionPolicy::builder().keep_latest(1).build()).is_empty());
+        assert_eq!(
+            ids(&RetentionPolicy::builder().untagged(true).build()),
+            vec![3, 1]
+        );
+        assert_eq!(
+            ids(&RetentionPolicy::builder()
+                .older_than("2020-01-01T00:00:00Z".parse().unwrap())
+                .build()),
+            vec![2, 1]
+        );
+        assert_eq!(
+            ids(&RetentionPolicy::builder()
+                .untagged(true)
+                .keep_latest(2)
+                .build()),
+            vec![1]
+        );
+        assert_eq!(
+            ids(&RetentionPolicy::builder()
+                .older_than("2020-06-01T00:00:00Z".parse().unwrap())
+                .keep_latest(2)
+                .build()),
+            vec![2, 1]
+        );
+    }
+}
@@ -0,0 +1,665 @@
+//! Projects (classic) interface
+//!
+//! Project boards of repositories and organizations, their columns, and the
+//! cards in those columns. All project endpoints require the `inertia`
+//! preview media type, which hubcaps sends on your behalf.
+//!
+//! See the [github docs](https://developer.github.com/v3/projects/) for more
+//! information.
+use std::collections::HashMap;
+use std::fmt;
+
+use futures::Future as StdFuture;
+use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
+
+use crate::users::User;
+use crate::{AuthenticationConstraint, DateTime, Error, ErrorKind, Future, Github, MediaType};
+
+/// project endpoints are only available with this preview
+const PROJECTS_PREVIEW: MediaType = MediaType::Preview("inertia");
+
+/// Provides access to the projects of a repository or an organization
+pub struct Projects {
+    github: Github,
+    owner: String,
+}
+
+impl Projects {
+    /// projects of a repository
+    #[doc(hidden)]
+    pub fn for_repo<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Projects {
+            github,
+            owner: format!("/repos/{}/{}", owner.into(), repo.into()),
+        }
+    }
+
+    /// projects of an organization
+    #[doc(hidden)]
+    pub fn for_org<O>(github: Github, org: O) -> Self
+    where
+        O: Into<String>,
+    {
+        Projects {
+            github,
+            owner: format!("/orgs/{}", org.into()),
+        }
+    }
+
+    /// list projects
+    ///
+    /// See the [github docs](https://developer.github.com/v3/projects/#list-repository-projects)
+    /// for more information.
+    pub fn list(&self, options: &ProjectListOptions) -> Future<Vec<Project>> {
+        let mut uri = vec![format!("{}/projects", self.owner)];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get_media(&uri.join("?"), PROJECTS_PREVIEW)
+    }
+
+    /// create a project. `ProjectOptions#name` is required
+    ///
+    /// See the [github docs](https://developer.github.com/v3/projects/#create-a-repository-project)
+    /// for more information.
+    pub fn create(&self, project: &ProjectOptions) -> Future<Project> {
+        self.github.post_media(
+            &format!("{}/projects", self.owner),
+            json!(project),
+            PROJECTS_PREVIEW,
+            AuthenticationConstraint::Unconstrained,
+        )
+    }
+
+    /// returns a reference to an interface for operations on a single project
+    pub fn get(&self, id: u64) -> ProjectRef {
+        ProjectRef {
+            github: self.github.clone(),
+            id,
+        }
+    }
+
+    /// returns a reference to an interface for operations on a single column
+    /// of any project
+    pub fn column(&self, id: u64) -> ColumnRef {
+        ColumnRef {
+            github: self.github.clone(),
+            id,
+        }
+    }
+
+    /// returns a reference to an interface for operations on a single card
+    /// of any project
+    pub fn card(&self, id: u64) -> CardRef {
+        CardRef {
+            github: self.github.clone(),
+            id,
+        }
+    }
+}
+
+/// Provides access to a single project and its columns
+pub struct ProjectRef {
+    github: Github,
+    id: u64,
+}
+
+impl ProjectRef {
+    fn path(&self, more: &str) -> String {
+        format!("/projects/{}{}", self.id, more)
+    }
+
+    pub fn get(&self) -> Future<Project> {
+        self.github.get_media(&self.path(""), PROJECTS_PREVIEW)
+    }
+
+    pub fn edit(&self, project: &ProjectOptions) -> Future<Project> {
+        self.github
+            .patch_media(&self.path(""), json!(project), PROJECTS_PREVIEW)
+    }
+
+    pub fn delete(&self) -> Future<()> {
+        self.github.delete_media(&self.path(""), PROJECTS_PREVIEW)
+    }
+
+    /// list the columns of this project
+    pub fn columns(&self) -> Future<Vec<ProjectColumn>> {
+        self.github
+            .get_media(&self.path("/columns"), PROJECTS_PREVIEW)
+    }
+
+    /// add a column to this project
+    pub fn create_column<N>(&self, name: N) -> Future<ProjectColumn>
+    where
+        N: Into<String>,
+    {
+        self.github.post_media(
+            &self.path("/columns"),
+            json_lit!({ "name": name.into() }),
+            PROJECTS_PREVIEW,
+            AuthenticationConstraint::Unconstrained,
+        )
+    }
+}
+
+/// Provides access to a single project column and its cards
+pub struct ColumnRef {
+    github: Github,
+    id: u64,
+}
+
+impl ColumnRef {
+    fn path(&self, more: &str) -> String {
+        format!("/projects/columns/{}{}", self.id, more)
+    }
+
+    pub fn get(&self) -> Future<ProjectColumn> {
+        self.github.get_media(&self.path(""), PROJECTS_PREVIEW)
+    }
+
+    pub fn rename<N>(&self, name: N) -> Future<ProjectColumn>
+    where
+        N: Into<String>,
+    {
+        self.github.patch_media(
+            &self.path(""),
+            json_lit!({ "name": name.into() }),
+            PROJECTS_PREVIEW,
+        )
+    }
+
+    pub fn delete(&self) -> Future<()> {
+        self.github.delete_media(&self.path(""), PROJECTS_PREVIEW)
+    }
+
+    /// move this column within its project
+    ///
+    /// See the [github docs](https://developer.github.com/v3/projects/columns/#move-a-project-column)
+    /// for more information.
+    pub fn move_to(&self, position: ColumnPosition) -> Future<()> {
+        post_move(
+            &self.github,
+            &self.path("/moves"),
+            json_lit!({ "position": position.to_string() }),
+        )
+    }
+
+    /// list the cards of this column
+    pub fn cards(&self, options: &CardListOptions) -> Future<Vec<ProjectCard>> {
+        let mut uri = vec![self.path("/cards")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get_media(&uri.join("?"), PROJECTS_PREVIEW)
+    }
+
+    /// add a note card, or a card for an issue or pull request, to the top of
+    /// this column
+    ///
+    /// See the [github docs](https://developer.github.com/v3/projects/cards/#create-a-project-card)
+    /// for more information.
+    pub fn create_card(&self, card: &CardOptions) -> Future<ProjectCard> {
+        self.github.post_media(
+            &self.path("/cards"),
+            json!(card),
+            PROJECTS_PREVIEW,
+            AuthenticationConstraint::Unconstrained,
+        )
+    }
+}
+
+/// Provides access to a single project card
+pub struct CardRef {
+    github: Github,
+    id: u64,
+}
+
+impl CardRef {
+    fn path(&self, more: &str) -> String {
+        format!("/projects/columns/cards/{}{}", self.id, more)
+    }
+
+    pub fn get(&self) -> Future<ProjectCard> {
+        self.github.get_media(&self.path(""), PROJECTS_PREVIEW)
+    }
+
+    /// edit a card's note, or archive or restore it
+    pub fn edit(&self, card: &CardEditOptions) -> Future<ProjectCard> {
+        self.github
+            .patch_media(&self.path(""), json!(card), PROJECTS_PREVIEW)
+    }
+
+    pub fn delete(&self) -> Future<()> {
+        self.github.delete_media(&self.path(""), PROJECTS_PREVIEW)
+    }
+
+    /// move this card within its column, or to another column of the same
+    /// project when `column_id` is given
+    ///
+    /// See the [github docs](https://developer.github.com/v3/projects/cards/#move-a-project-card)
+    /// for more information.
+    pub fn move_to(&self, position: CardPosition, column_id: Option<u64>) -> Future<()> {
+        let uri = self.path("/moves");
+        match column_id {
+            Some(column_id) => post_move(
+                &self.github,
+                &uri,
+                json_lit!({ "position": position.to_string(), "column_id": column_id }),
+            ),
+            None => post_move(
+                &self.github,
+                &uri,
+                json_lit!({ "position": position.to_string() }),
+            ),
+        }
+    }
+}
+
+/// moves respond with an empty object
+fn post_move(github: &Github, uri: &str, message: Vec<u8>) -> Future<()> {
+    Box::new(
+        github
+            .post_media::<()>(
+                uri,
+                message,
+                PROJECTS_PREVIEW,
+                AuthenticationConstraint::Unconstrained,
+            )
+            .or_else(|err| match err {
+                Error(ErrorKind::Codec(_), _) => Ok(()),
+                otherwise => Err(otherwise),
+            }),
+    )
+}
+
+// representations
+
+/// Where to move a column within its project
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColumnPosition {
+    First,
+    Last,
+    /// after the column with this id
+    After(u64),
+}
+
+impl fmt::Display for ColumnPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ColumnPosition::First => write!(f, "first"),
+            ColumnPosition::Last => write!(f, "last"),
+            ColumnPosition::After(id) => write!(f, "after:{}", id),
+        }
+    }
+}
+
+/// Where to move a card within its column
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CardPosition {
+    Top,
+    Bottom,
+    /// after the card with this id
+    After(u64),
+}
+
+impl fmt::Display for CardPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CardPosition::Top => write!(f, "top"),
+            CardPosition::Bottom => write!(f, "bottom"),
+            CardPosition::After(id) => write!(f, "after:{}", id),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectState {
+    Open,
+    Closed,
+}
+
+impl fmt::Display for ProjectState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ProjectState::Open => "open",
+            ProjectState::Closed => "closed",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Project {
+    pub id: u64,
+    pub node_id: String,
+    pub number: u64,
+    pub name: String,
+    pub body: Option<String>,
+    pub state: ProjectState,
+    pub url: String,
+    pub html_url: String,
+    pub owner_url: String,
+    pub columns_url: String,
+    pub creator: User,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProjectColumn {
+    pub id: u64,
+    pub node_id: String,
+    pub name: String,
+    pub url: String,
+    pub project_url: String,
+    pub cards_url: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProjectCard {
+    pub id: u64,
+    pub node_id: String,
+    /// the text of a note card, `None` for issue and pull request cards
+    pub note: Option<String>,
+    pub archived: bool,
+    pub creator: Option<User>,
+    pub url: String,
+    pub column_url: String,
+    pub project_url: String,
+    /// the issue or pull request this card represents
+    pub content_url: Option<String>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ProjectOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<ProjectState>,
+}
+
+impl ProjectOptions {
+    pub fn builder() -> ProjectOptionsBuilder {
+        ProjectOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct ProjectOptionsBuilder(ProjectOptions);
+
+impl ProjectOptionsBuilder {
+    pub fn name<N>(&mut self, name: N) -> &mut Self
+    where
+        N: Into<String>,
+    {
+        self.0.name = Some(name.into());
+        self
+    }
+
+    pub fn body<B>(&mut self, body: B) -> &mut Self
+    where
+        B: Into<String>,
+    {
+        self.0.body = Some(body.into());
+        self
+    }
+
+    /// only applies when editing a project
+    pub fn state(&mut self, state: ProjectState) -> &mut Self {
+        self.0.state = Some(state);
+        self
+    }
+
+    pub fn build(&self) -> ProjectOptions {
+        ProjectOptions {
+            name: self.0.name.clone(),
+            body: self.0.body.clone(),
+            state: self.0.state,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ProjectListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl ProjectListOptions {
+    pub fn builder() -> ProjectListOptionsBuilder {
+        ProjectListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ProjectListOptionsBuilder(ProjectListOptions);
+
+impl ProjectListOptionsBuilder {
+    /// only list projects in this state. defaults to open projects
+    pub fn state(&mut self, state: ProjectState) -> &mut Self {
+        self.0.params.insert("state", state.to_string());
+        self
+    }
+
+    /// list both open and closed projects
+    pub fn all_states(&mut self) -> &mut Self {
+        self.0.params.insert("state", "all".into());
+        self
+    }
+
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> ProjectListOptions {
+        ProjectListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// Filters cards by whether they are archived
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArchivedState {
+    All,
+    Archived,
+    NotArchived,
+}
+
+impl fmt::Display for ArchivedState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ArchivedState::All => "all",
+            ArchivedState::Archived => "archived",
+            ArchivedState::NotArchived => "not_archived",
+        }
+        .fmt(f)
+    }
+}
+
+#[derive(Default)]
+pub struct CardListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl CardListOptions {
+    pub fn builder() -> CardListOptionsBuilder {
+        CardListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct CardListOptionsBuilder(CardListOptions);
+
+impl CardListOptionsBuilder {
+    /// defaults to `NotArchived`
+    pub fn archived_state(&mut self, state: ArchivedState) -> &mut Self {
+        self.0.params.insert("archived_state", state.to_string());
+        self
+    }
+
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> CardListOptions {
+        CardListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// The kind of content a card represents
+#[derive(Clone, Copy, Debug, Serialize, PartialEq)]
+pub enum CardContentType {
+    Issue,
+    PullRequest,
+}
+
+/// A new card, either a note or a reference to an issue or pull request
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum CardOptions {
+    Note {
+        note: String,
+    },
+    Content {
+        /// the id, not the number, of the issue or pull request
+        content_id: u64,
+        content_type: CardContentType,
+    },
+}
+
+impl CardOptions {
+    pub fn note<N>(note: N) -> Self
+    where
+        N: Into<String>,
+    {
+        CardOptions::Note { note: note.into() }
+    }
+
+    pub fn issue(id: u64) -> Self {
+        CardOptions::Content {
+            content_id: id,
+            content_type: CardContentType::Issue,
+        }
+    }
+
+    pub fn pull_request(id: u64) -> Self {
+        CardOptions::Content {
+            content_id: id,
+            content_type: CardContentType::PullRequest,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct CardEditOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+}
+
+impl CardEditOptions {
+    pub fn builder() -> CardEditOptionsBuilder {
+        CardEditOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct CardEditOptionsBuilder(CardEditOptions);
+
+impl CardEditOptionsBuilder {
+    pub fn note<N>(&mut self, note: N) -> &mut Self
+    where
+        N: Into<String>,
+    {
+        self.0.note = Some(note.into());
+        self
+    }
+
+    pub fn archived(&mut self, archived: bool) -> &mut Self {
+        self.0.archived = Some(archived);
+        self
+    }
+
+    pub fn build(&self) -> CardEditOptions {
+        CardEditOptions {
+            note: self.0.note.clone(),
+            archived: self.0.archived,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_display() {
+        assert_eq!(ColumnPosition::First.to_string(), "first");
+        assert_eq!(ColumnPosition::After(42).to_string(), "after:42");
+        assert_eq!(CardPosition::Bottom.to_string(), "bottom");
+        assert_eq!(CardPosition::After(7).to_string(), "after:7");
+    }
+
+    #[test]
+    fn serialize_card_options() {
+        assert_eq!(
+            serde_json::to_value(CardOptions::note("ship it")).unwrap(),
+            serde_json::json!({ "note": "ship it" })
+        );
+        assert_eq!(
+            serde_json::to_value(CardOptions::pull_request(1347)).unwrap(),
+            serde_json::json!({ "content_id": 1347, "content_type": "PullRequest" })
+        );
+    }
+}
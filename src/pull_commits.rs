@@ -2,7 +2,7 @@
 use serde::Deserialize;
 
 use crate::users::User;
-use crate::{Future, Github, Stream};
+use crate::{DateTime, Future, Github, Stream};
 
 /// A structure for interfacing with a pull commits
 pub struct PullCommits {
@@ -55,8 +55,10 @@ pub struct PullCommit {
     pub html_url: String,
     pub comments_url: String,
     pub commit: CommitDetails,
-    pub author: User,
-    pub committer: User,
+    /// `None` when the commit's author email isn't linked to a github account
+    pub author: Option<User>,
+    /// `None` when the commit's committer email isn't linked to a github account
+    pub committer: Option<User>,
     pub parents: Vec<CommitRef>,
 }
 
@@ -83,5 +85,5 @@ pub struct CommitRef {
 pub struct UserStamp {
     pub name: String,
     pub email: String,
-    pub date: String,
+    pub date: DateTime,
 }
@@ -2,22 +2,35 @@
 use std::collections::HashMap;
 use std::fmt;
 
+use futures::Future as StdFuture;
+use http::StatusCode;
 use url::form_urlencoded;
 use serde::{Deserialize, Serialize};
 
-use crate::comments::Comments;
-use crate::issues::{IssueAssignees, IssueLabels, Sort as IssueSort, State};
+use crate::comments::{Comment, Comments};
+use crate::issues::{IssueAssignees, IssueLabels, State};
 use crate::labels::Label;
-use crate::pull_commits::PullCommits;
-use crate::review_comments::ReviewComments;
+use crate::pull_commits::{PullCommit, PullCommits};
+use crate::review_comments::{ReviewComment, ReviewComments};
+use crate::repositories::Repo;
 use crate::review_requests::ReviewRequests;
+use crate::reviews::Reviews;
 use crate::users::User;
-use crate::{Future, Github, SortDirection, Stream};
+use crate::{DateTime, Error, ErrorKind, Future, Github, SortDirection, Stream};
+
+/// The state of a pull request. Merged pull requests are closed
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PullState {
+    Open,
+    Closed,
+}
 
 /// Sort directions for pull requests
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub enum Sort {
     /// Sort by time created
+    #[default]
     Created,
     /// Sort by last updated
     Updated,
@@ -39,12 +52,6 @@ impl fmt::Display for Sort {
     }
 }
 
-impl Default for Sort {
-    fn default() -> Sort {
-        Sort::Created
-    }
-}
-
 /// A structure for accessing interfacing with a specific pull request
 pub struct PullRequest {
     github: Github,
@@ -102,12 +109,12 @@ impl PullRequest {
 
     /// short hand for editing state = open
     pub fn open(&self) -> Future<Pull> {
-        self.edit(&PullEditOptions::builder().state("open").build())
+        self.edit(&PullEditOptions::builder().state(PullState::Open).build())
     }
 
     /// shorthand for editing state = closed
     pub fn close(&self) -> Future<Pull> {
-        self.edit(&PullEditOptions::builder().state("closed").build())
+        self.edit(&PullEditOptions::builder().state(PullState::Closed).build())
     }
 
     /// Edit a pull request
@@ -115,11 +122,70 @@ impl PullRequest {
         self.github.patch::<Pull>(&self.path(""), json!(pr))
     }
 
+    /// Merge this pull request. Failures github reports for the pull request
+    /// itself rather than the request, a pull that isn't mergeable or whose
+    /// head no longer matches `MergeOptionsBuilder#sha`, resolve to a
+    /// `MergeResult` instead of an error
+    ///
+    /// See the [github docs](https://developer.github.com/v3/pulls/#merge-a-pull-request-merge-button)
+    /// for more information.
+    pub fn merge(&self, options: &MergeOptions) -> Future<MergeResult> {
+        Box::new(
+            self.github
+                .put::<Merge>(&self.path("/merge"), json!(options))
+                .map(MergeResult::Merged)
+                .or_else(|err| match err {
+                    Error(
+                        ErrorKind::Fault {
+                            code: StatusCode::METHOD_NOT_ALLOWED,
+                            error,
+                            ..
+                        },
+                        _,
+                    ) => Ok(MergeResult::NotMergeable(error.message)),
+                    Error(
+                        ErrorKind::Fault {
+                            code: StatusCode::CONFLICT,
+                            error,
+                            ..
+                        },
+                        _,
+                    ) => Ok(MergeResult::HeadChanged(error.message)),
+                    otherwise => Err(otherwise),
+                }),
+        )
+    }
+
+    /// check whether this pull request has been merged
+    pub fn is_merged(&self) -> Future<bool> {
+        Box::new(
+            self.github
+                .get::<()>(&self.path("/merge"))
+                .map(|_| true)
+                .or_else(|err| match err {
+                    Error(
+                        ErrorKind::Fault {
+                            code: StatusCode::NOT_FOUND,
+                            ..
+                        },
+                        _,
+                    ) => Ok(false),
+                    Error(ErrorKind::Codec(_), _) => Ok(true),
+                    otherwise => Err(otherwise),
+                }),
+        )
+    }
+
     /// Returns a vector of file diffs associated with this pull
     pub fn files(&self) -> Future<Vec<FileDiff>> {
         self.github.get(&self.path("/files"))
     }
 
+    /// Provides a stream over all pages of file diffs associated with this pull
+    pub fn iter_files(&self) -> Stream<FileDiff> {
+        self.github.get_stream(&self.path("/files"))
+    }
+
     /// returns issue comments interface
     pub fn comments(&self) -> Comments {
         Comments::new(
@@ -140,6 +206,16 @@ impl PullRequest {
         )
     }
 
+    /// returns reviews interface
+    pub fn reviews(&self) -> Reviews {
+        Reviews::new(
+            self.github.clone(),
+            self.owner.clone(),
+            self.repo.clone(),
+            self.number,
+        )
+    }
+
     pub fn review_requests(&self) -> ReviewRequests {
         ReviewRequests::new(
             self.github.clone(),
@@ -236,13 +312,13 @@ pub struct Pull {
     pub comments_url: String,
     pub statuses_url: String,
     pub number: u64,
-    pub state: String,
+    pub state: PullState,
     pub title: String,
     pub body: Option<String>,
-    pub created_at: String,
-    pub updated_at: String,
-    pub closed_at: Option<String>,
-    pub merged_at: Option<String>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub closed_at: Option<DateTime>,
+    pub merged_at: Option<DateTime>,
     pub head: Commit,
     pub base: Commit,
     // links
@@ -261,13 +337,33 @@ pub struct Pull {
     pub labels: Vec<Label>,
 }
 
+impl Pull {
+    /// List the commits of this pull request by following its `commits_url`.
+    pub fn commits(&self, github: &Github) -> Future<Vec<PullCommit>> {
+        github.get_url(&self.commits_url)
+    }
+
+    /// List the issue comments on this pull request by following its `comments_url`.
+    pub fn comments(&self, github: &Github) -> Future<Vec<Comment>> {
+        github.get_url(&self.comments_url)
+    }
+
+    /// List the review comments on this pull request by following its
+    /// `review_comments_url`.
+    pub fn review_comments(&self, github: &Github) -> Future<Vec<ReviewComment>> {
+        github.get_url(&self.review_comments_url)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Commit {
     pub label: String,
     #[serde(rename = "ref")]
     pub commit_ref: String,
     pub sha: String,
-    pub user: User, //    pub repo: Option<Repo>,
+    pub user: User,
+    /// `None` when the repository has since been deleted
+    pub repo: Option<Repo>,
 }
 
 #[derive(Default)]
@@ -293,11 +389,8 @@ impl PullEditOptionsBuilder {
     }
 
     /// set the state of the pull
-    pub fn state<S>(&mut self, state: S) -> &mut Self
-    where
-        S: Into<String>,
-    {
-        self.0.state = Some(state.into());
+    pub fn state(&mut self, state: PullState) -> &mut Self {
+        self.0.state = Some(state);
         self
     }
 
@@ -306,7 +399,7 @@ impl PullEditOptionsBuilder {
         PullEditOptions {
             title: self.0.title.clone(),
             body: self.0.body.clone(),
-            state: self.0.state.clone(),
+            state: self.0.state,
         }
     }
 }
@@ -318,21 +411,19 @@ pub struct PullEditOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     body: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    state: Option<String>,
+    state: Option<PullState>,
 }
 
 impl PullEditOptions {
-    // todo represent state as enum
-    pub fn new<T, B, S>(title: Option<T>, body: Option<B>, state: Option<S>) -> PullEditOptions
+    pub fn new<T, B>(title: Option<T>, body: Option<B>, state: Option<PullState>) -> PullEditOptions
     where
         T: Into<String>,
         B: Into<String>,
-        S: Into<String>,
     {
         PullEditOptions {
             title: title.map(|t| t.into()),
             body: body.map(|b| b.into()),
-            state: state.map(|s| s.into()),
+            state,
         }
     }
     pub fn builder() -> PullEditOptionsBuilder {
@@ -340,6 +431,104 @@ impl PullEditOptions {
     }
 }
 
+/// The way a pull request's commits are merged into its base branch
+#[derive(Clone, Copy, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeMethod {
+    /// a merge commit
+    Merge,
+    /// a single commit of all of the pull request's changes
+    Squash,
+    /// the pull request's commits, rebased onto the base branch
+    Rebase,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct MergeOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sha: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merge_method: Option<MergeMethod>,
+}
+
+impl MergeOptions {
+    pub fn builder() -> MergeOptionsBuilder {
+        MergeOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct MergeOptionsBuilder(MergeOptions);
+
+impl MergeOptionsBuilder {
+    /// the title of the merge commit. defaults to the pull request's title
+    pub fn commit_title<T>(&mut self, commit_title: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.0.commit_title = Some(commit_title.into());
+        self
+    }
+
+    /// extra detail appended to the merge commit's message
+    pub fn commit_message<M>(&mut self, commit_message: M) -> &mut Self
+    where
+        M: Into<String>,
+    {
+        self.0.commit_message = Some(commit_message.into());
+        self
+    }
+
+    /// only merge if the pull request's head is still this commit
+    pub fn sha<S>(&mut self, sha: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.0.sha = Some(sha.into());
+        self
+    }
+
+    /// defaults to `MergeMethod::Merge`
+    pub fn merge_method(&mut self, merge_method: MergeMethod) -> &mut Self {
+        self.0.merge_method = Some(merge_method);
+        self
+    }
+
+    pub fn build(&self) -> MergeOptions {
+        MergeOptions {
+            commit_title: self.0.commit_title.clone(),
+            commit_message: self.0.commit_message.clone(),
+            sha: self.0.sha.clone(),
+            merge_method: self.0.merge_method,
+        }
+    }
+}
+
+/// A successful merge
+#[derive(Debug, Deserialize)]
+pub struct Merge {
+    /// the sha of the merge commit
+    pub sha: String,
+    pub merged: bool,
+    pub message: String,
+}
+
+/// The outcome of merging a pull request
+#[derive(Debug)]
+pub enum MergeResult {
+    Merged(Merge),
+    /// github refused to merge the pull request, with its reason. the pull
+    /// request may have conflicts or fail required status checks
+    NotMergeable(String),
+    /// the pull request's head no longer matches the `sha` it was to be
+    /// merged at, with github's message
+    HeadChanged(String),
+}
+
 #[derive(Debug, Serialize)]
 pub struct PullOptions {
     pub title: String,
@@ -366,12 +555,28 @@ impl PullOptions {
     }
 }
 
+/// How a pull request changed a file
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FileStatus {
+    Added,
+    Removed,
+    Modified,
+    Renamed,
+    Copied,
+    /// the file's mode changed
+    Changed,
+    Unchanged,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct FileDiff {
     /// sha from GitHub may be null when file mode changed without contents changing
     pub sha: Option<String>,
     pub filename: String,
-    pub status: String,
+    /// the file's name before it was renamed
+    pub previous_filename: Option<String>,
+    pub status: FileStatus,
     pub additions: u64,
     pub deletions: u64,
     pub changes: u64,
@@ -414,7 +619,26 @@ impl PullListOptionsBuilder {
         self
     }
 
-    pub fn sort(&mut self, sort: IssueSort) -> &mut Self {
+    /// only list pull requests from this head, in the form `user:ref-name`
+    /// or `organization:ref-name`
+    pub fn head<H>(&mut self, head: H) -> &mut Self
+    where
+        H: Into<String>,
+    {
+        self.0.params.insert("head", head.into());
+        self
+    }
+
+    /// only list pull requests targeting this base branch
+    pub fn base<B>(&mut self, base: B) -> &mut Self
+    where
+        B: Into<String>,
+    {
+        self.0.params.insert("base", base.into());
+        self
+    }
+
+    pub fn sort(&mut self, sort: Sort) -> &mut Self {
         self.0.params.insert("sort", sort.to_string());
         self
     }
@@ -424,6 +648,16 @@ impl PullListOptionsBuilder {
         self
     }
 
+    pub fn per_page(&mut self, n: u32) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn page(&mut self, n: u32) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
     pub fn build(&self) -> PullListOptions {
         PullListOptions {
             params: self.0.params.clone(),
@@ -437,20 +671,16 @@ mod tests {
     use serde::ser::Serialize;
     use serde_json;
     fn test_encoding<E: Serialize>(tests: Vec<(E, &str)>) {
-        for test in tests {
-            match test {
-                (k, v) => assert_eq!(serde_json::to_string(&k).unwrap(), v),
-            }
+        for (k, v) in tests {
+            assert_eq!(serde_json::to_string(&k).unwrap(), v);
         }
     }
 
     #[test]
     fn pull_list_reqs() {
         fn test_serialize(tests: Vec<(PullListOptions, Option<String>)>) {
-            for test in tests {
-                match test {
-                    (k, v) => assert_eq!(k.serialize(), v),
-                }
+            for (k, v) in tests {
+                assert_eq!(k.serialize(), v);
             }
         }
         let tests = vec![
@@ -459,6 +689,16 @@ mod tests {
                 PullListOptions::builder().state(State::Closed).build(),
                 Some("state=closed".to_owned()),
             ),
+            (
+                PullListOptions::builder().base("main").build(),
+                Some("base=main".to_owned()),
+            ),
+            (
+                PullListOptions::builder()
+                    .sort(Sort::LongRunning)
+                    .build(),
+                Some("sort=long-running".to_owned()),
+            ),
         ];
         test_serialize(tests)
     }
@@ -478,13 +718,50 @@ mod tests {
                 r#"{"title":"test","body":"desc"}"#,
             ),
             (
-                PullEditOptions::builder().state("closed").build(),
+                PullEditOptions::builder().state(PullState::Closed).build(),
                 r#"{"state":"closed"}"#,
             ),
         ];
         test_encoding(tests)
     }
 
+    #[test]
+    fn merge_options() {
+        let tests = vec![
+            (MergeOptions::builder().build(), "{}"),
+            (
+                MergeOptions::builder()
+                    .sha("6dcb09b")
+                    .merge_method(MergeMethod::Squash)
+                    .build(),
+                r#"{"sha":"6dcb09b","merge_method":"squash"}"#,
+            ),
+        ];
+        test_encoding(tests)
+    }
+
+    #[test]
+    fn deserialize_renamed_file_diff() {
+        let diff: FileDiff = serde_json::from_str(
+            r#"{
+                "sha": "bbcd538c8e72b8c175046e27cc8f907076331401",
+                "filename": "src/new.rs",
+                "previous_filename": "src/old.rs",
+                "status": "renamed",
+                "additions": 0,
+                "deletions": 0,
+                "changes": 0,
+                "blob_url": "https://github.com/octocat/Hello-World/blob/6dcb09b/src/new.rs",
+                "raw_url": "https://github.com/octocat/Hello-World/raw/6dcb09b/src/new.rs",
+                "contents_url": "https://api.github.com/repos/octocat/Hello-World/contents/src/new.rs?ref=6dcb09b"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(diff.status, FileStatus::Renamed);
+        assert_eq!(diff.previous_filename, Some("src/old.rs".into()));
+        assert_eq!(diff.patch, None);
+    }
+
     #[test]
     fn default_sort() {
         let default: Sort = Default::default();
@@ -1,7 +1,14 @@
 //! Rate Limit interface
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
+use http::header::HeaderMap;
 use serde::Deserialize;
 
-use crate::{Future, Github};
+use crate::{
+    DateTime, Future, Github, X_RATELIMIT_LIMIT, X_RATELIMIT_REMAINING, X_RATELIMIT_RESET,
+    X_RATELIMIT_USED,
+};
 
 pub struct RateLimit {
     github: Github,
@@ -24,6 +31,8 @@ impl RateLimit {
 #[derive(Debug, Deserialize)]
 pub struct RateLimitStatus {
     pub resources: RateLimitResourcesStatus,
+    /// the status of the core resource, kept for backwards compatibility
+    pub rate: Option<RateLimitResourceStatus>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -31,11 +40,89 @@ pub struct RateLimitResourcesStatus {
     pub core: RateLimitResourceStatus,
     pub search: RateLimitResourceStatus,
     pub graphql: RateLimitResourceStatus,
+    pub integration_manifest: Option<RateLimitResourceStatus>,
+    pub source_import: Option<RateLimitResourceStatus>,
+    pub code_scanning_upload: Option<RateLimitResourceStatus>,
+    pub actions_runner_registration: Option<RateLimitResourceStatus>,
+    pub scim: Option<RateLimitResourceStatus>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct RateLimitResourceStatus {
     pub limit: u32,
     pub remaining: u32,
-    pub reset: u32, // ideally something like std::time::Duration
+    /// the time at which the limit resets
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub reset: DateTime,
+    #[serde(default)]
+    pub used: u32,
+}
+
+impl RateLimitResourceStatus {
+    /// read the status from a response's `x-ratelimit` headers
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u32>().ok())
+        };
+        let reset = headers
+            .get(X_RATELIMIT_RESET)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<i64>().ok())
+            .and_then(|secs| Utc.timestamp_opt(secs, 0).single())?;
+        Some(Self {
+            limit: header(X_RATELIMIT_LIMIT)?,
+            remaining: header(X_RATELIMIT_REMAINING)?,
+            reset,
+            used: header(X_RATELIMIT_USED).unwrap_or_default(),
+        })
+    }
+
+    /// how long until the limit resets. zero once the reset time has passed
+    pub fn reset_in(&self) -> Duration {
+        (self.reset - Utc::now())
+            .to_std()
+            .unwrap_or_else(|_| Duration::from_secs(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_rate_limit_status() {
+        let status: RateLimitStatus = serde_json::from_str(
+            r#"{
+                "resources": {
+                    "core": {"limit": 5000, "remaining": 4999, "reset": 1372700873, "used": 1},
+                    "search": {"limit": 30, "remaining": 18, "reset": 1372697452, "used": 12},
+                    "graphql": {"limit": 5000, "remaining": 4993, "reset": 1372700389, "used": 7}
+                },
+                "rate": {"limit": 5000, "remaining": 4999, "reset": 1372700873, "used": 1}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(status.resources.search.used, 12);
+        assert_eq!(status.resources.core.reset.timestamp(), 1_372_700_873);
+        assert_eq!(status.resources.core.reset_in(), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn resource_status_from_headers() {
+        let mut headers = HeaderMap::new();
+        assert!(RateLimitResourceStatus::from_headers(&headers).is_none());
+
+        headers.insert(X_RATELIMIT_LIMIT, "5000".parse().unwrap());
+        headers.insert(X_RATELIMIT_REMAINING, "4942".parse().unwrap());
+        headers.insert(X_RATELIMIT_RESET, "1372700873".parse().unwrap());
+        headers.insert(X_RATELIMIT_USED, "58".parse().unwrap());
+        let status = RateLimitResourceStatus::from_headers(&headers).unwrap();
+        assert_eq!(status.limit, 5000);
+        assert_eq!(status.remaining, 4942);
+        assert_eq!(status.reset.timestamp(), 1_372_700_873);
+        assert_eq!(status.used, 58);
+    }
 }
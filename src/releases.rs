@@ -1,8 +1,14 @@
 //! Releases interface
+use futures::Future as StdFuture;
+use mime::Mime;
 use serde::{Deserialize, Serialize};
 
+use crate::uri_template;
 use crate::users::User;
-use crate::{Future, Github};
+use crate::{DateTime, Future, Github, Progress, Stream};
+
+/// the media type asset urls answer with the asset's content for
+const OCTET_STREAM: &str = "application/octet-stream";
 
 /// Provides access to assets for a release.
 /// See the [github docs](https://developer.github.com/v3/repos/releases/)
@@ -29,9 +35,6 @@ impl Assets {
         }
     }
 
-    // todo: upload asset
-    // todo: edit asset
-
     fn path(&self, more: &str) -> String {
         format!(
             "/repos/{}/{}/releases/{}/assets{}",
@@ -39,14 +42,25 @@ impl Assets {
         )
     }
 
-    // todo: stream interface to download
+    /// individual assets are addressed by id, independent of their release
+    fn asset_path(&self, id: u64) -> String {
+        format!("/repos/{}/{}/releases/assets/{}", self.owner, self.repo, id)
+    }
 
     /// Get the asset information.
     ///
     /// See the [github docs](https://developer.github.com/v3/repos/releases/#get-a-single-release-asset)
     /// for more information.
     pub fn get(&self, id: u64) -> Future<Asset> {
-        self.github.get(&self.path(&format!("/{}", id)))
+        self.github.get(&self.asset_path(id))
+    }
+
+    /// Edit the name or label of an asset.
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/releases/#edit-a-release-asset)
+    /// for more information.
+    pub fn edit(&self, id: u64, options: &AssetOptions) -> Future<Asset> {
+        self.github.patch(&self.asset_path(id), json!(options))
     }
 
     /// Delete an asset by id.
@@ -54,7 +68,15 @@ impl Assets {
     /// See the [github docs](https://developer.github.com/v3/repos/releases/#delete-a-release-asset)
     /// for more information.
     pub fn delete(&self, id: u64) -> Future<()> {
-        self.github.delete(&self.path(&format!("/{}", id)))
+        self.github.delete(&self.asset_path(id))
+    }
+
+    /// Download the content of an asset as a stream of chunks, following the
+    /// redirect github answers with. Unlike the `browser_download_url`, this
+    /// works for assets of private repositories and draft releases.
+    pub fn download(&self, id: u64) -> Stream<Vec<u8>> {
+        let url = self.github.host.clone() + &self.asset_path(id);
+        self.github.get_bytes_url(&url, Some(OCTET_STREAM))
     }
 
     /// List assets for a release.
@@ -64,6 +86,65 @@ impl Assets {
     pub fn list(&self) -> Future<Vec<Asset>> {
         self.github.get(&self.path(""))
     }
+
+    /// Provides a stream over all pages of assets for a release.
+    pub fn iter(&self) -> Stream<Asset> {
+        self.github.get_stream(&self.path(""))
+    }
+
+    /// Upload an asset to the release, following the release's `upload_url`.
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/releases/#upload-a-release-asset)
+    /// for more information.
+    pub fn upload<N>(&self, name: N, content_type: Mime, data: Vec<u8>) -> Future<Asset>
+    where
+        N: Into<String>,
+    {
+        self.upload_asset(name, content_type, data, None)
+    }
+
+    /// Upload an asset to the release, reporting the bytes uploaded so far and
+    /// the total size to `progress` as the upload proceeds.
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/releases/#upload-a-release-asset)
+    /// for more information.
+    pub fn upload_with_progress<N, P>(
+        &self,
+        name: N,
+        content_type: Mime,
+        data: Vec<u8>,
+        progress: P,
+    ) -> Future<Asset>
+    where
+        N: Into<String>,
+        P: FnMut(u64, u64) + Send + 'static,
+    {
+        self.upload_asset(name, content_type, data, Some(Box::new(progress)))
+    }
+
+    fn upload_asset<N>(
+        &self,
+        name: N,
+        content_type: Mime,
+        data: Vec<u8>,
+        progress: Option<Progress>,
+    ) -> Future<Asset>
+    where
+        N: Into<String>,
+    {
+        let github = self.github.clone();
+        let name = name.into();
+        Box::new(
+            self.github
+                .get::<Release>(&format!(
+                    "/repos/{}/{}/releases/{}",
+                    self.owner, self.repo, self.releaseid
+                ))
+                .and_then(move |release| {
+                    release.upload_asset(&github, name, content_type, data, progress)
+                }),
+        )
+    }
 }
 
 pub struct ReleaseRef
@@ -106,6 +187,19 @@ impl ReleaseRef {
         self.github.get::<Release>(&self.path(""))
     }
 
+    /// Edit the release.
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/releases/#edit-a-release)
+    /// for more information.
+    pub fn edit(&self, rel: &ReleaseOptions) -> Future<Release> {
+        self.github.patch(&self.path(""), json!(rel))
+    }
+
+    /// Delete the release.
+    pub fn delete(&self) -> Future<()> {
+        self.github.delete(&self.path(""))
+    }
+
     /// Get a reference to asset operations for a release.
     pub fn assets(&self) -> Assets {
         Assets::new(
@@ -180,6 +274,12 @@ impl Releases {
         self.github.get(&self.path(""))
     }
 
+    /// Provides a stream over all pages of published releases and draft releases
+    /// for users with push access.
+    pub fn iter(&self) -> Stream<Release> {
+        self.github.get_stream(&self.path(""))
+    }
+
     /// Return the latest full release. Draft releases and prereleases are not returned.
     ///
     /// See the [github docs](https://developer.github.com/v3/repos/releases/#get-the-latest-release)
@@ -213,6 +313,15 @@ impl Releases {
 
 // representations (todo: replace with derive_builder)
 
+/// The state of a release asset
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum AssetState {
+    /// still being uploaded
+    Open,
+    Uploaded,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Asset {
     pub url: String,
@@ -220,15 +329,68 @@ pub struct Asset {
     pub id: u64,
     pub name: String,
     pub label: Option<String>,
-    pub state: String,
+    pub state: AssetState,
     pub content_type: String,
     pub size: u64,
     pub download_count: u64,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
     pub uploader: User,
 }
 
+impl Asset {
+    /// Download the content of this asset as a stream of chunks.
+    pub fn download(&self, github: &Github) -> Stream<Vec<u8>> {
+        github.get_bytes_url(&self.url, Some(OCTET_STREAM))
+    }
+}
+
+/// Options for editing an asset
+#[derive(Debug, Default, Serialize)]
+pub struct AssetOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+}
+
+impl AssetOptions {
+    pub fn builder() -> AssetOptionsBuilder {
+        AssetOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct AssetOptionsBuilder(AssetOptions);
+
+impl AssetOptionsBuilder {
+    /// the file name of the asset
+    pub fn name<N>(&mut self, name: N) -> &mut Self
+    where
+        N: Into<String>,
+    {
+        self.0.name = Some(name.into());
+        self
+    }
+
+    /// an alternate short description of the asset, shown in place of its
+    /// file name
+    pub fn label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: Into<String>,
+    {
+        self.0.label = Some(label.into());
+        self
+    }
+
+    pub fn build(&self) -> AssetOptions {
+        AssetOptions {
+            name: self.0.name.clone(),
+            label: self.0.label.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Release {
     pub url: String,
@@ -240,16 +402,73 @@ pub struct Release {
     pub id: u64,
     pub tag_name: String,
     pub target_commitish: String,
-    pub name: String,
-    pub body: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
     pub draft: bool,
     pub prerelease: bool,
-    pub created_at: String,
-    pub published_at: String,
+    pub created_at: DateTime,
+    pub published_at: Option<DateTime>,
     pub author: User,
     pub assets: Vec<Asset>,
 }
 
+impl Release {
+    /// List the assets of this release by following its `assets_url`.
+    pub fn assets(&self, github: &Github) -> Future<Vec<Asset>> {
+        github.get_url(&self.assets_url)
+    }
+
+    /// Upload an asset to this release by following its `upload_url`.
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/releases/#upload-a-release-asset)
+    /// for more information.
+    pub fn upload<N>(
+        &self,
+        github: &Github,
+        name: N,
+        content_type: Mime,
+        data: Vec<u8>,
+    ) -> Future<Asset>
+    where
+        N: Into<String>,
+    {
+        self.upload_asset(github, name, content_type, data, None)
+    }
+
+    /// Upload an asset to this release by following its `upload_url`, reporting
+    /// the bytes uploaded so far and the total size to `progress` as the upload
+    /// proceeds.
+    pub fn upload_with_progress<N, P>(
+        &self,
+        github: &Github,
+        name: N,
+        content_type: Mime,
+        data: Vec<u8>,
+        progress: P,
+    ) -> Future<Asset>
+    where
+        N: Into<String>,
+        P: FnMut(u64, u64) + Send + 'static,
+    {
+        self.upload_asset(github, name, content_type, data, Some(Box::new(progress)))
+    }
+
+    fn upload_asset<N>(
+        &self,
+        github: &Github,
+        name: N,
+        content_type: Mime,
+        data: Vec<u8>,
+        progress: Option<Progress>,
+    ) -> Future<Asset>
+    where
+        N: Into<String>,
+    {
+        let url = uri_template::expand(&self.upload_url, &[("name", &name.into())]);
+        github.post_upload(&url, &content_type, data, progress)
+    }
+}
+
 #[derive(Debug, Default, Serialize)]
 pub struct ReleaseOptions {
     pub tag_name: String,
@@ -2,26 +2,69 @@
 use std::collections::HashMap;
 use std::fmt;
 
-use url::{form_urlencoded, Url};
+use futures::Future as StdFuture;
+use url::form_urlencoded;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "actions")]
+use crate::actions::RepoActions;
+#[cfg(feature = "issues")]
+use crate::assignees::Assignees;
+use crate::autolinks::Autolinks;
 use crate::branches::Branches;
+#[cfg(feature = "checks")]
 use crate::checks::CheckRuns;
+use crate::collaborators::Collaborators;
+use crate::commits::Commits;
 use crate::content::Content;
+#[cfg(feature = "orgs")]
+use crate::custom_properties::RepoCustomProperties;
+#[cfg(feature = "dependabot")]
+use crate::dependabot::Dependabot;
+use crate::dependency_graph::DependencyGraph;
+#[cfg(feature = "deployments")]
 use crate::deployments::Deployments;
-use crate::git::Git;
+#[cfg(feature = "discussions")]
+use crate::discussions::DiscussionRef;
+#[cfg(feature = "deployments")]
+use crate::environments::{EnvironmentRef, Environments};
+#[cfg(feature = "activity")]
+use crate::events::Events;
+use crate::forks::Forks;
+use crate::git::{FileChange, Git, GitCommit};
+#[cfg(feature = "hooks")]
 use crate::hooks::Hooks;
+#[cfg(feature = "issues")]
+use crate::issue_templates::IssueTemplates;
+#[cfg(feature = "issues")]
 use crate::issues::{IssueRef, Issues};
 use crate::keys::Keys;
 use crate::labels::Labels;
+#[cfg(feature = "issues")]
+use crate::milestones::Milestones;
+#[cfg(feature = "projects")]
+use crate::projects::Projects;
+#[cfg(feature = "pulls")]
 use crate::pulls::PullRequests;
+#[cfg(feature = "releases")]
 use crate::releases::Releases;
+use crate::rulesets::Rulesets;
+use crate::stats::RepoStats;
 use crate::statuses::Statuses;
+use crate::tag_protection::TagProtection;
 use crate::teams::RepoTeams;
+#[cfg(feature = "traffic")]
 use crate::traffic::Traffic;
-use crate::users::Contributors;
+use crate::users::{Contributors, Subscribers};
 use crate::users::User;
-use crate::{Future, Github, SortDirection, Stream};
+#[cfg(feature = "activity")]
+use crate::watching::RepoSubscription;
+use crate::{
+    AuthenticationConstraint, DateTime, Future, Github, MediaType, SortDirection, Stream,
+};
+
+/// repository topics are available through the mercy preview
+const TOPICS_PREVIEW: MediaType = MediaType::Preview("mercy");
 
 /// describes repository visibilities
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -42,6 +85,34 @@ impl fmt::Display for Visibility {
     }
 }
 
+/// Describes the formats repository archives are available in
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArchiveFormat {
+    /// a gzipped tar archive
+    Tarball,
+    Zipball,
+}
+
+impl fmt::Display for ArchiveFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ArchiveFormat::Tarball => "tarball",
+            ArchiveFormat::Zipball => "zipball",
+        }
+        .fmt(f)
+    }
+}
+
+/// Describes who can see a repository
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepoVisibility {
+    Public,
+    Private,
+    /// visible to members of the enterprise owning the repository
+    Internal,
+}
+
 /// Describes sorting options for repositories
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Sort {
@@ -130,6 +201,55 @@ impl fmt::Display for OrgRepoType {
     }
 }
 
+/// Describes the kinds of repository activity
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityType {
+    Push,
+    ForcePush,
+    BranchCreation,
+    BranchDeletion,
+    PrMerge,
+    MergeQueueMerge,
+}
+
+impl fmt::Display for ActivityType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ActivityType::Push => "push",
+            ActivityType::ForcePush => "force_push",
+            ActivityType::BranchCreation => "branch_creation",
+            ActivityType::BranchDeletion => "branch_deletion",
+            ActivityType::PrMerge => "pr_merge",
+            ActivityType::MergeQueueMerge => "merge_queue_merge",
+        }
+        .fmt(f)
+    }
+}
+
+/// Describes the time periods repository activity may be filtered by
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TimePeriod {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl fmt::Display for TimePeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TimePeriod::Day => "day",
+            TimePeriod::Week => "week",
+            TimePeriod::Month => "month",
+            TimePeriod::Quarter => "quarter",
+            TimePeriod::Year => "year",
+        }
+        .fmt(f)
+    }
+}
+
 #[derive(Clone)]
 pub struct Repositories {
     github: Github,
@@ -151,6 +271,30 @@ impl Repositories {
         self.github.post(&self.path(""), json!(repo))
     }
 
+    /// Create a new repository from the contents of a template repository
+    /// https://developer.github.com/v3/repos/#create-repository-using-a-repository-template
+    pub fn create_from_template<O, R>(
+        &self,
+        template_owner: O,
+        template_repo: R,
+        repo: &TemplateRepoOptions,
+    ) -> Future<Repo>
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        self.github.post_media(
+            &format!(
+                "/repos/{}/{}/generate",
+                template_owner.into(),
+                template_repo.into()
+            ),
+            json!(repo),
+            MediaType::Preview("baptiste"),
+            AuthenticationConstraint::Unconstrained,
+        )
+    }
+
     /// list the authenticated users repositories
     /// https://developer.github.com/v3/repos/#list-your-repositories
     pub fn list(&self, options: &RepoListOptions) -> Future<Vec<Repo>> {
@@ -340,37 +484,251 @@ impl Repository {
     }
 
     /// https://developer.github.com/v3/repos/#delete-a-repository
+    ///
+    /// Fails with `ErrorKind::MissingScope` when github reports the credentials
+    /// lack the `delete_repo` scope. Other refusals, like deleting a repository
+    /// without admin rights, fail with `ErrorKind::Fault`
     pub fn delete(&self) -> Future<()> {
         self.github.delete(&self.path(""))
     }
 
+    /// archive this repository, making it read only, or unarchive a
+    /// previously archived repository
+    /// https://developer.github.com/v3/repos/#update-a-repository
+    pub fn set_archived(&self, archived: bool) -> Future<Repo> {
+        self.github
+            .patch(&self.path(""), json_lit!({ "archived": archived }))
+    }
+
+    /// download an archive of the repository at `git_ref`, a branch, tag, or
+    /// sha, as a stream of chunks of the archive so that large repositories
+    /// don't need to be buffered in memory
+    /// https://developer.github.com/v3/repos/contents/#get-archive-link
+    pub fn archive(&self, format: ArchiveFormat, git_ref: &str) -> Stream<Vec<u8>> {
+        self.github
+            .get_bytes(&self.path(&format!("/{}/{}", format, git_ref)))
+    }
+
+    /// get the languages this repository is implemented in, mapped to the
+    /// number of bytes of code written in each
+    /// https://developer.github.com/v3/repos/#list-languages
+    pub fn languages(&self) -> Future<Languages> {
+        self.github.get(&self.path("/languages"))
+    }
+
+    /// list the topics of this repository
+    /// https://developer.github.com/v3/repos/#get-all-repository-topics
+    pub fn topics(&self) -> Future<Vec<String>> {
+        Box::new(
+            self.github
+                .get_media::<Topics>(&self.path("/topics"), TOPICS_PREVIEW)
+                .map(|topics| topics.names),
+        )
+    }
+
+    /// replace all of the topics of this repository. topics are lowercase
+    /// and may contain letters, numbers, and hyphens. an empty list removes
+    /// every topic
+    /// https://developer.github.com/v3/repos/#replace-all-repository-topics
+    pub fn replace_topics<T>(&self, topics: Vec<T>) -> Future<Vec<String>>
+    where
+        T: Into<String>,
+    {
+        let names = topics.into_iter().map(|t| t.into()).collect::<Vec<_>>();
+        Box::new(
+            self.github
+                .put_media::<Topics>(
+                    &self.path("/topics"),
+                    json_lit!({ "names": names }),
+                    TOPICS_PREVIEW,
+                )
+                .map(|topics| topics.names),
+        )
+    }
+
+    /// list the pushes, force pushes, branch creations and deletions, and merges of
+    /// this repository along with the actors responsible for them
+    /// https://docs.github.com/en/rest/repos/repos#list-repository-activities
+    pub fn activity(&self, options: &RepoActivityListOptions) -> Future<Vec<RepoActivity>> {
+        let mut uri = vec![self.path("/activity")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get(&uri.join("?"))
+    }
+
+    /// provides a stream over all pages of this repository's activity
+    /// https://docs.github.com/en/rest/repos/repos#list-repository-activities
+    pub fn iter_activity(&self, options: &RepoActivityListOptions) -> Stream<RepoActivity> {
+        let mut uri = vec![self.path("/activity")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get_stream(&uri.join("?"))
+    }
+
+    /// get a reference to the [actions](https://developer.github.com/v3/actions/)
+    /// settings of this repository ref
+    #[cfg(feature = "actions")]
+    pub fn actions(&self) -> RepoActions {
+        RepoActions::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to the users [assignable](https://developer.github.com/v3/issues/assignees/)
+    /// to issues of this repository ref
+    #[cfg(feature = "issues")]
+    pub fn assignees(&self) -> Assignees {
+        Assignees::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to [autolinks](https://developer.github.com/v3/repos/autolinks/)
+    /// associated with this repository ref
+    pub fn autolinks(&self) -> Autolinks {
+        Autolinks::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
     /// get a reference to branch operations
     pub fn branches(&self) -> Branches {
         Branches::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
 
+    /// get a reference to the [collaborators](https://developer.github.com/v3/repos/collaborators/)
+    /// and pending invitations of this repository ref
+    pub fn collaborators(&self) -> Collaborators {
+        Collaborators::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to [commits](https://developer.github.com/v3/repos/commits/)
+    /// associated with this repository ref
+    pub fn commits(&self) -> Commits {
+        Commits::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
     /// get a reference to content operations
     pub fn content(&self) -> Content {
         Content::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
 
+    /// get a reference to [forks](https://developer.github.com/v3/repos/forks/)
+    /// of this repository ref
+    pub fn forks(&self) -> Forks {
+        Forks::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to the [custom property](https://docs.github.com/en/rest/repos/custom-properties)
+    /// values of this repository ref
+    #[cfg(feature = "orgs")]
+    pub fn custom_properties(&self) -> RepoCustomProperties {
+        RepoCustomProperties::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
     /// get a reference to git operations
     pub fn git(&self) -> Git {
         Git::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
 
+    /// commit a set of file changes on top of an existing branch in one call.
+    /// see `Git::commit_files`
+    pub fn commit_files<B, M>(
+        &self,
+        branch: B,
+        message: M,
+        files: Vec<FileChange>,
+    ) -> Future<GitCommit>
+    where
+        B: Into<String>,
+        M: Into<String>,
+    {
+        self.git().commit_files(branch, message, files)
+    }
+
+    /// commit a set of file changes on top of `base`, creating `branch` in one
+    /// call. see `Git::commit_files_to_new_branch`
+    pub fn commit_files_to_new_branch<B, F, M>(
+        &self,
+        branch: B,
+        base: F,
+        message: M,
+        files: Vec<FileChange>,
+    ) -> Future<GitCommit>
+    where
+        B: Into<String>,
+        F: Into<String>,
+        M: Into<String>,
+    {
+        self.git()
+            .commit_files_to_new_branch(branch, base, message, files)
+    }
+
+    /// get a reference to the [events](https://developer.github.com/v3/activity/events/#list-repository-events)
+    /// of this repository ref
+    #[cfg(feature = "activity")]
+    pub fn events(&self) -> Events {
+        Events::for_repo(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
     /// get a reference to repo hook operations
+    #[cfg(feature = "hooks")]
     pub fn hooks(&self) -> Hooks {
         Hooks::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
 
+    /// get a reference to the [dependabot](https://docs.github.com/en/rest/dependabot)
+    /// alerts and secrets of this repository ref
+    #[cfg(feature = "dependabot")]
+    pub fn dependabot(&self) -> Dependabot {
+        Dependabot::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to the [dependency graph](https://docs.github.com/en/rest/dependency-graph)
+    /// of this repository ref
+    pub fn dependency_graph(&self) -> DependencyGraph {
+        DependencyGraph::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
     /// get a reference to [deployments](https://developer.github.com/v3/repos/deployments/)
     /// associated with this repository ref
+    #[cfg(feature = "deployments")]
     pub fn deployments(&self) -> Deployments {
         Deployments::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
 
+    /// get a reference to the deployment [environments](https://docs.github.com/en/rest/deployments/environments)
+    /// of this repository ref
+    #[cfg(feature = "deployments")]
+    pub fn environments(&self) -> Environments {
+        Environments::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to a deployment [environment](https://docs.github.com/en/rest/deployments/environments)
+    /// of this repository ref
+    #[cfg(feature = "deployments")]
+    pub fn environment<N>(&self, name: N) -> EnvironmentRef
+    where
+        N: Into<String>,
+    {
+        EnvironmentRef::new(
+            self.github.clone(),
+            self.owner.as_str(),
+            self.repo.as_str(),
+            name,
+        )
+    }
+
+    /// get a reference to a specific [discussion](https://developer.github.com/v4/object/discussion/)
+    /// associated with this repository ref
+    #[cfg(feature = "discussions")]
+    pub fn discussion(&self, number: u64) -> DiscussionRef {
+        DiscussionRef::new(
+            self.github.clone(),
+            self.owner.as_str(),
+            self.repo.as_str(),
+            number,
+        )
+    }
+
     /// get a reference to a specific github issue associated with this repository ref
+    #[cfg(feature = "issues")]
     pub fn issue(&self, number: u64) -> IssueRef {
         IssueRef::new(
             self.github.clone(),
@@ -380,12 +738,20 @@ impl Repository {
         )
     }
 
+    /// get a reference to the issue and pull request templates of this repository ref
+    #[cfg(feature = "issues")]
+    pub fn issue_templates(&self) -> IssueTemplates {
+        IssueTemplates::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
     /// get a reference to github issues associated with this repository ref
+    #[cfg(feature = "issues")]
     pub fn issues(&self) -> Issues {
         Issues::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
 
     /// get a reference to github checks associated with this repository ref
+    #[cfg(feature = "checks")]
     pub fn checkruns(&self) -> CheckRuns {
         CheckRuns::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
@@ -401,24 +767,58 @@ impl Repository {
         Labels::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
 
+    /// get a reference to [milestones](https://developer.github.com/v3/issues/milestones/)
+    /// associated with this repository ref
+    #[cfg(feature = "issues")]
+    pub fn milestones(&self) -> Milestones {
+        Milestones::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to [projects](https://developer.github.com/v3/projects/)
+    /// associated with this repository ref
+    #[cfg(feature = "projects")]
+    pub fn projects(&self) -> Projects {
+        Projects::for_repo(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
     /// get a list of [pulls](https://developer.github.com/v3/pulls/)
     /// associated with this repository ref
+    #[cfg(feature = "pulls")]
     pub fn pulls(&self) -> PullRequests {
         PullRequests::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
 
     /// get a reference to [releases](https://developer.github.com/v3/repos/releases/)
     /// associated with this repository ref
+    #[cfg(feature = "releases")]
     pub fn releases(&self) -> Releases {
         Releases::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
 
+    /// get a reference to the [rulesets](https://docs.github.com/en/rest/repos/rules)
+    /// of this repository ref
+    pub fn rulesets(&self) -> Rulesets {
+        Rulesets::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to the [statistics](https://developer.github.com/v3/repos/statistics/)
+    /// of this repository ref
+    pub fn stats(&self) -> RepoStats {
+        RepoStats::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
     /// get a reference to [statuses](https://developer.github.com/v3/repos/statuses/)
     /// associated with this repository ref
     pub fn statuses(&self) -> Statuses {
         Statuses::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
 
+    /// get a reference to the [tag protection](https://docs.github.com/en/rest/repos/tags)
+    /// patterns of this repository ref
+    pub fn tag_protection(&self) -> TagProtection {
+        TagProtection::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
     /// get a reference to [teams](https://developer.github.com/v3/repos/#list-teams)
     /// associated with this repository ref
     pub fn teams(&self) -> RepoTeams {
@@ -432,8 +832,23 @@ impl Repository {
         Contributors::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
 
+    /// get a reference to the authenticated user's
+    /// [subscription](https://developer.github.com/v3/activity/watching/#get-a-repository-subscription)
+    /// to this repository ref
+    #[cfg(feature = "activity")]
+    pub fn subscription(&self) -> RepoSubscription {
+        RepoSubscription::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
+    /// get a reference to the users watching this repository ref, its
+    /// [subscribers](https://developer.github.com/v3/activity/watching/#list-watchers)
+    pub fn subscribers(&self) -> Subscribers {
+        Subscribers::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
+    }
+
     /// get a reference of [traffic](https://developer.github.com/v3/repos/traffic/)
     /// associated with this repository ref
+    #[cfg(feature = "traffic")]
     pub fn traffic(&self) -> Traffic {
         Traffic::new(self.github.clone(), self.owner.as_str(), self.repo.as_str())
     }
@@ -506,9 +921,53 @@ pub struct Repo {
     pub has_pages: bool,
     pub has_downloads: bool,
     pub archived: bool,
-    pub pushed_at: String,
-    pub created_at: String,
-    pub updated_at: String, // permissions: Permissions
+    #[serde(default)]
+    pub disabled: bool,
+    /// whether new repositories may be generated from this repository
+    #[serde(default)]
+    pub is_template: bool,
+    /// One of `public`, `private`, or `internal`
+    pub visibility: Option<String>,
+    #[serde(default)]
+    pub topics: Vec<String>,
+    pub license: Option<License>,
+    /// The authenticated user's permissions on this repository
+    pub permissions: Option<Permissions>,
+    pub pushed_at: DateTime,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+/// A repository's detected license
+#[derive(Debug, Deserialize)]
+pub struct License {
+    pub key: String,
+    pub name: String,
+    pub spdx_id: Option<String>,
+    pub url: Option<String>,
+    pub node_id: Option<String>,
+}
+
+/// The languages of a repository, mapped to the number of bytes of code
+/// written in each
+pub type Languages = HashMap<String, u64>;
+
+/// The topics of a repository
+#[derive(Debug, Deserialize)]
+struct Topics {
+    names: Vec<String>,
+}
+
+/// Permissions a user has on a repository
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Permissions {
+    pub admin: bool,
+    #[serde(default)]
+    pub maintain: bool,
+    pub push: bool,
+    #[serde(default)]
+    pub triage: bool,
+    pub pull: bool,
 }
 
 impl Repo {
@@ -520,12 +979,26 @@ impl Repo {
     /// that language.
     #[allow(clippy::needless_pass_by_value)] // shipped public API
     pub fn languages(&self, github: Github) -> Future<HashMap<String, i64>> {
-        let url = Url::parse(&self.languages_url).unwrap();
-        let uri: String = url.path().into();
-        github.get(&uri)
+        github.get_url(&self.languages_url)
     }
 }
 
+/// A change to a repository's refs
+#[derive(Debug, Deserialize)]
+pub struct RepoActivity {
+    pub id: u64,
+    pub node_id: String,
+    /// the sha of the ref before the activity
+    pub before: String,
+    /// the sha of the ref after the activity
+    pub after: String,
+    #[serde(rename = "ref")]
+    pub reference: String,
+    pub timestamp: DateTime,
+    pub activity_type: ActivityType,
+    pub actor: Option<User>,
+}
+
 #[derive(Debug, Default, Serialize)]
 pub struct RepoOptions {
     pub name: String,
@@ -601,16 +1074,21 @@ impl RepoOptionsBuilder {
         self
     }
 
+    /// the team to grant access to the repository. only applies to
+    /// repositories created with `OrgRepositories#create`
     pub fn team_id(&mut self, team_id: i32) -> &mut Self {
         self.0.team_id = Some(team_id);
         self
     }
 
+    /// create an initial commit with an empty README
     pub fn auto_init(&mut self, auto_init: bool) -> &mut Self {
         self.0.auto_init = Some(auto_init);
         self
     }
 
+    /// the name of a [gitignore template](https://github.com/github/gitignore), like
+    /// `Rust`, to include in the initial commit. requires `auto_init`
     pub fn gitignore_template<GI>(&mut self, gitignore_template: GI) -> &mut Self
     where
         GI: Into<String>,
@@ -619,6 +1097,8 @@ impl RepoOptionsBuilder {
         self
     }
 
+    /// the keyword of a license, like `mit`, to include in the initial
+    /// commit. requires `auto_init`
     pub fn license_template<L>(&mut self, license_template: L) -> &mut Self
     where
         L: Into<String>,
@@ -686,6 +1166,68 @@ impl RepoOptions {
     }
 }
 
+/// Options for generating a repository from a template repository
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct TemplateRepoOptions {
+    pub name: String,
+    /// the user or organization to own the new repository. defaults to the
+    /// authenticated user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// whether to copy every branch of the template rather than only its default branch.
+    /// false by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_all_branches: Option<bool>,
+    /// false by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+}
+
+impl TemplateRepoOptions {
+    pub fn builder<N: Into<String>>(name: N) -> TemplateRepoOptionsBuilder {
+        TemplateRepoOptionsBuilder(TemplateRepoOptions {
+            name: name.into(),
+            ..Default::default()
+        })
+    }
+}
+
+pub struct TemplateRepoOptionsBuilder(TemplateRepoOptions);
+
+impl TemplateRepoOptionsBuilder {
+    pub fn owner<O>(&mut self, owner: O) -> &mut Self
+    where
+        O: Into<String>,
+    {
+        self.0.owner = Some(owner.into());
+        self
+    }
+
+    pub fn description<D>(&mut self, description: D) -> &mut Self
+    where
+        D: Into<String>,
+    {
+        self.0.description = Some(description.into());
+        self
+    }
+
+    pub fn include_all_branches(&mut self, include_all_branches: bool) -> &mut Self {
+        self.0.include_all_branches = Some(include_all_branches);
+        self
+    }
+
+    pub fn private(&mut self, private: bool) -> &mut Self {
+        self.0.private = Some(private);
+        self
+    }
+
+    pub fn build(&self) -> TemplateRepoOptions {
+        self.0.clone()
+    }
+}
+
 #[derive(Default)]
 pub struct RepoListOptions {
     params: HashMap<&'static str, String>,
@@ -788,6 +1330,12 @@ pub struct RepoEditOptions {
     pub allow_merge_commit: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_rebase_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_branch_on_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<RepoVisibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
 }
 
 impl RepoEditOptions {
@@ -823,6 +1371,9 @@ impl RepoEditOptions {
             allow_squash_merge,
             allow_merge_commit,
             allow_rebase_merge,
+            delete_branch_on_merge: None,
+            visibility: None,
+            archived: None,
         }
     }
 
@@ -903,20 +1454,44 @@ impl RepoEditOptionsBuilder {
         self
     }
 
+    /// automatically delete head branches when pull requests are merged
+    pub fn delete_branch_on_merge(&mut self, delete_branch_on_merge: bool) -> &mut Self {
+        self.0.delete_branch_on_merge = Some(delete_branch_on_merge);
+        self
+    }
+
+    /// takes precedence over `private`. `internal` is only available for
+    /// repositories of organizations in an enterprise
+    pub fn visibility(&mut self, visibility: RepoVisibility) -> &mut Self {
+        self.0.visibility = Some(visibility);
+        self
+    }
+
+    /// archive the repository, making it read only, or unarchive it
+    pub fn archived(&mut self, archived: bool) -> &mut Self {
+        self.0.archived = Some(archived);
+        self
+    }
+
     pub fn build(&self) -> RepoEditOptions {
-        RepoEditOptions::new(
-            self.0.name.as_str(),
-            self.0.description.clone(),
-            self.0.homepage.clone(),
-            self.0.private,
-            self.0.has_issues,
-            self.0.has_projects,
-            self.0.has_wiki,
-            self.0.default_branch.clone(),
-            self.0.allow_squash_merge,
-            self.0.allow_merge_commit,
-            self.0.allow_rebase_merge,
-        )
+        RepoEditOptions {
+            delete_branch_on_merge: self.0.delete_branch_on_merge,
+            visibility: self.0.visibility,
+            archived: self.0.archived,
+            ..RepoEditOptions::new(
+                self.0.name.as_str(),
+                self.0.description.clone(),
+                self.0.homepage.clone(),
+                self.0.private,
+                self.0.has_issues,
+                self.0.has_projects,
+                self.0.has_wiki,
+                self.0.default_branch.clone(),
+                self.0.allow_squash_merge,
+                self.0.allow_merge_commit,
+                self.0.allow_rebase_merge,
+            )
+        }
     }
 }
 
@@ -1069,3 +1644,199 @@ impl OrganizationRepoListOptionsBuilder {
         }
     }
 }
+
+#[derive(Default)]
+pub struct RepoActivityListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl RepoActivityListOptions {
+    pub fn builder() -> RepoActivityListOptionsBuilder {
+        RepoActivityListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct RepoActivityListOptionsBuilder(RepoActivityListOptions);
+
+impl RepoActivityListOptionsBuilder {
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    /// list the activity before a pagination cursor
+    pub fn before<C>(&mut self, cursor: C) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        self.0.params.insert("before", cursor.into());
+        self
+    }
+
+    /// list the activity after a pagination cursor
+    pub fn after<C>(&mut self, cursor: C) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        self.0.params.insert("after", cursor.into());
+        self
+    }
+
+    pub fn asc(&mut self) -> &mut Self {
+        self.direction(SortDirection::Asc)
+    }
+
+    pub fn desc(&mut self) -> &mut Self {
+        self.direction(SortDirection::Desc)
+    }
+
+    pub fn direction(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("direction", direction.to_string());
+        self
+    }
+
+    /// only list activity of a ref, like `main` or `refs/heads/main`
+    pub fn reference<R>(&mut self, reference: R) -> &mut Self
+    where
+        R: Into<String>,
+    {
+        self.0.params.insert("ref", reference.into());
+        self
+    }
+
+    /// only list activity of a user
+    pub fn actor<A>(&mut self, actor: A) -> &mut Self
+    where
+        A: Into<String>,
+    {
+        self.0.params.insert("actor", actor.into());
+        self
+    }
+
+    pub fn time_period(&mut self, period: TimePeriod) -> &mut Self {
+        self.0.params.insert("time_period", period.to_string());
+        self
+    }
+
+    pub fn activity_type(&mut self, activity_type: ActivityType) -> &mut Self {
+        self.0.params.insert("activity_type", activity_type.to_string());
+        self
+    }
+
+    pub fn build(&self) -> RepoActivityListOptions {
+        RepoActivityListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::{HeaderMap, HeaderValue};
+    use http::{Method, StatusCode};
+
+    use super::*;
+    use crate::errors::{Error, ErrorKind};
+    use crate::transport::MockTransport;
+
+    #[test]
+    fn delete_fails_with_missing_scope_when_github_reports_one() {
+        let forbidden = |granted: &'static str| {
+            let mut headers = HeaderMap::new();
+            headers.insert("x-oauth-scopes", HeaderValue::from_static(granted));
+            headers.insert(
+                "x-accepted-oauth-scopes",
+                HeaderValue::from_static("delete_repo"),
+            );
+            headers
+        };
+        let body = r#"{"message": "Must have admin rights to Repository."}"#;
+        let mut github = Github::new("agent", None).unwrap();
+        github.set_transport(
+            MockTransport::new()
+                .respond_with_headers(
+                    Method::DELETE,
+                    "/repos/softprops/unscoped",
+                    StatusCode::FORBIDDEN,
+                    forbidden("repo, user"),
+                    body,
+                )
+                .respond_with_headers(
+                    Method::DELETE,
+                    "/repos/softprops/not-admin",
+                    StatusCode::FORBIDDEN,
+                    forbidden("repo, delete_repo"),
+                    body,
+                )
+                .respond(
+                    Method::DELETE,
+                    "/repos/softprops/fine-grained",
+                    StatusCode::FORBIDDEN,
+                    body,
+                ),
+        );
+        let delete = |repo: &str| github.repo("softprops", repo).delete().wait();
+        match delete("unscoped") {
+            Err(Error(ErrorKind::MissingScope { scope, .. }, _)) => {
+                assert_eq!(scope, "delete_repo")
+            }
+            otherwise => panic!("unexpected result {:?}", otherwise),
+        }
+        // forbidden for reasons other than the token's scopes
+        for repo in &["not-admin", "fine-grained"] {
+            match delete(repo) {
+                Err(Error(ErrorKind::Fault { code, .. }, _)) => {
+                    assert_eq!(code, StatusCode::FORBIDDEN)
+                }
+                otherwise => panic!("unexpected result {:?}", otherwise),
+            }
+        }
+    }
+
+    #[test]
+    fn serialize_repo_edit_options() {
+        let options = RepoEditOptions::builder("hubcaps")
+            .visibility(RepoVisibility::Internal)
+            .delete_branch_on_merge(true)
+            .build();
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({
+                "name": "hubcaps",
+                "visibility": "internal",
+                "delete_branch_on_merge": true
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_repo_options() {
+        let options = RepoOptions::builder("hubcaps")
+            .private(true)
+            .auto_init(true)
+            .license_template("mit")
+            .build();
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({
+                "name": "hubcaps",
+                "private": true,
+                "auto_init": true,
+                "license_template": "mit"
+            })
+        );
+    }
+}
@@ -1,8 +1,30 @@
 //! Review comments interface
+use std::collections::HashMap;
+use std::fmt;
+
+use url::form_urlencoded;
 use serde::{Deserialize, Serialize};
 
+use crate::reviews::DiffSide;
 use crate::users::User;
-use crate::{Future, Github};
+use crate::{DateTime, Future, Github, SortDirection, Stream};
+
+/// Sort options available for review comments
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReviewCommentSort {
+    Created,
+    Updated,
+}
+
+impl fmt::Display for ReviewCommentSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ReviewCommentSort::Created => "created",
+            ReviewCommentSort::Updated => "updated",
+        }
+        .fmt(f)
+    }
+}
 
 /// A structure for interfacing with a review comments
 pub struct ReviewComments {
@@ -28,8 +50,21 @@ impl ReviewComments {
     }
 
     /// list review comments
-    pub fn list(&self) -> Future<Vec<ReviewComment>> {
-        self.github.get::<Vec<ReviewComment>>(&self.path())
+    pub fn list(&self, options: &ReviewCommentListOptions) -> Future<Vec<ReviewComment>> {
+        let mut uri = vec![self.path()];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get::<Vec<ReviewComment>>(&uri.join("?"))
+    }
+
+    /// provides a stream over all pages of review comments
+    pub fn iter(&self, options: &ReviewCommentListOptions) -> Stream<ReviewComment> {
+        let mut uri = vec![self.path()];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get_stream(&uri.join("?"))
     }
 
     /// Create new review comment
@@ -37,38 +72,183 @@ impl ReviewComments {
         self.github.post(&self.path(), json!(review_comment))
     }
 
+    /// Reply to a top level review comment. Replies to replies aren't supported
+    ///
+    /// See the [github docs](https://developer.github.com/v3/pulls/comments/#create-a-reply-for-a-review-comment)
+    /// for more information.
+    pub fn reply(&self, id: u64, body: &str) -> Future<ReviewComment> {
+        self.github.post(
+            &format!("{}/{}/replies", self.path(), id),
+            json_lit!({ "body": body }),
+        )
+    }
+
+    /// get a single review comment by its id
+    pub fn get(&self, id: u64) -> Future<ReviewComment> {
+        self.github.get(&self.comment_path(id))
+    }
+
+    /// replace the body of a review comment
+    pub fn edit(&self, id: u64, body: &str) -> Future<ReviewComment> {
+        self.github
+            .patch(&self.comment_path(id), json_lit!({ "body": body }))
+    }
+
+    /// delete a review comment
+    pub fn delete(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.comment_path(id))
+    }
+
     fn path(&self) -> String {
         format!(
             "/repos/{}/{}/pulls/{}/comments",
             self.owner, self.repo, self.number
         )
     }
+
+    /// individual comments are addressed by id, independent of their pull
+    fn comment_path(&self, id: u64) -> String {
+        format!("/repos/{}/{}/pulls/comments/{}", self.owner, self.repo, id)
+    }
 }
 
 // representations (todo: replace with derive_builder)
 
+#[derive(Default)]
+pub struct ReviewCommentListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl ReviewCommentListOptions {
+    pub fn builder() -> ReviewCommentListOptionsBuilder {
+        ReviewCommentListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ReviewCommentListOptionsBuilder(ReviewCommentListOptions);
+
+impl ReviewCommentListOptionsBuilder {
+    pub fn since<S>(&mut self, since: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.0.params.insert("since", since.into());
+        self
+    }
+
+    pub fn sort(&mut self, sort: ReviewCommentSort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    pub fn direction(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("direction", direction.to_string());
+        self
+    }
+
+    pub fn per_page(&mut self, n: u32) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> ReviewCommentListOptions {
+        ReviewCommentListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// Comments are placed either by `line` and `side`, optionally spanning
+/// several lines from `start_line`, or by their `position` in the diff
 #[derive(Default, Serialize)]
 pub struct ReviewCommentOptions {
     pub body: String,
     pub commit_id: String,
     pub path: String,
-    pub position: usize,
+    /// the line's offset from the first `@@` hunk header of the file's diff
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<usize>,
+    /// the line of the file to comment on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<DiffSide>,
+    /// the first line of a multi-line comment ending at `line`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_side: Option<DiffSide>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct ReviewComment {
     pub id: u64,
+    /// the review this comment belongs to
+    pub pull_request_review_id: Option<u64>,
+    /// the comment this comment replies to
+    pub in_reply_to_id: Option<u64>,
     pub url: String,
     pub diff_hunk: String,
     pub path: String,
-    pub position: u64,
+    /// `None` when the comment no longer applies to the pull request's current diff
+    pub position: Option<u64>,
     pub original_position: u64,
+    pub line: Option<u64>,
+    pub original_line: Option<u64>,
+    pub side: Option<DiffSide>,
+    pub start_line: Option<u64>,
+    pub original_start_line: Option<u64>,
+    pub start_side: Option<DiffSide>,
     pub commit_id: String,
     pub original_commit_id: String,
     pub user: User,
     pub body: String,
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
     pub html_url: String,
     pub pull_request_url: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_multi_line_review_comment() {
+        let options = ReviewCommentOptions {
+            body: "extract this".into(),
+            commit_id: "6dcb09b".into(),
+            path: "src/lib.rs".into(),
+            line: Some(14),
+            side: Some(DiffSide::Right),
+            start_line: Some(10),
+            start_side: Some(DiffSide::Right),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({
+                "body": "extract this",
+                "commit_id": "6dcb09b",
+                "path": "src/lib.rs",
+                "line": 14,
+                "side": "RIGHT",
+                "start_line": 10,
+                "start_side": "RIGHT",
+            })
+        );
+    }
+}
@@ -29,17 +29,24 @@ impl ReviewRequests {
         }
     }
 
-    /// list requested reviews
+    /// list the users and teams whose review is requested. users and teams
+    /// are removed from the list once they submit a review
+    ///
+    /// See the [github docs](https://developer.github.com/v3/pulls/review_requests/#list-review-requests)
+    /// for more information.
     pub fn get(&self) -> Future<ReviewRequest> {
         self.github.get::<ReviewRequest>(&self.path())
     }
 
-    /// Add new requested reviews
+    /// Request reviews from users and teams
+    ///
+    /// See the [github docs](https://developer.github.com/v3/pulls/review_requests/#create-a-review-request)
+    /// for more information.
     pub fn create(&self, review_request: &ReviewRequestOptions) -> Future<Pull> {
         self.github.post(&self.path(), json!(review_request))
     }
 
-    /// Delete a review request
+    /// Remove requests for reviews from users and teams
     pub fn delete(&self, review_request: &ReviewRequestOptions) -> Future<()> {
         self.github
             .delete_message(&self.path(), json!(review_request))
@@ -55,17 +62,79 @@ impl ReviewRequests {
 
 // representations (todo: replace with derive_builder)
 
-#[derive(Default, Serialize)]
+#[derive(Debug, Default, Serialize)]
 pub struct ReviewRequestOptions {
     /// An array of user `logins` that will be requested.
     /// Note, each login must be a collaborator.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub reviewers: Vec<String>,
     /// An array of team `slugs` that will be requested.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub team_reviewers: Vec<String>,
 }
 
+impl ReviewRequestOptions {
+    pub fn builder() -> ReviewRequestOptionsBuilder {
+        ReviewRequestOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct ReviewRequestOptionsBuilder(ReviewRequestOptions);
+
+impl ReviewRequestOptionsBuilder {
+    /// request a review from the user with this login
+    pub fn reviewer<R>(&mut self, login: R) -> &mut Self
+    where
+        R: Into<String>,
+    {
+        self.0.reviewers.push(login.into());
+        self
+    }
+
+    /// request a review from the team with this slug
+    pub fn team_reviewer<T>(&mut self, slug: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.0.team_reviewers.push(slug.into());
+        self
+    }
+
+    pub fn build(&self) -> ReviewRequestOptions {
+        ReviewRequestOptions {
+            reviewers: self.0.reviewers.clone(),
+            team_reviewers: self.0.team_reviewers.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ReviewRequest {
     pub users: Vec<User>,
     pub teams: Vec<Team>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_review_request_options() {
+        let options = ReviewRequestOptions::builder()
+            .reviewer("octocat")
+            .reviewer("hubot")
+            .build();
+        assert_eq!(
+            serde_json::to_string(&options).unwrap(),
+            r#"{"reviewers":["octocat","hubot"]}"#
+        );
+        let options = ReviewRequestOptions::builder()
+            .team_reviewer("justice-league")
+            .build();
+        assert_eq!(
+            serde_json::to_string(&options).unwrap(),
+            r#"{"team_reviewers":["justice-league"]}"#
+        );
+    }
+}
@@ -0,0 +1,287 @@
+//! Pull request reviews interface
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/pulls/reviews/)
+use serde::{Deserialize, Serialize};
+
+use crate::review_comments::ReviewComment;
+use crate::users::User;
+use crate::{DateTime, Future, Github, Stream};
+
+/// A structure for interfacing with the reviews of a pull request
+pub struct Reviews {
+    github: Github,
+    owner: String,
+    repo: String,
+    number: u64,
+}
+
+impl Reviews {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R, number: u64) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Reviews {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+            number,
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!(
+            "/repos/{}/{}/pulls/{}/reviews{}",
+            self.owner, self.repo, self.number, more
+        )
+    }
+
+    /// list reviews
+    pub fn list(&self) -> Future<Vec<Review>> {
+        self.github.get(&self.path(""))
+    }
+
+    /// provides a stream over all pages of reviews
+    pub fn iter(&self) -> Stream<Review> {
+        self.github.get_stream(&self.path(""))
+    }
+
+    pub fn get(&self, id: u64) -> Future<Review> {
+        self.github.get(&self.path(&format!("/{}", id)))
+    }
+
+    /// create a review. reviews created without an `event` are left pending
+    /// until they are submitted with `submit`
+    ///
+    /// See the [github docs](https://developer.github.com/v3/pulls/reviews/#create-a-pull-request-review)
+    /// for more information.
+    pub fn create(&self, review: &ReviewOptions) -> Future<Review> {
+        self.github.post(&self.path(""), json!(review))
+    }
+
+    /// submit a pending review
+    pub fn submit(&self, id: u64, event: ReviewEvent, body: Option<&str>) -> Future<Review> {
+        let uri = self.path(&format!("/{}/events", id));
+        match body {
+            Some(body) => self
+                .github
+                .post(&uri, json_lit!({ "event": event, "body": body })),
+            None => self.github.post(&uri, json_lit!({ "event": event })),
+        }
+    }
+
+    /// replace the summary body of a review
+    pub fn update(&self, id: u64, body: &str) -> Future<Review> {
+        self.github.put(
+            &self.path(&format!("/{}", id)),
+            json_lit!({ "body": body }),
+        )
+    }
+
+    /// dismiss a submitted review, explaining why with `message`. dismissing
+    /// requires repository admin access or branch protection settings that
+    /// allow it
+    pub fn dismiss(&self, id: u64, message: &str) -> Future<Review> {
+        self.github.put(
+            &self.path(&format!("/{}/dismissals", id)),
+            json_lit!({ "message": message }),
+        )
+    }
+
+    /// list the inline comments of a review
+    pub fn comments(&self, id: u64) -> Future<Vec<ReviewComment>> {
+        self.github.get(&self.path(&format!("/{}/comments", id)))
+    }
+}
+
+// representations
+
+/// The action to perform when creating or submitting a review
+#[derive(Clone, Copy, Debug, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewEvent {
+    Approve,
+    RequestChanges,
+    Comment,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewState {
+    Approved,
+    ChangesRequested,
+    Commented,
+    Dismissed,
+    Pending,
+}
+
+/// The side of a diff an inline comment applies to
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DiffSide {
+    /// the deletions, in red
+    Left,
+    /// the additions or unchanged lines, in green or white
+    Right,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Review {
+    pub id: u64,
+    pub user: Option<User>,
+    pub body: Option<String>,
+    pub state: ReviewState,
+    pub html_url: String,
+    pub pull_request_url: String,
+    pub commit_id: Option<String>,
+    /// `None` while the review is pending
+    pub submitted_at: Option<DateTime>,
+}
+
+/// An inline comment created along with its review
+#[derive(Clone, Debug, Serialize)]
+pub struct DraftReviewComment {
+    path: String,
+    body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    side: Option<DiffSide>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_side: Option<DiffSide>,
+}
+
+impl DraftReviewComment {
+    /// comment on a line of the file at `path`
+    pub fn line<P, B>(path: P, line: u64, side: DiffSide, body: B) -> Self
+    where
+        P: Into<String>,
+        B: Into<String>,
+    {
+        DraftReviewComment {
+            path: path.into(),
+            body: body.into(),
+            line: Some(line),
+            side: Some(side),
+            start_line: None,
+            start_side: None,
+        }
+    }
+
+    /// comment on the lines `start` through `end` of the file at `path`
+    pub fn lines<P, B>(path: P, start: u64, end: u64, side: DiffSide, body: B) -> Self
+    where
+        P: Into<String>,
+        B: Into<String>,
+    {
+        DraftReviewComment {
+            path: path.into(),
+            body: body.into(),
+            line: Some(end),
+            side: Some(side),
+            start_line: Some(start),
+            start_side: Some(side),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ReviewOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event: Option<ReviewEvent>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    comments: Vec<DraftReviewComment>,
+}
+
+impl ReviewOptions {
+    pub fn builder() -> ReviewOptionsBuilder {
+        ReviewOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct ReviewOptionsBuilder(ReviewOptions);
+
+impl ReviewOptionsBuilder {
+    /// the commit to review. defaults to the pull request's head
+    pub fn commit_id<C>(&mut self, commit_id: C) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        self.0.commit_id = Some(commit_id.into());
+        self
+    }
+
+    /// the summary of the review. required when requesting changes or
+    /// commenting
+    pub fn body<B>(&mut self, body: B) -> &mut Self
+    where
+        B: Into<String>,
+    {
+        self.0.body = Some(body.into());
+        self
+    }
+
+    /// submit the review as it's created. without an event it stays pending
+    pub fn event(&mut self, event: ReviewEvent) -> &mut Self {
+        self.0.event = Some(event);
+        self
+    }
+
+    pub fn comment(&mut self, comment: DraftReviewComment) -> &mut Self {
+        self.0.comments.push(comment);
+        self
+    }
+
+    pub fn build(&self) -> ReviewOptions {
+        ReviewOptions {
+            commit_id: self.0.commit_id.clone(),
+            body: self.0.body.clone(),
+            event: self.0.event,
+            comments: self.0.comments.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_review_options() {
+        let options = ReviewOptions::builder()
+            .event(ReviewEvent::RequestChanges)
+            .body("a few nits")
+            .comment(DraftReviewComment::line(
+                "src/lib.rs",
+                12,
+                DiffSide::Right,
+                "typo",
+            ))
+            .build();
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({
+                "body": "a few nits",
+                "event": "REQUEST_CHANGES",
+                "comments": [
+                    {"path": "src/lib.rs", "body": "typo", "line": 12, "side": "RIGHT"}
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_review_state() {
+        let state: ReviewState = serde_json::from_str(r#""CHANGES_REQUESTED""#).unwrap();
+        assert_eq!(state, ReviewState::ChangesRequested);
+    }
+}
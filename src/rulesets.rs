@@ -0,0 +1,415 @@
+//! Rulesets interface
+//!
+//! Rulesets control how people can interact with the branches and tags of a
+//! repository, like requiring pull requests or signed commits, or restricting
+//! which tags may be created.
+//!
+//! See the [github docs](https://docs.github.com/en/rest/repos/rules) for more information
+use serde::de::{self, DeserializeOwned, Deserializer};
+use serde::ser::{self, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::{DateTime, Future, Github, Stream};
+
+/// Provides access to the rulesets of a repository
+pub struct Rulesets {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Rulesets {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Rulesets {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/rulesets{}", self.owner, self.repo, more)
+    }
+
+    /// list the rulesets of this repository. listed rulesets leave out their
+    /// conditions and rules, `get` a ruleset for those
+    pub fn list(&self) -> Future<Vec<Ruleset>> {
+        self.github.get(&self.path(""))
+    }
+
+    /// provides a stream over all pages of the rulesets of this repository
+    pub fn iter(&self) -> Stream<Ruleset> {
+        self.github.get_stream(&self.path(""))
+    }
+
+    pub fn get(&self, id: u64) -> Future<Ruleset> {
+        self.github.get(&self.path(&format!("/{}", id)))
+    }
+
+    pub fn create(&self, ruleset: &RulesetOptions) -> Future<Ruleset> {
+        self.github.post(&self.path(""), json!(ruleset))
+    }
+
+    pub fn update(&self, id: u64, ruleset: &RulesetOptions) -> Future<Ruleset> {
+        self.github
+            .put(&self.path(&format!("/{}", id)), json!(ruleset))
+    }
+
+    pub fn delete(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.path(&format!("/{}", id)))
+    }
+}
+
+// representations
+
+#[derive(Debug, Deserialize)]
+pub struct Ruleset {
+    pub id: u64,
+    pub node_id: Option<String>,
+    pub name: String,
+    pub target: Option<RulesetTarget>,
+    /// `Repository` or `Organization`
+    pub source_type: Option<String>,
+    /// the repository or organization the ruleset belongs to
+    pub source: String,
+    pub enforcement: Enforcement,
+    #[serde(default)]
+    pub bypass_actors: Vec<BypassActor>,
+    pub conditions: Option<RulesetConditions>,
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+    pub created_at: Option<DateTime>,
+    pub updated_at: Option<DateTime>,
+}
+
+/// What a ruleset applies to
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RulesetTarget {
+    Branch,
+    Tag,
+    Push,
+}
+
+/// Whether a ruleset is enforced
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Enforcement {
+    Disabled,
+    Active,
+    /// report what the ruleset would have blocked without blocking it.
+    /// only available to enterprise organizations
+    Evaluate,
+}
+
+/// A user, team, app, or role which may bypass a ruleset
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct BypassActor {
+    /// the id of the actor. unused for the `OrganizationAdmin` actor type
+    pub actor_id: Option<u64>,
+    /// one of `Integration`, `OrganizationAdmin`, `RepositoryRole`, `Team`,
+    /// or `DeployKey`
+    pub actor_type: String,
+    /// `always` or `pull_request`
+    pub bypass_mode: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct RulesetConditions {
+    pub ref_name: Option<RefNameCondition>,
+}
+
+/// The refs a ruleset applies to, as patterns of full ref names, like
+/// `refs/heads/release/*`, or as `~DEFAULT_BRANCH` or `~ALL`
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct RefNameCondition {
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// A rule of a ruleset, by its type
+#[derive(Clone, Debug, PartialEq)]
+pub enum Rule {
+    /// only allow users with bypass permission to create matching refs
+    Creation,
+    /// only allow users with bypass permission to update matching refs
+    Update,
+    /// only allow users with bypass permissions to delete matching refs
+    Deletion,
+    RequiredLinearHistory,
+    RequiredSignatures,
+    /// prevent users with push access from force pushing to matching refs
+    NonFastForward,
+    RequiredDeployments(RequiredDeploymentsParameters),
+    PullRequest(PullRequestParameters),
+    RequiredStatusChecks(RequiredStatusChecksParameters),
+    CommitMessagePattern(PatternParameters),
+    CommitAuthorEmailPattern(PatternParameters),
+    CommitterEmailPattern(PatternParameters),
+    BranchNamePattern(PatternParameters),
+    TagNamePattern(PatternParameters),
+    /// a rule hubcaps doesn't define, with its type and raw parameters
+    Other(String, Option<serde_json::Value>),
+}
+
+impl Rule {
+    fn parse(kind: String, parameters: Option<serde_json::Value>) -> serde_json::Result<Rule> {
+        Ok(match kind.as_str() {
+            "creation" => Rule::Creation,
+            "update" => Rule::Update,
+            "deletion" => Rule::Deletion,
+            "required_linear_history" => Rule::RequiredLinearHistory,
+            "required_signatures" => Rule::RequiredSignatures,
+            "non_fast_forward" => Rule::NonFastForward,
+            "required_deployments" => Rule::RequiredDeployments(params(&parameters)?),
+            "pull_request" => Rule::PullRequest(params(&parameters)?),
+            "required_status_checks" => Rule::RequiredStatusChecks(params(&parameters)?),
+            "commit_message_pattern" => Rule::CommitMessagePattern(params(&parameters)?),
+            "commit_author_email_pattern" => Rule::CommitAuthorEmailPattern(params(&parameters)?),
+            "committer_email_pattern" => Rule::CommitterEmailPattern(params(&parameters)?),
+            "branch_name_pattern" => Rule::BranchNamePattern(params(&parameters)?),
+            "tag_name_pattern" => Rule::TagNamePattern(params(&parameters)?),
+            _ => Rule::Other(kind, parameters),
+        })
+    }
+
+    fn raw(&self) -> serde_json::Result<RawRule> {
+        let (kind, parameters) = match self {
+            Rule::Creation => ("creation", None),
+            Rule::Update => ("update", None),
+            Rule::Deletion => ("deletion", None),
+            Rule::RequiredLinearHistory => ("required_linear_history", None),
+            Rule::RequiredSignatures => ("required_signatures", None),
+            Rule::NonFastForward => ("non_fast_forward", None),
+            Rule::RequiredDeployments(params) => {
+                ("required_deployments", Some(serde_json::to_value(params)?))
+            }
+            Rule::PullRequest(params) => ("pull_request", Some(serde_json::to_value(params)?)),
+            Rule::RequiredStatusChecks(params) => {
+                ("required_status_checks", Some(serde_json::to_value(params)?))
+            }
+            Rule::CommitMessagePattern(params) => {
+                ("commit_message_pattern", Some(serde_json::to_value(params)?))
+            }
+            Rule::CommitAuthorEmailPattern(params) => (
+                "commit_author_email_pattern",
+                Some(serde_json::to_value(params)?),
+            ),
+            Rule::CommitterEmailPattern(params) => {
+                ("committer_email_pattern", Some(serde_json::to_value(params)?))
+            }
+            Rule::BranchNamePattern(params) => {
+                ("branch_name_pattern", Some(serde_json::to_value(params)?))
+            }
+            Rule::TagNamePattern(params) => {
+                ("tag_name_pattern", Some(serde_json::to_value(params)?))
+            }
+            Rule::Other(kind, parameters) => {
+                return Ok(RawRule {
+                    kind: kind.clone(),
+                    parameters: parameters.clone(),
+                })
+            }
+        };
+        Ok(RawRule {
+            kind: kind.into(),
+            parameters,
+        })
+    }
+}
+
+/// deserialize the parameters of a rule
+fn params<P>(parameters: &Option<serde_json::Value>) -> serde_json::Result<P>
+where
+    P: DeserializeOwned,
+{
+    serde_json::from_value(parameters.clone().unwrap_or_default())
+}
+
+impl<'de> Deserialize<'de> for Rule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawRule::deserialize(deserializer)?;
+        Rule::parse(raw.kind, raw.parameters).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Rule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.raw()
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+/// a rule as github represents it, with its parameters described by its type
+#[derive(Deserialize, Serialize)]
+struct RawRule {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parameters: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct RequiredDeploymentsParameters {
+    /// the environments refs must be deployed to before they can be merged
+    pub required_deployment_environments: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct PullRequestParameters {
+    pub dismiss_stale_reviews_on_push: bool,
+    pub require_code_owner_review: bool,
+    pub require_last_push_approval: bool,
+    pub required_approving_review_count: u32,
+    pub required_review_thread_resolution: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct RequiredStatusChecksParameters {
+    pub required_status_checks: Vec<RequiredStatusCheck>,
+    /// whether pull requests must be tested with the latest code of the
+    /// branch they merge into
+    pub strict_required_status_checks_policy: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct RequiredStatusCheck {
+    pub context: String,
+    /// the app which must provide the status check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_id: Option<u64>,
+}
+
+/// The pattern names, like the names of branches or commit messages, must
+/// match
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct PatternParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// whether names must not match the pattern
+    #[serde(default)]
+    pub negate: bool,
+    /// one of `starts_with`, `ends_with`, `contains`, or `regex`
+    pub operator: String,
+    pub pattern: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RulesetOptions {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<RulesetTarget>,
+    pub enforcement: Enforcement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_actors: Option<Vec<BypassActor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<RulesetConditions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rules: Option<Vec<Rule>>,
+}
+
+impl RulesetOptions {
+    pub fn builder<N>(name: N, enforcement: Enforcement) -> RulesetOptionsBuilder
+    where
+        N: Into<String>,
+    {
+        RulesetOptionsBuilder(RulesetOptions {
+            name: name.into(),
+            target: None,
+            enforcement,
+            bypass_actors: None,
+            conditions: None,
+            rules: None,
+        })
+    }
+}
+
+pub struct RulesetOptionsBuilder(RulesetOptions);
+
+impl RulesetOptionsBuilder {
+    pub fn target(&mut self, target: RulesetTarget) -> &mut Self {
+        self.0.target = Some(target);
+        self
+    }
+
+    pub fn bypass_actor(&mut self, actor: BypassActor) -> &mut Self {
+        self.0.bypass_actors.get_or_insert_with(Vec::new).push(actor);
+        self
+    }
+
+    /// apply the ruleset to refs matching any of `include` and none of
+    /// `exclude`
+    pub fn ref_names<I, E>(&mut self, include: Vec<I>, exclude: Vec<E>) -> &mut Self
+    where
+        I: Into<String>,
+        E: Into<String>,
+    {
+        self.0.conditions = Some(RulesetConditions {
+            ref_name: Some(RefNameCondition {
+                include: include.into_iter().map(|i| i.into()).collect(),
+                exclude: exclude.into_iter().map(|e| e.into()).collect(),
+            }),
+        });
+        self
+    }
+
+    pub fn rule(&mut self, rule: Rule) -> &mut Self {
+        self.0.rules.get_or_insert_with(Vec::new).push(rule);
+        self
+    }
+
+    pub fn build(&self) -> RulesetOptions {
+        RulesetOptions {
+            name: self.0.name.clone(),
+            target: self.0.target,
+            enforcement: self.0.enforcement,
+            bypass_actors: self.0.bypass_actors.clone(),
+            conditions: self.0.conditions.clone(),
+            rules: self.0.rules.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_roundtrip() {
+        let rules: Vec<Rule> = serde_json::from_value(serde_json::json!([
+            {"type": "deletion"},
+            {
+                "type": "tag_name_pattern",
+                "parameters": {"operator": "starts_with", "pattern": "v", "negate": false}
+            },
+            {"type": "merge_queue", "parameters": {"grouping_strategy": "ALLGREEN"}}
+        ]))
+        .unwrap();
+        assert_eq!(rules[0], Rule::Deletion);
+        match rules[2] {
+            Rule::Other(ref kind, _) => assert_eq!(kind, "merge_queue"),
+            ref other => panic!("expected an unknown rule, got {:?}", other),
+        }
+        assert_eq!(
+            serde_json::to_value(&rules[1]).unwrap(),
+            serde_json::json!({
+                "type": "tag_name_pattern",
+                "parameters": {"operator": "starts_with", "pattern": "v", "negate": false}
+            })
+        );
+    }
+}
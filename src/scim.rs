@@ -0,0 +1,243 @@
+//! SCIM provisioning interface
+//!
+//! Lets identity providers provision and deprovision the members of
+//! organizations with SAML single sign-on enabled. See the
+//! [github docs](https://developer.github.com/v3/scim/) for more information.
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
+
+use crate::{Future, Github};
+
+const USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+
+/// Provides access to the provisioned identities of an organization
+pub struct Scim {
+    github: Github,
+    org: String,
+}
+
+impl Scim {
+    #[doc(hidden)]
+    pub fn new<O>(github: Github, org: O) -> Self
+    where
+        O: Into<String>,
+    {
+        Scim {
+            github,
+            org: org.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/scim/v2/organizations/{}/Users{}", self.org, more)
+    }
+
+    /// list the provisioned identities of this organization
+    ///
+    /// See the [github docs](https://developer.github.com/v3/scim/#get-a-list-of-provisioned-identities)
+    /// for more information.
+    pub fn list(&self, options: &ScimUserListOptions) -> Future<ScimUserList> {
+        let mut uri = vec![self.path("")];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        self.github.get(&uri.join("?"))
+    }
+
+    /// get a provisioned identity by its scim id
+    ///
+    /// See the [github docs](https://developer.github.com/v3/scim/#get-provisioning-details-for-a-single-user)
+    /// for more information.
+    pub fn get<I>(&self, id: I) -> Future<ScimUser>
+    where
+        I: Into<String>,
+    {
+        self.github.get(&self.path(&format!("/{}", id.into())))
+    }
+
+    /// provision an identity, inviting the user to this organization
+    ///
+    /// See the [github docs](https://developer.github.com/v3/scim/#provision-and-invite-users)
+    /// for more information.
+    pub fn provision(&self, user: &ScimUserOptions) -> Future<ScimUser> {
+        self.github.post(&self.path(""), json!(user))
+    }
+
+    /// replace all of a provisioned identity's attributes
+    ///
+    /// See the [github docs](https://developer.github.com/v3/scim/#replace-a-provisioned-users-information)
+    /// for more information.
+    pub fn update<I>(&self, id: I, user: &ScimUserOptions) -> Future<ScimUser>
+    where
+        I: Into<String>,
+    {
+        self.github
+            .put(&self.path(&format!("/{}", id.into())), json!(user))
+    }
+
+    /// deprovision an identity, removing the user from this organization
+    ///
+    /// See the [github docs](https://developer.github.com/v3/scim/#remove-a-user-from-the-organization)
+    /// for more information.
+    pub fn deprovision<I>(&self, id: I) -> Future<()>
+    where
+        I: Into<String>,
+    {
+        self.github.delete(&self.path(&format!("/{}", id.into())))
+    }
+}
+
+// representations
+
+/// A page of provisioned identities
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserList {
+    pub schemas: Vec<String>,
+    pub total_results: u64,
+    pub items_per_page: u64,
+    pub start_index: u64,
+    #[serde(rename = "Resources", default)]
+    pub resources: Vec<ScimUser>,
+}
+
+/// A provisioned identity
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUser {
+    pub schemas: Vec<String>,
+    pub id: String,
+    pub external_id: Option<String>,
+    pub user_name: Option<String>,
+    pub name: Option<ScimName>,
+    #[serde(default)]
+    pub emails: Vec<ScimEmail>,
+    pub active: bool,
+    pub meta: ScimMeta,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimName {
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScimEmail {
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<bool>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub email_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimMeta {
+    pub resource_type: String,
+    pub created: String,
+    pub last_modified: String,
+    pub location: String,
+}
+
+/// The attributes of an identity to provision
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserOptions {
+    schemas: Vec<String>,
+    pub user_name: String,
+    pub name: ScimName,
+    pub emails: Vec<ScimEmail>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+}
+
+impl ScimUserOptions {
+    pub fn new<U, G, F, E>(user_name: U, given_name: G, family_name: F, email: E) -> Self
+    where
+        U: Into<String>,
+        G: Into<String>,
+        F: Into<String>,
+        E: Into<String>,
+    {
+        ScimUserOptions {
+            schemas: vec![USER_SCHEMA.into()],
+            user_name: user_name.into(),
+            name: ScimName {
+                given_name: Some(given_name.into()),
+                family_name: Some(family_name.into()),
+            },
+            emails: vec![ScimEmail {
+                value: email.into(),
+                primary: Some(true),
+                email_type: Some("work".into()),
+            }],
+            external_id: None,
+            active: None,
+        }
+    }
+}
+
+/// Options used to filter provisioned identity listings
+///
+/// See the [github docs](https://developer.github.com/v3/scim/#get-a-list-of-provisioned-identities)
+/// for more information
+#[derive(Default)]
+pub struct ScimUserListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl ScimUserListOptions {
+    pub fn builder() -> ScimUserListOptionsBuilder {
+        ScimUserListOptionsBuilder::default()
+    }
+
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+/// a mutable provisioned identity list builder
+#[derive(Default)]
+pub struct ScimUserListOptionsBuilder(ScimUserListOptions);
+
+impl ScimUserListOptionsBuilder {
+    /// a scim filter expression, like `userName eq "octocat"`
+    pub fn filter<F>(&mut self, filter: F) -> &mut Self
+    where
+        F: Into<String>,
+    {
+        self.0.params.insert("filter", filter.into());
+        self
+    }
+
+    /// the 1-based index of the first identity to list
+    pub fn start_index(&mut self, n: u64) -> &mut Self {
+        self.0.params.insert("startIndex", n.to_string());
+        self
+    }
+
+    /// the number of identities to list
+    pub fn count(&mut self, n: u64) -> &mut Self {
+        self.0.params.insert("count", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> ScimUserListOptions {
+        ScimUserListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
@@ -8,11 +8,25 @@ use serde::Deserialize;
 
 use crate::labels::Label;
 use crate::users::User;
-use crate::{unfold, Future, Github, SortDirection, Stream};
+use futures::Stream as StdStream;
 
+use crate::{unfold, DateTime, Future, Github, MediaType, SortDirection, Stream};
+
+mod code;
+mod commits;
+mod query;
 mod repos;
+mod users;
+
+/// The maximum number of results Github provides for a search, regardless
+/// of the search's `total_count`
+pub const MAX_RESULTS: u64 = 1000;
 
+pub use self::code::*;
+pub use self::commits::*;
+pub use self::query::*;
 pub use self::repos::*;
+pub use self::users::*;
 
 /// Sort directions for pull requests
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -38,6 +52,9 @@ impl fmt::Display for IssuesSort {
 
 /// Provides access to general search operations
 ///
+/// Searches have a rate limit of their own, separate from the rest of the
+/// api. Exhausting it fails with an `ErrorKind::RateLimit` whose `resource`
+/// is `search`, or `code_search` for code searches
 #[derive(Clone)]
 pub struct Search {
     github: Github,
@@ -66,11 +83,34 @@ impl Search {
         SearchRepos::new(self.clone())
     }
 
+    /// Return a reference to a search interface for code
+    pub fn code(&self) -> SearchCode {
+        SearchCode::new(self.clone())
+    }
+
+    /// Return a reference to a search interface for users
+    pub fn users(&self) -> SearchUsers {
+        SearchUsers::new(self.clone())
+    }
+
+    /// Return a reference to a search interface for commits
+    pub fn commits(&self) -> SearchCommits {
+        SearchCommits::new(self.clone())
+    }
+
     fn iter<D>(&self, url: &str) -> Stream<D>
     where
         D: DeserializeOwned + 'static + Send,
     {
-        unfold(self.github.clone(), self.github.get_pages(url), items)
+        Box::new(
+            unfold(
+                self.github.clone(),
+                self.github.get_pages(url),
+                items,
+                MediaType::Json,
+            )
+            .take(MAX_RESULTS),
+        )
     }
 
     fn search<D>(&self, url: &str) -> Future<SearchResult<D>>
@@ -163,6 +203,13 @@ impl SearchIssuesOptionsBuilder {
         self
     }
 
+    /// the page of results to fetch, starting at 1. Github only provides
+    /// access to the first `MAX_RESULTS` results of a search
+    pub fn page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
     pub fn sort(&mut self, sort: IssuesSort) -> &mut Self {
         self.0.params.insert("sort", sort.to_string());
         self
@@ -180,13 +227,42 @@ impl SearchIssuesOptionsBuilder {
     }
 }
 
+/// A page of search results
 #[derive(Debug, Deserialize)]
 pub struct SearchResult<D> {
+    /// The total number of matches, which may exceed the `MAX_RESULTS` Github provides access to
     pub total_count: u64,
+    /// True when the search timed out before all matches were found
     pub incomplete_results: bool,
     pub items: Vec<D>,
 }
 
+impl<D> SearchResult<D> {
+    /// returns the number of results which can actually be paged through,
+    /// `total_count` capped at `MAX_RESULTS`
+    pub fn accessible_count(&self) -> u64 {
+        self.total_count.min(MAX_RESULTS)
+    }
+
+    /// returns the number of pages of `per_page` results that can be fetched
+    pub fn total_pages(&self, per_page: u64) -> u64 {
+        match per_page {
+            0 => 0,
+            per_page => self.accessible_count().div_ceil(per_page),
+        }
+    }
+
+    /// returns the next page number after `page` when there are more results
+    /// to fetch, respecting the `MAX_RESULTS` cap
+    pub fn next_page(&self, page: u64, per_page: u64) -> Option<u64> {
+        if page < self.total_pages(per_page) {
+            Some(page + 1)
+        } else {
+            None
+        }
+    }
+}
+
 /// May reporesent a Github Issue or PullRequest
 /// depending on the type of search
 #[derive(Debug, Deserialize)]
@@ -207,9 +283,9 @@ pub struct IssuesItem {
     pub assignee: Option<User>,
     pub assignees: Vec<User>,
     pub comments: u64,
-    pub created_at: String,
-    pub updated_at: String,
-    pub closed_at: Option<String>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub closed_at: Option<DateTime>,
     pub pull_request: Option<PullRequestInfo>,
     pub body: Option<String>,
 }
@@ -225,6 +301,21 @@ impl IssuesItem {
     }
 }
 
+/// The repository of a code or commit search result
+#[derive(Debug, Deserialize)]
+pub struct MinimalRepo {
+    pub id: u64,
+    pub node_id: String,
+    pub name: String,
+    pub full_name: String,
+    pub owner: User,
+    pub private: bool,
+    pub html_url: String,
+    pub description: Option<String>,
+    pub fork: bool,
+    pub url: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct PullRequestInfo {
     pub url: String,
@@ -232,3 +323,28 @@ pub struct PullRequestInfo {
     pub diff_url: String,
     pub patch_url: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(total_count: u64) -> SearchResult<()> {
+        SearchResult {
+            total_count,
+            incomplete_results: false,
+            items: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn search_result_pages() {
+        assert_eq!(result(0).total_pages(30), 0);
+        assert_eq!(result(0).next_page(1, 30), None);
+        assert_eq!(result(31).total_pages(30), 2);
+        assert_eq!(result(31).next_page(1, 30), Some(2));
+        assert_eq!(result(31).next_page(2, 30), None);
+        assert_eq!(result(5000).accessible_count(), MAX_RESULTS);
+        assert_eq!(result(5000).total_pages(100), 10);
+        assert_eq!(result(5000).next_page(10, 100), None);
+    }
+}
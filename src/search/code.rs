@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use url::form_urlencoded;
+
+use super::{MinimalRepo, Search, SearchResult};
+use crate::{Future, SortDirection, Stream};
+
+/// Provides access to [search operations for code](https://docs.github.com/en/rest/search/search#search-code)
+///
+/// Code searches have a rate limit of their own, separate from other searches
+pub struct SearchCode {
+    search: Search,
+}
+
+impl SearchCode {
+    #[doc(hidden)]
+    pub fn new(search: Search) -> Self {
+        Self { search }
+    }
+
+    fn search_uri<Q>(&self, q: Q, options: &SearchCodeOptions) -> String
+    where
+        Q: Into<String>,
+    {
+        let mut uri = vec!["/search/code".to_string()];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair("q", &q.into())
+            .finish();
+        uri.push(query);
+        uri.join("?")
+    }
+
+    /// Return a stream of search results for a code query
+    pub fn iter<Q>(&self, q: Q, options: &SearchCodeOptions) -> Stream<CodeItem>
+    where
+        Q: Into<String>,
+    {
+        self.search.iter::<CodeItem>(&self.search_uri(q, options))
+    }
+
+    /// Return the first page of search results for a code query
+    pub fn list<Q>(&self, q: Q, options: &SearchCodeOptions) -> Future<SearchResult<CodeItem>>
+    where
+        Q: Into<String>,
+    {
+        self.search
+            .search::<CodeItem>(&self.search_uri(q, options))
+    }
+}
+
+#[derive(Default)]
+pub struct SearchCodeOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl SearchCodeOptions {
+    pub fn builder() -> SearchCodeOptionsBuilder {
+        SearchCodeOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct SearchCodeOptionsBuilder(SearchCodeOptions);
+
+impl SearchCodeOptionsBuilder {
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    /// the page of results to fetch, starting at 1. Github only provides
+    /// access to the first `MAX_RESULTS` results of a search
+    pub fn page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
+    /// sort by when files were last indexed rather than by best match
+    pub fn sort_indexed(&mut self) -> &mut Self {
+        self.0.params.insert("sort", "indexed".into());
+        self
+    }
+
+    pub fn order(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("order", direction.to_string());
+        self
+    }
+
+    pub fn build(&self) -> SearchCodeOptions {
+        SearchCodeOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// A file matching a code search
+#[derive(Debug, Deserialize)]
+pub struct CodeItem {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub url: String,
+    pub git_url: String,
+    pub html_url: String,
+    pub repository: MinimalRepo,
+    pub score: f64,
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::Deserialize;
+use url::form_urlencoded;
+
+use super::{MinimalRepo, Search, SearchResult};
+use crate::commits::{CommitDetails, CommitRef};
+use crate::users::User;
+use crate::{Future, SortDirection, Stream};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CommitsSort {
+    /// Sort by when the commit was authored
+    AuthorDate,
+    /// Sort by when the commit was committed
+    CommitterDate,
+}
+
+impl fmt::Display for CommitsSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CommitsSort::AuthorDate => "author-date",
+            CommitsSort::CommitterDate => "committer-date",
+        }
+        .fmt(f)
+    }
+}
+
+/// Provides access to [search operations for commits](https://docs.github.com/en/rest/search/search#search-commits)
+pub struct SearchCommits {
+    search: Search,
+}
+
+impl SearchCommits {
+    #[doc(hidden)]
+    pub fn new(search: Search) -> Self {
+        Self { search }
+    }
+
+    fn search_uri<Q>(&self, q: Q, options: &SearchCommitsOptions) -> String
+    where
+        Q: Into<String>,
+    {
+        let mut uri = vec!["/search/commits".to_string()];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair("q", &q.into())
+            .finish();
+        uri.push(query);
+        uri.join("?")
+    }
+
+    /// Return a stream of search results for a commit query
+    pub fn iter<Q>(&self, q: Q, options: &SearchCommitsOptions) -> Stream<CommitsItem>
+    where
+        Q: Into<String>,
+    {
+        self.search.iter::<CommitsItem>(&self.search_uri(q, options))
+    }
+
+    /// Return the first page of search results for a commit query
+    pub fn list<Q>(
+        &self,
+        q: Q,
+        options: &SearchCommitsOptions,
+    ) -> Future<SearchResult<CommitsItem>>
+    where
+        Q: Into<String>,
+    {
+        self.search
+            .search::<CommitsItem>(&self.search_uri(q, options))
+    }
+}
+
+#[derive(Default)]
+pub struct SearchCommitsOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl SearchCommitsOptions {
+    pub fn builder() -> SearchCommitsOptionsBuilder {
+        SearchCommitsOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct SearchCommitsOptionsBuilder(SearchCommitsOptions);
+
+impl SearchCommitsOptionsBuilder {
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    /// the page of results to fetch, starting at 1. Github only provides
+    /// access to the first `MAX_RESULTS` results of a search
+    pub fn page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
+    pub fn sort(&mut self, sort: CommitsSort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    pub fn order(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("order", direction.to_string());
+        self
+    }
+
+    pub fn build(&self) -> SearchCommitsOptions {
+        SearchCommitsOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// A commit matching a commit search
+#[derive(Debug, Deserialize)]
+pub struct CommitsItem {
+    pub url: String,
+    pub sha: String,
+    pub html_url: String,
+    pub comments_url: String,
+    pub commit: CommitDetails,
+    /// the github user of the author, if github could associate one
+    pub author: Option<User>,
+    /// the github user of the committer, if github could associate one
+    pub committer: Option<User>,
+    pub parents: Vec<CommitRef>,
+    pub repository: MinimalRepo,
+    pub score: f64,
+}
@@ -0,0 +1,152 @@
+use std::fmt;
+
+/// Builds a search query out of free text terms and `qualifier:value` pairs,
+/// quoting values which contain whitespace
+///
+/// ```
+/// # use hubcaps::search::SearchQuery;
+/// let query = SearchQuery::builder()
+///     .term("memory leak")
+///     .repo("softprops/hubcaps")
+///     .label("help wanted")
+///     .exclude("is", "closed")
+///     .build();
+/// assert_eq!(
+///     query.to_string(),
+///     r#""memory leak" repo:softprops/hubcaps label:"help wanted" -is:closed"#
+/// );
+/// ```
+///
+/// See the [github docs](https://docs.github.com/en/search-github/getting-started-with-searching-on-github/understanding-the-search-syntax)
+/// for the qualifiers each kind of search supports
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchQuery {
+    parts: Vec<String>,
+}
+
+impl SearchQuery {
+    pub fn builder() -> SearchQueryBuilder {
+        SearchQueryBuilder::default()
+    }
+}
+
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.parts.join(" ").fmt(f)
+    }
+}
+
+impl From<SearchQuery> for String {
+    fn from(query: SearchQuery) -> String {
+        query.to_string()
+    }
+}
+
+impl<'a> From<&'a SearchQuery> for String {
+    fn from(query: &'a SearchQuery) -> String {
+        query.to_string()
+    }
+}
+
+#[derive(Default)]
+pub struct SearchQueryBuilder(SearchQuery);
+
+impl SearchQueryBuilder {
+    /// match free text
+    pub fn term<T>(&mut self, term: T) -> &mut Self
+    where
+        T: AsRef<str>,
+    {
+        self.0.parts.push(quote(term.as_ref()));
+        self
+    }
+
+    /// match a `qualifier:value` pair, like `language:rust` or `stars:>100`
+    pub fn qualifier<Q, V>(&mut self, qualifier: Q, value: V) -> &mut Self
+    where
+        Q: AsRef<str>,
+        V: AsRef<str>,
+    {
+        self.0
+            .parts
+            .push(format!("{}:{}", qualifier.as_ref(), quote(value.as_ref())));
+        self
+    }
+
+    /// exclude results matching a `qualifier:value` pair
+    pub fn exclude<Q, V>(&mut self, qualifier: Q, value: V) -> &mut Self
+    where
+        Q: AsRef<str>,
+        V: AsRef<str>,
+    {
+        self.0
+            .parts
+            .push(format!("-{}:{}", qualifier.as_ref(), quote(value.as_ref())));
+        self
+    }
+
+    /// only match within a repository, given as `owner/repo`
+    pub fn repo<R>(&mut self, repo: R) -> &mut Self
+    where
+        R: AsRef<str>,
+    {
+        self.qualifier("repo", repo)
+    }
+
+    /// only match within the repositories of an organization
+    pub fn org<O>(&mut self, org: O) -> &mut Self
+    where
+        O: AsRef<str>,
+    {
+        self.qualifier("org", org)
+    }
+
+    /// only match within the repositories of a user
+    pub fn user<U>(&mut self, user: U) -> &mut Self
+    where
+        U: AsRef<str>,
+    {
+        self.qualifier("user", user)
+    }
+
+    pub fn language<L>(&mut self, language: L) -> &mut Self
+    where
+        L: AsRef<str>,
+    {
+        self.qualifier("language", language)
+    }
+
+    /// match a state or kind, like `open`, `pr`, or `public`
+    pub fn is<I>(&mut self, is: I) -> &mut Self
+    where
+        I: AsRef<str>,
+    {
+        self.qualifier("is", is)
+    }
+
+    pub fn label<L>(&mut self, label: L) -> &mut Self
+    where
+        L: AsRef<str>,
+    {
+        self.qualifier("label", label)
+    }
+
+    pub fn author<A>(&mut self, author: A) -> &mut Self
+    where
+        A: AsRef<str>,
+    {
+        self.qualifier("author", author)
+    }
+
+    pub fn build(&self) -> SearchQuery {
+        self.0.clone()
+    }
+}
+
+fn quote(value: &str) -> String {
+    if value.chars().any(char::is_whitespace) {
+        format!("\"{}\"", value.replace('"', ""))
+    } else {
+        value.to_string()
+    }
+}
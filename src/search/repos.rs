@@ -5,7 +5,8 @@ use url::form_urlencoded;
 use serde::Deserialize;
 
 use super::{Search, SearchResult};
-use crate::{Future, SortDirection, Stream};
+use crate::{DateTime, Future, SortDirection, Stream};
+pub use crate::repositories::License;
 use crate::users::User;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -108,6 +109,13 @@ impl SearchReposOptionsBuilder {
         self
     }
 
+    /// the page of results to fetch, starting at 1. Github only provides
+    /// access to the first `MAX_RESULTS` results of a search
+    pub fn page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
     pub fn sort(&mut self, sort: ReposSort) -> &mut Self {
         self.0.params.insert("sort", sort.to_string());
         self
@@ -133,7 +141,7 @@ pub struct ReposItem {
     pub owner: User,
     pub private: bool,
     pub html_url: String,
-    pub description: String,
+    pub description: Option<String>,
     pub fork: bool,
     pub url: String,
     pub forks_url: String,
@@ -172,18 +180,18 @@ pub struct ReposItem {
     pub labels_url: String,
     pub releases_url: String,
     pub deployments_url: String,
-    pub created_at: String,
-    pub updated_at: String,
-    pub pushed_at: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+    pub pushed_at: DateTime,
     pub git_url: String,
     pub ssh_url: String,
     pub clone_url: String,
     pub svn_url: String,
-    pub homepage: String,
+    pub homepage: Option<String>,
     pub size: u32,
     pub stargazers_count: u32,
     pub watchers_count: u32,
-    pub language: String,
+    pub language: Option<String>,
     pub has_issues: bool,
     pub has_projects: bool,
     pub has_downloads: bool,
@@ -193,18 +201,10 @@ pub struct ReposItem {
     pub mirror_url: Option<String>,
     pub archived: bool,
     pub open_issues_count: u32,
-    pub license: License,
+    pub license: Option<License>,
     pub forks: u32,
     pub open_issues: u32,
     pub watchers: u32,
     pub default_branch: String,
     pub score: f64,
 }
-
-#[derive(Debug, Deserialize)]
-pub struct License {
-    key: String,
-    name: String,
-    spdx_id: String,
-    url: String,
-}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::Deserialize;
+use url::form_urlencoded;
+
+use super::{Search, SearchResult};
+use crate::users::UserType;
+use crate::{Future, SortDirection, Stream};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UsersSort {
+    /// Sort by the number of followers
+    Followers,
+    /// Sort by the number of public repositories
+    Repositories,
+    /// Sort by when the user joined github
+    Joined,
+}
+
+impl fmt::Display for UsersSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            UsersSort::Followers => "followers",
+            UsersSort::Repositories => "repositories",
+            UsersSort::Joined => "joined",
+        }
+        .fmt(f)
+    }
+}
+
+/// Provides access to [search operations for users](https://docs.github.com/en/rest/search/search#search-users)
+pub struct SearchUsers {
+    search: Search,
+}
+
+impl SearchUsers {
+    #[doc(hidden)]
+    pub fn new(search: Search) -> Self {
+        Self { search }
+    }
+
+    fn search_uri<Q>(&self, q: Q, options: &SearchUsersOptions) -> String
+    where
+        Q: Into<String>,
+    {
+        let mut uri = vec!["/search/users".to_string()];
+        let query_options = options.serialize().unwrap_or_default();
+        let query = form_urlencoded::Serializer::new(query_options)
+            .append_pair("q", &q.into())
+            .finish();
+        uri.push(query);
+        uri.join("?")
+    }
+
+    /// Return a stream of search results for a user query
+    pub fn iter<Q>(&self, q: Q, options: &SearchUsersOptions) -> Stream<UsersItem>
+    where
+        Q: Into<String>,
+    {
+        self.search.iter::<UsersItem>(&self.search_uri(q, options))
+    }
+
+    /// Return the first page of search results for a user query
+    pub fn list<Q>(&self, q: Q, options: &SearchUsersOptions) -> Future<SearchResult<UsersItem>>
+    where
+        Q: Into<String>,
+    {
+        self.search
+            .search::<UsersItem>(&self.search_uri(q, options))
+    }
+}
+
+#[derive(Default)]
+pub struct SearchUsersOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl SearchUsersOptions {
+    pub fn builder() -> SearchUsersOptionsBuilder {
+        SearchUsersOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct SearchUsersOptionsBuilder(SearchUsersOptions);
+
+impl SearchUsersOptionsBuilder {
+    pub fn per_page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    /// the page of results to fetch, starting at 1. Github only provides
+    /// access to the first `MAX_RESULTS` results of a search
+    pub fn page(&mut self, n: usize) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
+    pub fn sort(&mut self, sort: UsersSort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    pub fn order(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("order", direction.to_string());
+        self
+    }
+
+    pub fn build(&self) -> SearchUsersOptions {
+        SearchUsersOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+/// A user or organization matching a user search
+#[derive(Debug, Deserialize)]
+pub struct UsersItem {
+    pub login: String,
+    pub id: u64,
+    pub avatar_url: String,
+    pub url: String,
+    pub html_url: String,
+    #[serde(rename = "type")]
+    pub user_type: UserType,
+    pub site_admin: bool,
+    pub score: f64,
+}
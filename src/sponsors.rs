@@ -0,0 +1,412 @@
+//! Sponsors interface
+//!
+//! Github sponsors is only exposed through github's
+//! [graphql api](https://developer.github.com/v4/object/sponsorship/). The
+//! sponsors of both users and organizations are available through
+//! `Github::sponsors`.
+use std::fmt;
+
+use futures::Future as StdFuture;
+use serde::Deserialize;
+
+use crate::{DateTime, Future, Github, PageInfo};
+
+const SPONSORSHIPS_QUERY: &str = "
+query($login: String!, $first: Int!, $after: String) {
+  repositoryOwner(login: $login) {
+    ... on Sponsorable {
+      sponsorshipsAsMaintainer(first: $first, after: $after, includePrivate: true) {
+        totalCount
+        pageInfo { hasNextPage endCursor }
+        nodes {
+          id createdAt isOneTimePayment privacyLevel
+          tier { id name description monthlyPriceInDollars isOneTime isCustomAmount }
+          sponsorEntity {
+            ... on User { login url avatarUrl }
+            ... on Organization { login url avatarUrl }
+          }
+        }
+      }
+    }
+  }
+}";
+
+const TIERS_QUERY: &str = "
+query($login: String!) {
+  repositoryOwner(login: $login) {
+    ... on Sponsorable {
+      sponsorsListing {
+        tiers(first: 100) {
+          nodes { id name description monthlyPriceInDollars isOneTime isCustomAmount }
+        }
+      }
+    }
+  }
+}";
+
+const ACTIVITIES_QUERY: &str = "
+query($login: String!, $first: Int!, $after: String, $period: SponsorsActivityPeriod) {
+  repositoryOwner(login: $login) {
+    ... on Sponsorable {
+      sponsorsActivities(first: $first, after: $after, period: $period) {
+        totalCount
+        pageInfo { hasNextPage endCursor }
+        nodes {
+          id action timestamp
+          sponsor {
+            ... on User { login url avatarUrl }
+            ... on Organization { login url avatarUrl }
+          }
+          sponsorsTier { id name description monthlyPriceInDollars isOneTime isCustomAmount }
+          previousSponsorsTier { id name description monthlyPriceInDollars isOneTime isCustomAmount }
+        }
+      }
+    }
+  }
+}";
+
+/// Provides access to the sponsors of a user or organization
+pub struct Sponsors {
+    github: Github,
+    login: String,
+}
+
+impl Sponsors {
+    #[doc(hidden)]
+    pub fn new<L>(github: Github, login: L) -> Self
+    where
+        L: Into<String>,
+    {
+        Sponsors {
+            github,
+            login: login.into(),
+        }
+    }
+
+    /// list a page of the active sponsorships of this user or organization.
+    /// private sponsorships are only included when authenticated as the
+    /// sponsored account
+    pub fn list(&self, options: &SponsorListOptions) -> Future<SponsorshipPage> {
+        Box::new(
+            self.github
+                .graphql::<OwnerData<SponsorshipsData>>(
+                    SPONSORSHIPS_QUERY,
+                    serde_json::json!({
+                        "login": self.login,
+                        "first": options.per_page.unwrap_or(30),
+                        "after": options.after
+                    }),
+                )
+                .map(|data| data.repository_owner.sponsorships_as_maintainer),
+        )
+    }
+
+    /// list the tiers of this user or organization's sponsors listing. empty
+    /// when the account has no listing
+    pub fn tiers(&self) -> Future<Vec<SponsorsTier>> {
+        Box::new(
+            self.github
+                .graphql::<OwnerData<TiersData>>(
+                    TIERS_QUERY,
+                    serde_json::json!({ "login": self.login }),
+                )
+                .map(|data| match data.repository_owner.sponsors_listing {
+                    Some(listing) => listing.tiers.nodes,
+                    None => Vec::new(),
+                }),
+        )
+    }
+
+    /// list a page of sponsorship activity, such as new and cancelled
+    /// sponsorships. only available when authenticated as the sponsored account
+    pub fn activity(&self, options: &SponsorActivityListOptions) -> Future<SponsorsActivityPage> {
+        Box::new(
+            self.github
+                .graphql::<OwnerData<ActivitiesData>>(
+                    ACTIVITIES_QUERY,
+                    serde_json::json!({
+                        "login": self.login,
+                        "first": options.per_page.unwrap_or(30),
+                        "after": options.after,
+                        "period": options.period.map(|period| period.to_string())
+                    }),
+                )
+                .map(|data| data.repository_owner.sponsors_activities),
+        )
+    }
+}
+
+// representations
+
+/// The period of sponsorship activity to list
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SponsorsActivityPeriod {
+    Day,
+    Week,
+    Month,
+    All,
+}
+
+impl fmt::Display for SponsorsActivityPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SponsorsActivityPeriod::Day => "DAY",
+            SponsorsActivityPeriod::Week => "WEEK",
+            SponsorsActivityPeriod::Month => "MONTH",
+            SponsorsActivityPeriod::All => "ALL",
+        }
+        .fmt(f)
+    }
+}
+
+/// The kind of a sponsorship activity
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SponsorsActivityAction {
+    NewSponsorship,
+    CancelledSponsorship,
+    TierChange,
+    Refund,
+    PendingChange,
+    SponsorMatchDisabled,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SponsorshipPrivacy {
+    Public,
+    Private,
+}
+
+/// A user or organization sponsoring an account
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Sponsor {
+    pub login: String,
+    pub url: String,
+    pub avatar_url: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SponsorsTier {
+    /// the graphql node id of this tier
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub monthly_price_in_dollars: u64,
+    pub is_one_time: bool,
+    pub is_custom_amount: bool,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Sponsorship {
+    /// the graphql node id of this sponsorship
+    pub id: String,
+    pub created_at: DateTime,
+    pub is_one_time_payment: bool,
+    pub privacy_level: SponsorshipPrivacy,
+    /// `None` when the viewer may not see the sponsorship's tier
+    pub tier: Option<SponsorsTier>,
+    /// `None` when the sponsor's account is hidden from the viewer
+    pub sponsor_entity: Option<Sponsor>,
+}
+
+/// A page of sponsorships
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SponsorshipPage {
+    pub total_count: u64,
+    pub page_info: PageInfo,
+    pub nodes: Vec<Sponsorship>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SponsorsActivity {
+    /// the graphql node id of this activity
+    pub id: String,
+    pub action: SponsorsActivityAction,
+    pub timestamp: Option<DateTime>,
+    pub sponsor: Option<Sponsor>,
+    pub sponsors_tier: Option<SponsorsTier>,
+    /// the tier sponsored before a `TierChange`
+    pub previous_sponsors_tier: Option<SponsorsTier>,
+}
+
+/// A page of sponsorship activity
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SponsorsActivityPage {
+    pub total_count: u64,
+    pub page_info: PageInfo,
+    pub nodes: Vec<SponsorsActivity>,
+}
+
+#[derive(Default)]
+pub struct SponsorListOptions {
+    per_page: Option<u32>,
+    after: Option<String>,
+}
+
+impl SponsorListOptions {
+    pub fn builder() -> SponsorListOptionsBuilder {
+        SponsorListOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct SponsorListOptionsBuilder(SponsorListOptions);
+
+impl SponsorListOptionsBuilder {
+    /// the number of sponsorships to list, up to 100. defaults to 30
+    pub fn per_page(&mut self, n: u32) -> &mut Self {
+        self.0.per_page = Some(n);
+        self
+    }
+
+    /// list the sponsorships after a page's `end_cursor`
+    pub fn after<C>(&mut self, cursor: C) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        self.0.after = Some(cursor.into());
+        self
+    }
+
+    pub fn build(&self) -> SponsorListOptions {
+        SponsorListOptions {
+            per_page: self.0.per_page,
+            after: self.0.after.clone(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct SponsorActivityListOptions {
+    per_page: Option<u32>,
+    after: Option<String>,
+    period: Option<SponsorsActivityPeriod>,
+}
+
+impl SponsorActivityListOptions {
+    pub fn builder() -> SponsorActivityListOptionsBuilder {
+        SponsorActivityListOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct SponsorActivityListOptionsBuilder(SponsorActivityListOptions);
+
+impl SponsorActivityListOptionsBuilder {
+    /// the number of activities to list, up to 100. defaults to 30
+    pub fn per_page(&mut self, n: u32) -> &mut Self {
+        self.0.per_page = Some(n);
+        self
+    }
+
+    /// list the activities after a page's `end_cursor`
+    pub fn after<C>(&mut self, cursor: C) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        self.0.after = Some(cursor.into());
+        self
+    }
+
+    /// the period of activity to list. github defaults to a month
+    pub fn period(&mut self, period: SponsorsActivityPeriod) -> &mut Self {
+        self.0.period = Some(period);
+        self
+    }
+
+    pub fn build(&self) -> SponsorActivityListOptions {
+        SponsorActivityListOptions {
+            per_page: self.0.per_page,
+            after: self.0.after.clone(),
+            period: self.0.period,
+        }
+    }
+}
+
+// graphql response wrappers
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OwnerData<D> {
+    repository_owner: D,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SponsorshipsData {
+    sponsorships_as_maintainer: SponsorshipPage,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TiersData {
+    sponsors_listing: Option<SponsorsListing>,
+}
+
+#[derive(Deserialize)]
+struct SponsorsListing {
+    tiers: TierNodes,
+}
+
+#[derive(Deserialize)]
+struct TierNodes {
+    nodes: Vec<SponsorsTier>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ActivitiesData {
+    sponsors_activities: SponsorsActivityPage,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_activity_page() {
+        let data: OwnerData<ActivitiesData> = serde_json::from_str(
+            r#"{"repositoryOwner": {"sponsorsActivities": {
+                "totalCount": 1,
+                "pageInfo": {"hasNextPage": false, "endCursor": null},
+                "nodes": [{
+                    "id": "SA_kwDOABCD",
+                    "action": "TIER_CHANGE",
+                    "timestamp": "2021-01-01T00:00:00Z",
+                    "sponsor": {
+                        "login": "octocat",
+                        "url": "https://github.com/octocat",
+                        "avatarUrl": "https://avatars.githubusercontent.com/u/583231"
+                    },
+                    "sponsorsTier": {
+                        "id": "ST_kwDOABCE",
+                        "name": "$10 a month",
+                        "description": "thank you!",
+                        "monthlyPriceInDollars": 10,
+                        "isOneTime": false,
+                        "isCustomAmount": false
+                    },
+                    "previousSponsorsTier": null
+                }]
+            }}}"#,
+        )
+        .unwrap();
+        let page = data.repository_owner.sponsors_activities;
+        assert_eq!(page.nodes[0].action, SponsorsActivityAction::TierChange);
+        assert_eq!(
+            page.nodes[0]
+                .sponsors_tier
+                .as_ref()
+                .map(|tier| tier.monthly_price_in_dollars),
+            Some(10)
+        );
+        assert_eq!(page.nodes[0].previous_sponsors_tier, None);
+    }
+}
@@ -1,8 +1,38 @@
 //! Stars interface
+use std::collections::HashMap;
+use std::fmt;
+
 use futures::Future as StdFuture;
 use http::StatusCode;
+use serde::Deserialize;
+use url::form_urlencoded;
+
+use crate::repositories::Repo;
+use crate::users::User;
+use crate::{DateTime, Error, ErrorKind, Future, Github, MediaType, SortDirection, Stream};
 
-use crate::{Error, ErrorKind, Future, Github};
+/// the representation of stargazers and starred repositories that includes
+/// when they were starred
+const STAR_MEDIA: MediaType = MediaType::Custom("star");
+
+/// Sort options available for starred repositories
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StarSort {
+    /// when the repository was starred
+    Created,
+    /// when the repository was last pushed to
+    Updated,
+}
+
+impl fmt::Display for StarSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            StarSort::Created => "created",
+            StarSort::Updated => "updated",
+        }
+        .fmt(f)
+    }
+}
 
 pub struct Stars {
     github: Github,
@@ -14,6 +44,14 @@ impl Stars {
         Self { github }
     }
 
+    fn uri(path: String, options: &StarListOptions) -> String {
+        let mut uri = vec![path];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        uri.join("?")
+    }
+
     /// Returns whether or not authenticated user has started a repo
     pub fn is_starred<O, R>(&self, owner: O, repo: R) -> Future<bool>
     where
@@ -59,4 +97,331 @@ impl Stars {
         self.github
             .delete(&format!("/user/starred/{}/{}", owner.into(), repo.into()))
     }
+
+    /// list the users who starred a repo
+    ///
+    /// See the [github docs](https://developer.github.com/v3/activity/starring/#list-stargazers)
+    /// for more information.
+    pub fn stargazers<O, R>(&self, owner: O, repo: R, options: &StarListOptions) -> Future<Vec<User>>
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        self.github.get(&Self::uri(
+            format!("/repos/{}/{}/stargazers", owner.into(), repo.into()),
+            options,
+        ))
+    }
+
+    /// provides a stream over all pages of the users who starred a repo
+    pub fn iter_stargazers<O, R>(&self, owner: O, repo: R, options: &StarListOptions) -> Stream<User>
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        self.github.get_stream(&Self::uri(
+            format!("/repos/{}/{}/stargazers", owner.into(), repo.into()),
+            options,
+        ))
+    }
+
+    /// list the users who starred a repo along with when they starred it.
+    /// use `StarListOptionsBuilder#page` to page through stargazers
+    pub fn stargazers_with_timestamps<O, R>(
+        &self,
+        owner: O,
+        repo: R,
+        options: &StarListOptions,
+    ) -> Future<Vec<Stargazer>>
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        self.github.get_media(
+            &Self::uri(
+                format!("/repos/{}/{}/stargazers", owner.into(), repo.into()),
+                options,
+            ),
+            STAR_MEDIA,
+        )
+    }
+
+    /// provides a stream over all pages of the users who starred a repo along
+    /// with when they starred it
+    pub fn iter_stargazers_with_timestamps<O, R>(
+        &self,
+        owner: O,
+        repo: R,
+        options: &StarListOptions,
+    ) -> Stream<Stargazer>
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        self.github.get_stream_media(
+            &Self::uri(
+                format!("/repos/{}/{}/stargazers", owner.into(), repo.into()),
+                options,
+            ),
+            STAR_MEDIA,
+        )
+    }
+
+    /// list the repos starred by the authenticated user
+    ///
+    /// See the [github docs](https://developer.github.com/v3/activity/starring/#list-repositories-being-starred)
+    /// for more information.
+    pub fn starred(&self, options: &StarListOptions) -> Future<Vec<Repo>> {
+        self.github
+            .get(&Self::uri("/user/starred".into(), options))
+    }
+
+    /// provides a stream over all pages of the repos starred by the
+    /// authenticated user
+    pub fn iter_starred(&self, options: &StarListOptions) -> Stream<Repo> {
+        self.github
+            .get_stream(&Self::uri("/user/starred".into(), options))
+    }
+
+    /// list the repos starred by the authenticated user along with when they
+    /// were starred
+    pub fn starred_with_timestamps(&self, options: &StarListOptions) -> Future<Vec<StarredRepo>> {
+        self.github
+            .get_media(&Self::uri("/user/starred".into(), options), STAR_MEDIA)
+    }
+
+    /// provides a stream over all pages of the repos starred by the
+    /// authenticated user along with when they were starred
+    pub fn iter_starred_with_timestamps(&self, options: &StarListOptions) -> Stream<StarredRepo> {
+        self.github
+            .get_stream_media(&Self::uri("/user/starred".into(), options), STAR_MEDIA)
+    }
+
+    /// list the repos starred by a user
+    pub fn user_starred<U>(&self, user: U, options: &StarListOptions) -> Future<Vec<Repo>>
+    where
+        U: Into<String>,
+    {
+        self.github.get(&Self::uri(
+            format!("/users/{}/starred", user.into()),
+            options,
+        ))
+    }
+
+    /// provides a stream over all pages of the repos starred by a user
+    pub fn iter_user_starred<U>(&self, user: U, options: &StarListOptions) -> Stream<Repo>
+    where
+        U: Into<String>,
+    {
+        self.github.get_stream(&Self::uri(
+            format!("/users/{}/starred", user.into()),
+            options,
+        ))
+    }
+
+    /// list the repos starred by a user along with when they were starred
+    pub fn user_starred_with_timestamps<U>(
+        &self,
+        user: U,
+        options: &StarListOptions,
+    ) -> Future<Vec<StarredRepo>>
+    where
+        U: Into<String>,
+    {
+        self.github.get_media(
+            &Self::uri(format!("/users/{}/starred", user.into()), options),
+            STAR_MEDIA,
+        )
+    }
+
+    /// provides a stream over all pages of the repos starred by a user along
+    /// with when they were starred
+    pub fn iter_user_starred_with_timestamps<U>(
+        &self,
+        user: U,
+        options: &StarListOptions,
+    ) -> Stream<StarredRepo>
+    where
+        U: Into<String>,
+    {
+        self.github.get_stream_media(
+            &Self::uri(format!("/users/{}/starred", user.into()), options),
+            STAR_MEDIA,
+        )
+    }
+}
+
+// representations
+
+/// A user who starred a repository
+#[derive(Debug, Deserialize)]
+pub struct Stargazer {
+    pub starred_at: DateTime,
+    pub user: User,
+}
+
+/// A repository starred by a user
+#[derive(Debug, Deserialize)]
+pub struct StarredRepo {
+    pub starred_at: DateTime,
+    pub repo: Repo,
+}
+
+#[derive(Default)]
+pub struct StarListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl StarListOptions {
+    pub fn builder() -> StarListOptionsBuilder {
+        StarListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct StarListOptionsBuilder(StarListOptions);
+
+impl StarListOptionsBuilder {
+    /// only applies to starred repositories
+    pub fn sort(&mut self, sort: StarSort) -> &mut Self {
+        self.0.params.insert("sort", sort.to_string());
+        self
+    }
+
+    /// only applies to starred repositories
+    pub fn asc(&mut self) -> &mut Self {
+        self.direction(SortDirection::Asc)
+    }
+
+    /// only applies to starred repositories
+    pub fn desc(&mut self) -> &mut Self {
+        self.direction(SortDirection::Desc)
+    }
+
+    /// only applies to starred repositories
+    pub fn direction(&mut self, direction: SortDirection) -> &mut Self {
+        self.0.params.insert("direction", direction.to_string());
+        self
+    }
+
+    pub fn per_page(&mut self, n: u32) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn page(&mut self, n: u32) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> StarListOptions {
+        StarListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use futures::{future, Stream as StdStream};
+    use http::header::{ACCEPT, LINK};
+    use reqwest::r#async::{Request, Response};
+
+    use super::*;
+    use crate::transport::Transport;
+
+    /// answers the first page of stargazers with a link to a second,
+    /// recording the accept header of every request
+    #[derive(Debug, Default)]
+    struct PagedStargazers {
+        accepted: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Transport for PagedStargazers {
+        fn execute(&self, request: Request) -> Future<Response> {
+            let accept = request
+                .headers()
+                .get(ACCEPT)
+                .and_then(|value| value.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+            self.accepted.lock().unwrap().push(accept);
+            let mut response = http::Response::builder();
+            if request.url().query() != Some("page=2") {
+                response.header(
+                    LINK,
+                    r#"<https://api.github.com/repos/softprops/hubcaps/stargazers?page=2>; rel="next""#,
+                );
+            }
+            let body = serde_json::json!([{
+                "starred_at": "2019-01-01T00:00:00Z",
+                "user": {
+                    "login": "octocat",
+                    "id": 1,
+                    "avatar_url": "",
+                    "gravatar_id": "",
+                    "url": "",
+                    "html_url": "",
+                    "followers_url": "",
+                    "following_url": "",
+                    "gists_url": "",
+                    "starred_url": "",
+                    "subscriptions_url": "",
+                    "organizations_url": "",
+                    "repos_url": "",
+                    "events_url": "",
+                    "received_events_url": "",
+                    "type": "User",
+                    "site_admin": false
+                }
+            }]);
+            Box::new(future::ok(Response::from(
+                response.body(body.to_string().into_bytes()).unwrap(),
+            )))
+        }
+    }
+
+    #[test]
+    fn stargazer_pages_keep_star_media_type() {
+        let transport = PagedStargazers::default();
+        let accepted = transport.accepted.clone();
+        let mut github = Github::new("agent", None).unwrap();
+        github.set_transport(transport);
+        let stargazers = Stars::new(github)
+            .iter_stargazers_with_timestamps("softprops", "hubcaps", &StarListOptions::default())
+            .collect()
+            .wait()
+            .unwrap();
+        assert_eq!(stargazers.len(), 2);
+        assert_eq!(
+            *accepted.lock().unwrap(),
+            vec!["application/vnd.github.v3.star+json"; 2]
+        );
+    }
+
+    #[test]
+    fn star_list_reqs() {
+        assert_eq!(StarListOptions::builder().build().serialize(), None);
+        assert_eq!(
+            StarListOptions::builder()
+                .sort(StarSort::Updated)
+                .build()
+                .serialize(),
+            Some("sort=updated".to_owned())
+        );
+    }
 }
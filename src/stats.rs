@@ -0,0 +1,218 @@
+//! Repository statistics interface
+//!
+//! Github computes statistics in the background and caches them. Requesting
+//! statistics that aren't cached yet starts computing them and yields
+//! `Stats::Computing`, so they should be requested again after a short wait.
+//! `RepoStats#retry` does this automatically.
+//!
+//! For more information, visit the official
+//! [Github docs](https://developer.github.com/v3/repos/statistics/)
+use std::time::{Duration, Instant};
+
+use futures::{future, Future as StdFuture};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio_timer::Delay;
+
+use crate::users::User;
+use crate::{Error, ErrorKind, Future, Github};
+
+/// Provides access to the statistics of a repository
+pub struct RepoStats {
+    github: Github,
+    owner: String,
+    repo: String,
+    retry: Option<(u32, Duration)>,
+}
+
+impl RepoStats {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        RepoStats {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+            retry: None,
+        }
+    }
+
+    /// request statistics that are still being computed up to `attempts` more
+    /// times, waiting `interval` between requests, before yielding
+    /// `Stats::Computing`. the returned futures must be driven by a tokio
+    /// runtime
+    pub fn retry(mut self, attempts: u32, interval: Duration) -> Self {
+        self.retry = Some((attempts, interval));
+        self
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/stats{}", self.owner, self.repo, more)
+    }
+
+    fn stats<T>(&self, more: &str) -> Future<Stats<T>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let github = self.github.clone();
+        let uri = self.path(more);
+        let (attempts, interval) = self.retry.unwrap_or((0, Duration::from_secs(0)));
+        Box::new(future::loop_fn(0, move |attempt| {
+            let github = github.clone();
+            let uri = uri.clone();
+            let wait: Future<()> = if attempt == 0 {
+                Box::new(future::ok(()))
+            } else {
+                Box::new(
+                    Delay::new(Instant::now() + interval)
+                        .map_err(|e| Error::from(ErrorKind::Msg(e.to_string()))),
+                )
+            };
+            wait.and_then(move |_| github.get::<StatsResponse<T>>(&uri))
+                .map(move |response| match response {
+                    StatsResponse::Ready(stats) => future::Loop::Break(Stats::Ready(stats)),
+                    StatsResponse::Computing(_) if attempt < attempts => {
+                        future::Loop::Continue(attempt + 1)
+                    }
+                    StatsResponse::Computing(_) => future::Loop::Break(Stats::Computing),
+                })
+        }))
+    }
+
+    /// the total commits, additions, and deletions of each contributor, and
+    /// their weekly breakdown
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/statistics/#get-contributors-list-with-additions-deletions-and-commit-counts)
+    /// for more information.
+    pub fn contributors(&self) -> Future<Stats<Vec<ContributorActivity>>> {
+        self.stats("/contributors")
+    }
+
+    /// the daily commit counts of each week of the last year
+    pub fn commit_activity(&self) -> Future<Stats<Vec<WeeklyCommitActivity>>> {
+        self.stats("/commit_activity")
+    }
+
+    /// the additions and deletions of each week
+    pub fn code_frequency(&self) -> Future<Stats<Vec<CodeFrequency>>> {
+        self.stats("/code_frequency")
+    }
+
+    /// the weekly commit counts of the last year, of everyone and of the
+    /// repository owner
+    pub fn participation(&self) -> Future<Stats<Participation>> {
+        self.stats("/participation")
+    }
+
+    /// the commit counts of each hour of each day of the week
+    pub fn punch_card(&self) -> Future<Stats<Vec<PunchCard>>> {
+        self.stats("/punch_card")
+    }
+}
+
+// representations
+
+/// Statistics of a repository, which may not be computed yet
+#[derive(Debug, PartialEq)]
+pub enum Stats<T> {
+    Ready(T),
+    /// github is computing the statistics. request them again later
+    Computing,
+}
+
+impl<T> Stats<T> {
+    /// the statistics, if they are computed
+    pub fn ready(self) -> Option<T> {
+        match self {
+            Stats::Ready(stats) => Some(stats),
+            Stats::Computing => None,
+        }
+    }
+}
+
+/// github answers requests for statistics it's still computing with a 202
+/// and an empty object
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum StatsResponse<T> {
+    Ready(T),
+    Computing(Computing),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Computing {}
+
+#[derive(Debug, Deserialize)]
+pub struct ContributorActivity {
+    /// `None` for contributors without a github account
+    pub author: Option<User>,
+    pub total: u64,
+    pub weeks: Vec<ContributorWeek>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ContributorWeek {
+    /// the start of the week, as a unix timestamp
+    #[serde(rename = "w")]
+    pub week: u64,
+    #[serde(rename = "a")]
+    pub additions: u64,
+    #[serde(rename = "d")]
+    pub deletions: u64,
+    #[serde(rename = "c")]
+    pub commits: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WeeklyCommitActivity {
+    /// commit counts from sunday through saturday
+    pub days: Vec<u64>,
+    pub total: u64,
+    /// the start of the week, as a unix timestamp
+    pub week: u64,
+}
+
+/// The start of a week as a unix timestamp, and the additions and deletions
+/// made that week. Deletions are negative
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CodeFrequency(pub u64, pub i64, pub i64);
+
+#[derive(Debug, Deserialize)]
+pub struct Participation {
+    /// weekly commit counts of everyone, oldest first
+    pub all: Vec<u64>,
+    /// weekly commit counts of the repository owner, oldest first
+    pub owner: Vec<u64>,
+}
+
+/// The day of the week, starting with 0 for sunday, the hour of the day, and
+/// the number of commits made in that hour
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct PunchCard(pub u8, pub u8, pub u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_stats_response() {
+        match serde_json::from_str::<StatsResponse<Vec<CodeFrequency>>>(
+            "[[1302998400, 1124, -435]]",
+        )
+        .unwrap()
+        {
+            StatsResponse::Ready(frequency) => {
+                assert_eq!(frequency, vec![CodeFrequency(1_302_998_400, 1124, -435)])
+            }
+            other => panic!("expected stats, got {:?}", other),
+        }
+        match serde_json::from_str::<StatsResponse<Participation>>("{}").unwrap() {
+            StatsResponse::Computing(_) => (),
+            other => panic!("expected computing, got {:?}", other),
+        }
+    }
+}
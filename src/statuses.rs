@@ -1,8 +1,11 @@
 //! Statuses interface
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
 
 use crate::users::User;
-use crate::{Future, Github};
+use crate::{DateTime, Future, Github, Stream};
 
 /// interface for statuses associated with a repository
 pub struct Statuses {
@@ -35,17 +38,37 @@ impl Statuses {
             .post(&self.path(&format!("/{}", sha)), json!(status))
     }
 
-    /// lists all statuses associated with a given git sha
-    pub fn list(&self, sha: &str) -> Future<Vec<Status>> {
-        self.github.get(&format!(
+    fn list_uri(&self, reference: &str, options: &StatusListOptions) -> String {
+        let mut uri = vec![format!(
             "/repos/{}/{}/commits/{}/statuses",
-            self.owner, self.repo, sha
-        ))
+            self.owner, self.repo, reference
+        )];
+        if let Some(query) = options.serialize() {
+            uri.push(query);
+        }
+        uri.join("?")
+    }
+
+    /// lists the statuses of a git sha, branch, or tag, most recent first
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/statuses/#list-statuses-for-a-specific-ref)
+    /// for more information.
+    pub fn list(&self, reference: &str, options: &StatusListOptions) -> Future<Vec<Status>> {
+        self.github.get(&self.list_uri(reference, options))
+    }
+
+    /// provides a stream over all pages of the statuses of a git sha, branch,
+    /// or tag, most recent first
+    pub fn iter(&self, reference: &str, options: &StatusListOptions) -> Stream<Status> {
+        self.github.get_stream(&self.list_uri(reference, options))
     }
 
-    /// list the combined statuses for a given git sha
-    /// fixme: give this a type
-    pub fn combined(&self, sha: &str) -> Future<String> {
+    /// get the combined status for a given git sha, branch, or tag: the
+    /// latest status of each context rolled up into a single state
+    ///
+    /// See the [github docs](https://developer.github.com/v3/repos/statuses/#get-the-combined-status-for-a-specific-ref)
+    /// for more information.
+    pub fn combined(&self, sha: &str) -> Future<CombinedStatus> {
         self.github.get(&format!(
             "/repos/{}/{}/commits/{}/status",
             self.owner, self.repo, sha
@@ -55,19 +78,93 @@ impl Statuses {
 
 // representations (todo: replace with derive_builder)
 
+/// Options used to page through the statuses of a ref
+#[derive(Default)]
+pub struct StatusListOptions {
+    params: HashMap<&'static str, String>,
+}
+
+impl StatusListOptions {
+    pub fn builder() -> StatusListOptionsBuilder {
+        StatusListOptionsBuilder::default()
+    }
+
+    /// serialize options as a string. returns None if no options are defined
+    pub fn serialize(&self) -> Option<String> {
+        if self.params.is_empty() {
+            None
+        } else {
+            let encoded: String = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(&self.params)
+                .finish();
+            Some(encoded)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct StatusListOptionsBuilder(StatusListOptions);
+
+impl StatusListOptionsBuilder {
+    /// the number of statuses per page, up to 100
+    pub fn per_page(&mut self, n: u32) -> &mut Self {
+        self.0.params.insert("per_page", n.to_string());
+        self
+    }
+
+    pub fn page(&mut self, n: u32) -> &mut Self {
+        self.0.params.insert("page", n.to_string());
+        self
+    }
+
+    pub fn build(&self) -> StatusListOptions {
+        StatusListOptions {
+            params: self.0.params.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Status {
-    pub created_at: String,
-    pub updated_at: String,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
     pub state: State,
-    pub target_url: String,
-    pub description: String,
+    pub target_url: Option<String>,
+    pub description: Option<String>,
     pub id: u64,
     pub url: String,
     pub context: String,
     pub creator: User,
 }
 
+/// The combined status of a ref. Its state is `failure` if any context
+/// reports an error or failure, `pending` if there are no statuses or any
+/// context is pending, and `success` otherwise
+#[derive(Debug, Deserialize)]
+pub struct CombinedStatus {
+    pub state: State,
+    pub sha: String,
+    pub total_count: u64,
+    /// the latest status of each context
+    pub statuses: Vec<ContextStatus>,
+    pub commit_url: String,
+    pub url: String,
+}
+
+/// The latest status of a single context in a `CombinedStatus`
+#[derive(Debug, Deserialize)]
+pub struct ContextStatus {
+    pub id: u64,
+    pub url: String,
+    pub state: State,
+    pub context: String,
+    pub description: Option<String>,
+    pub target_url: Option<String>,
+    pub avatar_url: Option<String>,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
 #[derive(Debug, Default, Serialize)]
 pub struct StatusOptions {
     state: State,
@@ -150,10 +247,11 @@ impl StatusOptions {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize, Default)]
 pub enum State {
     /// pending
     #[serde(rename = "pending")]
+    #[default]
     Pending,
     /// success
     #[serde(rename = "success")]
@@ -166,12 +264,6 @@ pub enum State {
     Failure,
 }
 
-impl Default for State {
-    fn default() -> State {
-        State::Pending
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,16 +271,14 @@ mod tests {
     use serde_json;
 
     fn test_encoding<E: Serialize>(tests: Vec<(E, &str)>) {
-        for test in tests {
-            match test {
-                (k, v) => assert_eq!(serde_json::to_string(&k).unwrap(), v),
-            }
+        for (k, v) in tests {
+            assert_eq!(serde_json::to_string(&k).unwrap(), v);
         }
     }
 
     #[test]
     fn deserialize_status_state() {
-        for (json, value) in vec![
+        for (json, value) in [
             ("\"pending\"", State::Pending),
             ("\"success\"", State::Success),
             ("\"error\"", State::Error),
@@ -200,7 +290,7 @@ mod tests {
 
     #[test]
     fn serialize_status_state() {
-        for (json, value) in vec![
+        for (json, value) in [
             ("\"pending\"", State::Pending),
             ("\"success\"", State::Success),
             ("\"error\"", State::Error),
@@ -240,4 +330,30 @@ mod tests {
         test_encoding(tests)
     }
 
+    #[test]
+    fn deserialize_combined_status() {
+        let combined: CombinedStatus = serde_json::from_str(
+            r#"{
+                "state": "failure",
+                "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "total_count": 1,
+                "statuses": [{
+                    "id": 1,
+                    "url": "https://api.github.com/repos/octocat/Hello-World/statuses/6dcb09b",
+                    "state": "failure",
+                    "context": "ci/build",
+                    "description": null,
+                    "target_url": null,
+                    "avatar_url": null,
+                    "created_at": "2012-07-20T01:19:13Z",
+                    "updated_at": "2012-07-20T01:19:13Z"
+                }],
+                "commit_url": "https://api.github.com/repos/octocat/Hello-World/6dcb09b",
+                "url": "https://api.github.com/repos/octocat/Hello-World/6dcb09b/status"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(combined.state, State::Failure);
+        assert_eq!(combined.statuses[0].context, "ci/build");
+    }
 }
@@ -0,0 +1,66 @@
+//! Tag protection interface
+//!
+//! Protected tags may only be created and deleted by users with admin or
+//! maintain access to a repository. Github is replacing tag protection with
+//! rulesets targeting tags, see the `rulesets` module.
+//!
+//! See the [github docs](https://docs.github.com/en/rest/repos/tags) for more information
+use serde::Deserialize;
+
+use crate::{DateTime, Future, Github};
+
+/// Provides access to the tag protection patterns of a repository
+pub struct TagProtection {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl TagProtection {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        TagProtection {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self, more: &str) -> String {
+        format!("/repos/{}/{}/tags/protection{}", self.owner, self.repo, more)
+    }
+
+    /// list the tag protection patterns of this repository
+    pub fn list(&self) -> Future<Vec<TagProtectionRule>> {
+        self.github.get(&self.path(""))
+    }
+
+    /// protect tags matching a pattern, like `v*`
+    pub fn create<P>(&self, pattern: P) -> Future<TagProtectionRule>
+    where
+        P: Into<String>,
+    {
+        self.github
+            .post(&self.path(""), json_lit!({ "pattern": pattern.into() }))
+    }
+
+    /// stop protecting the tags matching a tag protection pattern
+    pub fn delete(&self, id: u64) -> Future<()> {
+        self.github.delete(&self.path(&format!("/{}", id)))
+    }
+}
+
+// representations
+
+#[derive(Debug, Deserialize)]
+pub struct TagProtectionRule {
+    pub id: u64,
+    pub pattern: String,
+    pub created_at: Option<DateTime>,
+    pub updated_at: Option<DateTime>,
+    pub enabled: Option<bool>,
+}
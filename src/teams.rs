@@ -1,16 +1,21 @@
 //! Teams interface
 use std::fmt;
 
+use futures::Future as StdFuture;
+use http::StatusCode;
 use serde::{Deserialize, Serialize};
 
+use crate::repositories::Repo;
 use crate::users::User;
-use crate::{Future, Github, Stream};
+use crate::{Error, ErrorKind, Future, Github, Stream};
 
 /// Team repository permissions
 #[derive(Clone, Copy)]
 pub enum Permission {
     Pull,
+    Triage,
     Push,
+    Maintain,
     Admin,
 }
 
@@ -18,7 +23,9 @@ impl fmt::Display for Permission {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Permission::Pull => "pull",
+            Permission::Triage => "triage",
             Permission::Push => "push",
+            Permission::Maintain => "maintain",
             Permission::Admin => "admin",
         }
         .fmt(f)
@@ -138,7 +145,7 @@ impl OrgTeamActions {
         self.github.get(&self.path(""))
     }
 
-    /// edit the team
+    /// edit the team's name, description, privacy, or default permission
     pub fn update(&self, team_options: &TeamOptions) -> Future<Team> {
         self.github.patch(&self.path(""), json!(team_options))
     }
@@ -173,6 +180,60 @@ impl OrgTeamActions {
         self.github
             .delete(&self.path(&format!("/memberships/{}", user)))
     }
+
+    /// get a user's membership of the team, including their role and whether
+    /// they have accepted an invitation to the organization yet
+    ///
+    /// See the [github docs](https://developer.github.com/v3/teams/members/#get-team-membership)
+    /// for more information.
+    pub fn membership(&self, user: &str) -> Future<TeamMember> {
+        self.github
+            .get(&self.path(&format!("/memberships/{}", user)))
+    }
+
+    /// check whether a user is a member of the team, either active or
+    /// pending
+    pub fn is_member(&self, user: &str) -> Future<bool> {
+        Box::new(self.membership(user).map(|_| true).or_else(|err| match err {
+            Error(
+                ErrorKind::Fault {
+                    code: StatusCode::NOT_FOUND,
+                    ..
+                },
+                _,
+            ) => Ok(false),
+            otherwise => Err(otherwise),
+        }))
+    }
+
+    /// list of repositories this team has access to
+    pub fn list_repos(&self) -> Future<Vec<Repo>> {
+        self.github.get(&self.path("/repos"))
+    }
+
+    /// provides an iterator over all pages of repositories this team has
+    /// access to
+    pub fn iter_repos(&self) -> Stream<Repo> {
+        self.github.get_stream(&self.path("/repos"))
+    }
+
+    /// give the team access to a repository with the given permission,
+    /// or change the permission it already has
+    ///
+    /// See the [github docs](https://developer.github.com/v3/teams/#add-or-update-team-repository)
+    /// for more information.
+    pub fn add_repo(&self, owner: &str, repo: &str, permission: Permission) -> Future<()> {
+        self.github.put_no_response(
+            &self.path(&format!("/repos/{}/{}", owner, repo)),
+            json_lit!({ "permission": permission.to_string() }),
+        )
+    }
+
+    /// revoke the team's access to a repository
+    pub fn remove_repo(&self, owner: &str, repo: &str) -> Future<()> {
+        self.github
+            .delete(&self.path(&format!("/repos/{}/{}", owner, repo)))
+    }
 }
 
 // representations (todo: replace with derive_builder)
@@ -189,14 +250,14 @@ pub struct TeamMemberOptions {
     pub role: TeamMemberRole,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum TeamMemberRole {
     Member,
     Maintainer,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum TeamMemberState {
     Active,
@@ -227,3 +288,36 @@ pub struct TeamOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub permission: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permission_display() {
+        let permissions = [
+            Permission::Pull,
+            Permission::Triage,
+            Permission::Push,
+            Permission::Maintain,
+            Permission::Admin,
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+        assert_eq!(
+            permissions,
+            vec!["pull", "triage", "push", "maintain", "admin"]
+        );
+    }
+
+    #[test]
+    fn deserialize_team_member() {
+        let member: TeamMember = serde_json::from_str(
+            r#"{"url": "https://api.github.com/teams/1/memberships/octocat", "role": "maintainer", "state": "pending"}"#,
+        )
+        .unwrap();
+        assert_eq!(member.role, TeamMemberRole::Maintainer);
+        assert_eq!(member.state, TeamMemberState::Pending);
+    }
+}
@@ -1,9 +1,9 @@
 //! Traffic interface
 use std::fmt;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::{Future, Github};
+use crate::{DateTime, Future, Github};
 
 /// Describes types of breakdowns of the data for views or clones
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -76,16 +76,17 @@ impl Traffic {
     }
 }
 
-// representations
+// representations. these are serializable so that traffic, which github only
+// keeps for 14 days, can be exported
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Referrer {
     pub referrer: String,
     pub count: u32,
     pub uniques: u32,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Path {
     pub path: String,
     pub title: String,
@@ -93,23 +94,48 @@ pub struct Path {
     pub uniques: u32,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Views {
     pub count: u32,
     pub uniques: u32,
     pub views: Vec<DataPoint>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Clones {
     pub count: u32,
     pub uniques: u32,
     pub clones: Vec<DataPoint>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct DataPoint {
-    pub timestamp: String,
+    pub timestamp: DateTime,
     pub count: u32,
     pub uniques: u32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_views() {
+        let views: Views = serde_json::from_str(
+            r#"{
+                "count": 14850,
+                "uniques": 3782,
+                "views": [
+                    {"timestamp": "2016-10-10T00:00:00Z", "count": 440, "uniques": 143}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(views.count, 14850);
+        assert_eq!(views.views[0].uniques, 143);
+        assert_eq!(
+            serde_json::to_value(&views.views[0]).unwrap(),
+            serde_json::json!({"timestamp": "2016-10-10T00:00:00Z", "count": 440, "uniques": 143})
+        );
+    }
+}
@@ -0,0 +1,143 @@
+//! Transport interface
+//!
+//! A `Transport` sends the requests a `Github` client builds. By default
+//! requests are sent over the network with the client's `reqwest` client.
+//! Tests of code using hubcaps can instead give a client a transport answering
+//! with canned responses with `Github#set_transport`, like `MockTransport`,
+//! so they don't need a live token or a stub http server
+//!
+//! ```
+//! use futures::Future;
+//! use hubcaps::transport::MockTransport;
+//! use hubcaps::reqwest::{Method, StatusCode};
+//! use hubcaps::Github;
+//!
+//! let mut github = Github::new("my-cool-user-agent/0.1.0", None).unwrap();
+//! github.set_transport(MockTransport::new().respond(
+//!     Method::GET,
+//!     "/repos/softprops/hubcaps/labels",
+//!     StatusCode::OK,
+//!     r#"[{"url": "", "name": "bug", "color": "d73a4a"}]"#,
+//! ));
+//! let labels = github
+//!     .repo("softprops", "hubcaps")
+//!     .labels()
+//!     .list()
+//!     .wait()
+//!     .unwrap();
+//! assert_eq!(labels[0].name, "bug");
+//! ```
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use futures::{future, Future as StdFuture};
+use http::header::HeaderMap;
+use http::{Method, StatusCode};
+use reqwest::r#async::{Client, Request, Response};
+
+use crate::{Error, ErrorKind, Future};
+
+/// Sends requests and yields their responses
+pub trait Transport: fmt::Debug + Send + Sync {
+    fn execute(&self, request: Request) -> Future<Response>;
+}
+
+impl Transport for Client {
+    fn execute(&self, request: Request) -> Future<Response> {
+        Box::new(Client::execute(self, request).map_err(Error::from))
+    }
+}
+
+impl<T> Transport for Arc<T>
+where
+    T: Transport + ?Sized,
+{
+    fn execute(&self, request: Request) -> Future<Response> {
+        (**self).execute(request)
+    }
+}
+
+/// A transport answering requests with canned responses by their method and
+/// path. Requests without a canned response fail
+#[derive(Clone, Debug, Default)]
+pub struct MockTransport {
+    responses: HashMap<(Method, String), (StatusCode, HeaderMap, Vec<u8>)>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// answer requests with `method` for `path`, like
+    /// `/repos/softprops/hubcaps`, with a response with `status` and `body`.
+    /// query strings are ignored when matching paths
+    pub fn respond<P, B>(self, method: Method, path: P, status: StatusCode, body: B) -> Self
+    where
+        P: Into<String>,
+        B: Into<Vec<u8>>,
+    {
+        self.respond_with_headers(method, path, status, HeaderMap::new(), body)
+    }
+
+    /// like `respond`, answering with `headers` too
+    pub fn respond_with_headers<P, B>(
+        mut self,
+        method: Method,
+        path: P,
+        status: StatusCode,
+        headers: HeaderMap,
+        body: B,
+    ) -> Self
+    where
+        P: Into<String>,
+        B: Into<Vec<u8>>,
+    {
+        self.responses
+            .insert((method, path.into()), (status, headers, body.into()));
+        self
+    }
+}
+
+impl Transport for MockTransport {
+    fn execute(&self, request: Request) -> Future<Response> {
+        let key = (request.method().clone(), request.url().path().to_string());
+        match self.responses.get(&key) {
+            Some((status, headers, body)) => {
+                let mut response = http::Response::builder();
+                response.status(*status);
+                for (name, value) in headers {
+                    response.header(name, value.clone());
+                }
+                let response = response
+                    .body(body.clone())
+                    .map_err(|err| Error::from(ErrorKind::Msg(err.to_string())));
+                Box::new(future::result(response.map(Response::from)))
+            }
+            None => Box::new(future::err(
+                ErrorKind::Msg(format!("no response for {} {}", key.0, key.1)).into(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_transport_fails_unknown_requests() {
+        let transport =
+            MockTransport::new().respond(Method::GET, "/user", StatusCode::OK, "{}");
+        let request = |path: &str| {
+            Client::new()
+                .get(&*format!("https://api.github.com{}", path))
+                .build()
+                .unwrap()
+        };
+        let response = transport.execute(request("/user?page=2")).wait().unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(transport.execute(request("/users")).wait().is_err());
+    }
+}
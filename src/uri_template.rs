@@ -0,0 +1,139 @@
+//! URI template expansion
+//!
+//! Many github representations carry [RFC 6570](https://tools.ietf.org/html/rfc6570)
+//! templated urls, like a release's `upload_url` or a repository's
+//! `collaborators_url{/collaborator}`. `expand` fills those in so that
+//! hypermedia links can be followed as is.
+//!
+//! Expressions with the `+`, `#`, `.`, `/`, `;`, `?` and `&` operators
+//! are supported for string values. Variables without a value are
+//! left out of the expansion. Value modifiers (`:n` and `*`) are accepted
+//! but ignored.
+
+/// Expand a uri template with the provided variables
+///
+/// ```rust
+/// use hubcaps::uri_template::expand;
+///
+/// assert_eq!(
+///     expand(
+///         "https://uploads.github.com/repos/o/r/releases/1/assets{?name,label}",
+///         &[("name", "hubcaps.tar.gz")],
+///     ),
+///     "https://uploads.github.com/repos/o/r/releases/1/assets?name=hubcaps.tar.gz"
+/// );
+/// ```
+pub fn expand(template: &str, vars: &[(&str, &str)]) -> String {
+    let mut expanded = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        expanded.push_str(&rest[..start]);
+        match rest[start..].find('}') {
+            Some(end) => {
+                expand_expression(&rest[start + 1..start + end], vars, &mut expanded);
+                rest = &rest[start + end + 1..];
+            }
+            None => {
+                // unterminated expressions are copied through untouched
+                expanded.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+fn expand_expression(expression: &str, vars: &[(&str, &str)], out: &mut String) {
+    let (operator, names) = match expression.chars().next() {
+        Some(op @ '+') | Some(op @ '#') | Some(op @ '.') | Some(op @ '/') | Some(op @ ';')
+        | Some(op @ '?') | Some(op @ '&') => (Some(op), &expression[1..]),
+        _ => (None, expression),
+    };
+    let (first, separator, named, allow_reserved) = match operator {
+        None => ("", ",", false, false),
+        Some('+') => ("", ",", false, true),
+        Some('#') => ("#", ",", false, true),
+        Some('.') => (".", ".", false, false),
+        Some('/') => ("/", "/", false, false),
+        Some(';') => (";", ";", true, false),
+        Some('?') => ("?", "&", true, false),
+        _ => ("&", "&", true, false),
+    };
+    let mut defined = names
+        .split(',')
+        .map(|name| name.trim_end_matches('*').split(':').next().unwrap_or(""))
+        .filter_map(|name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| (name, *value))
+        })
+        .peekable();
+    if defined.peek().is_some() {
+        out.push_str(first);
+    }
+    for (i, (name, value)) in defined.enumerate() {
+        if i > 0 {
+            out.push_str(separator);
+        }
+        if named {
+            out.push_str(name);
+            if value.is_empty() && operator == Some(';') {
+                continue;
+            }
+            out.push('=');
+        }
+        encode(value, allow_reserved, out);
+    }
+}
+
+fn encode(value: &str, allow_reserved: bool, out: &mut String) {
+    for byte in value.bytes() {
+        let unreserved = byte.is_ascii_alphanumeric() || b"-._~".contains(&byte);
+        let reserved = b":/?#[]@!$&'()*+,;=".contains(&byte);
+        if unreserved || (allow_reserved && reserved) {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_operators() {
+        let vars = &[
+            ("owner", "softprops"),
+            ("repo", "hubcaps"),
+            ("collaborator", "jasonwhite"),
+            ("name", "hubcaps 0.6.tar.gz"),
+            ("path", "/docs/readme"),
+        ];
+        for (template, expected) in &[
+            ("/repos/{owner}/{repo}", "/repos/softprops/hubcaps"),
+            (
+                "/repos/softprops/hubcaps/collaborators{/collaborator}",
+                "/repos/softprops/hubcaps/collaborators/jasonwhite",
+            ),
+            ("/assets{/id}", "/assets"),
+            ("/assets{?name,label}", "/assets?name=hubcaps%200.6.tar.gz"),
+            (
+                "/search?q=x{&owner,repo}",
+                "/search?q=x&owner=softprops&repo=hubcaps",
+            ),
+            ("{+path}", "/docs/readme"),
+            ("{path}", "%2Fdocs%2Freadme"),
+            ("/x{#repo}", "/x#hubcaps"),
+            ("/x{.repo}", "/x.hubcaps"),
+            ("/x{;owner}", "/x;owner=softprops"),
+            ("/x{/owner*}", "/x/softprops"),
+            ("/x{/owner:3}", "/x/softprops"),
+            ("/unterminated{owner", "/unterminated{owner"),
+        ] {
+            assert_eq!(expand(template, vars), *expected)
+        }
+    }
+}
@@ -1,6 +1,24 @@
 //! Users interface
-use crate::{Future, Github, Stream};
-use serde::Deserialize;
+use futures::Future as StdFuture;
+use http::StatusCode;
+
+#[cfg(feature = "activity")]
+use crate::events::Events;
+use crate::keys::{PublicKey, UserKeys};
+use crate::{DateTime, Error, ErrorKind, Future, Github, Stream};
+use serde::{Deserialize, Serialize};
+
+/// The type of account behind a `User`
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+pub enum UserType {
+    User,
+    Organization,
+    Bot,
+    /// an account type hubcaps doesn't define, like the `Mannequin`
+    /// placeholders of imported accounts
+    #[serde(other)]
+    Unknown,
+}
 
 /// User information
 #[derive(Debug, Deserialize)]
@@ -20,7 +38,8 @@ pub struct User {
     pub repos_url: String,
     pub events_url: String,
     pub received_events_url: String,
-    // type (keyword)
+    #[serde(rename = "type")]
+    pub user_type: UserType,
     pub site_admin: bool,
 }
 
@@ -42,13 +61,14 @@ pub struct AuthenticatedUser {
     pub repos_url: String,
     pub events_url: String,
     pub received_events_url: String,
-    // type (keyword)
+    #[serde(rename = "type")]
+    pub user_type: UserType,
     pub site_admin: bool,
 
     // extend over `User`:
     pub name: Option<String>,
     pub company: Option<String>,
-    pub blog: String,
+    pub blog: Option<String>,
     pub location: Option<String>,
     pub email: Option<String>,
     pub hireable: Option<bool>,
@@ -57,8 +77,124 @@ pub struct AuthenticatedUser {
     pub public_gists: u64,
     pub followers: u64,
     pub following: u64,
-    pub created_at: String, // TODO: change to `DateTime`?
-    pub updated_at: String, // TODO: change to `DateTime`?
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+
+    // only included for credentials with the `user` scope:
+    pub total_private_repos: Option<u64>,
+    pub owned_private_repos: Option<u64>,
+    pub private_gists: Option<u64>,
+    /// in kilobytes
+    pub disk_usage: Option<u64>,
+    pub collaborators: Option<u64>,
+    pub two_factor_authentication: Option<bool>,
+    pub plan: Option<Plan>,
+}
+
+/// The plan of the current authenticated user
+#[derive(Debug, Deserialize)]
+pub struct Plan {
+    pub name: String,
+    pub space: u64,
+    pub private_repos: u64,
+    pub collaborators: u64,
+}
+
+/// Options for editing the current authenticated user's profile
+#[derive(Debug, Default, Serialize)]
+pub struct UserEditOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blog: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hireable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bio: Option<String>,
+}
+
+impl UserEditOptions {
+    pub fn builder() -> UserEditOptionsBuilder {
+        UserEditOptionsBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct UserEditOptionsBuilder(UserEditOptions);
+
+impl UserEditOptionsBuilder {
+    pub fn name<N>(&mut self, name: N) -> &mut Self
+    where
+        N: Into<String>,
+    {
+        self.0.name = Some(name.into());
+        self
+    }
+
+    /// the publicly visible email address. must be one of the user's
+    /// verified addresses
+    pub fn email<E>(&mut self, email: E) -> &mut Self
+    where
+        E: Into<String>,
+    {
+        self.0.email = Some(email.into());
+        self
+    }
+
+    pub fn blog<B>(&mut self, blog: B) -> &mut Self
+    where
+        B: Into<String>,
+    {
+        self.0.blog = Some(blog.into());
+        self
+    }
+
+    pub fn company<C>(&mut self, company: C) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        self.0.company = Some(company.into());
+        self
+    }
+
+    pub fn location<L>(&mut self, location: L) -> &mut Self
+    where
+        L: Into<String>,
+    {
+        self.0.location = Some(location.into());
+        self
+    }
+
+    pub fn hireable(&mut self, hireable: bool) -> &mut Self {
+        self.0.hireable = Some(hireable);
+        self
+    }
+
+    pub fn bio<B>(&mut self, bio: B) -> &mut Self
+    where
+        B: Into<String>,
+    {
+        self.0.bio = Some(bio.into());
+        self
+    }
+
+    pub fn build(&self) -> UserEditOptions {
+        UserEditOptions {
+            name: self.0.name.clone(),
+            email: self.0.email.clone(),
+            blog: self.0.blog.clone(),
+            company: self.0.company.clone(),
+            location: self.0.location.clone(),
+            hireable: self.0.hireable,
+            bio: self.0.bio.clone(),
+        }
+    }
 }
 
 /// Query user information
@@ -76,6 +212,12 @@ impl Users {
         self.github.get("/user")
     }
 
+    /// Edit the profile of the current authenticated user
+    /// https://developer.github.com/v3/users/#update-the-authenticated-user
+    pub fn edit(&self, options: &UserEditOptions) -> Future<AuthenticatedUser> {
+        self.github.patch("/user", json!(options))
+    }
+
     pub fn get<U>(&self, username: U) -> Future<User>
     where
         U: Into<String>,
@@ -83,6 +225,217 @@ impl Users {
         self.github
             .get(&format!("/users/{username}", username = username.into()))
     }
+
+    /// Return a reference to the SSH keys of the current authenticated user
+    pub fn keys(&self) -> UserKeys {
+        UserKeys::new(self.github.clone())
+    }
+
+    /// Return a reference to the email addresses of the current authenticated user
+    pub fn emails(&self) -> UserEmails {
+        UserEmails::new(self.github.clone())
+    }
+
+    /// Return a reference to the events performed by a user. only the
+    /// authenticated user sees their private events
+    #[cfg(feature = "activity")]
+    pub fn events<U>(&self, username: U) -> Events
+    where
+        U: Into<String>,
+    {
+        Events::for_user(self.github.clone(), username)
+    }
+
+    /// Return a reference to the events of the repositories and users a user
+    /// watches and follows
+    #[cfg(feature = "activity")]
+    pub fn received_events<U>(&self, username: U) -> Events
+    where
+        U: Into<String>,
+    {
+        Events::received_by(self.github.clone(), username)
+    }
+
+    /// list the public SSH keys of any user
+    pub fn public_keys<U>(&self, username: U) -> Future<Vec<PublicKey>>
+    where
+        U: Into<String>,
+    {
+        self.github.get(&format!(
+            "/users/{username}/keys",
+            username = username.into()
+        ))
+    }
+
+    /// list the followers of a user
+    /// https://developer.github.com/v3/users/followers/#list-followers-of-a-user
+    pub fn followers<U>(&self, username: U) -> Future<Vec<User>>
+    where
+        U: Into<String>,
+    {
+        self.github
+            .get(&format!("/users/{}/followers", username.into()))
+    }
+
+    /// provides a stream over all pages of the followers of a user
+    pub fn iter_followers<U>(&self, username: U) -> Stream<User>
+    where
+        U: Into<String>,
+    {
+        self.github
+            .get_stream(&format!("/users/{}/followers", username.into()))
+    }
+
+    /// list the users a user follows
+    /// https://developer.github.com/v3/users/followers/#list-users-followed-by-another-user
+    pub fn following<U>(&self, username: U) -> Future<Vec<User>>
+    where
+        U: Into<String>,
+    {
+        self.github
+            .get(&format!("/users/{}/following", username.into()))
+    }
+
+    /// provides a stream over all pages of the users a user follows
+    pub fn iter_following<U>(&self, username: U) -> Stream<User>
+    where
+        U: Into<String>,
+    {
+        self.github
+            .get_stream(&format!("/users/{}/following", username.into()))
+    }
+
+    /// check whether the current authenticated user follows a user
+    /// https://developer.github.com/v3/users/followers/#check-if-you-are-following-a-user
+    pub fn is_following<U>(&self, username: U) -> Future<bool>
+    where
+        U: Into<String>,
+    {
+        self.check(&format!("/user/following/{}", username.into()))
+    }
+
+    /// check whether a user follows another user
+    pub fn follows<U, T>(&self, username: U, target: T) -> Future<bool>
+    where
+        U: Into<String>,
+        T: Into<String>,
+    {
+        self.check(&format!(
+            "/users/{}/following/{}",
+            username.into(),
+            target.into()
+        ))
+    }
+
+    /// follow a user as the current authenticated user
+    /// https://developer.github.com/v3/users/followers/#follow-a-user
+    pub fn follow<U>(&self, username: U) -> Future<()>
+    where
+        U: Into<String>,
+    {
+        self.github.put_no_response(
+            &format!("/user/following/{}", username.into()),
+            Vec::new(),
+        )
+    }
+
+    /// unfollow a user as the current authenticated user
+    pub fn unfollow<U>(&self, username: U) -> Future<()>
+    where
+        U: Into<String>,
+    {
+        self.github
+            .delete(&format!("/user/following/{}", username.into()))
+    }
+
+    /// github answers checks with a 204 when they hold and a 404 otherwise
+    fn check(&self, uri: &str) -> Future<bool> {
+        Box::new(
+            self.github
+                .get::<()>(uri)
+                .map(|_| true)
+                .or_else(|err| match err {
+                    Error(
+                        ErrorKind::Fault {
+                            code: StatusCode::NOT_FOUND,
+                            ..
+                        },
+                        _,
+                    ) => Ok(false),
+                    Error(ErrorKind::Codec(_), _) => Ok(true),
+                    otherwise => Err(otherwise),
+                }),
+        )
+    }
+}
+
+/// Provides access to the email addresses of the current authenticated user
+pub struct UserEmails {
+    github: Github,
+}
+
+impl UserEmails {
+    #[doc(hidden)]
+    pub fn new(github: Github) -> Self {
+        UserEmails { github }
+    }
+
+    /// list the email addresses of the authenticated user
+    /// https://developer.github.com/v3/users/emails/#list-email-addresses-for-a-user
+    pub fn list(&self) -> Future<Vec<Email>> {
+        self.github.get("/user/emails")
+    }
+
+    /// provides a stream over all pages of the email addresses of the
+    /// authenticated user
+    pub fn iter(&self) -> Stream<Email> {
+        self.github.get_stream("/user/emails")
+    }
+
+    /// list the email addresses of the authenticated user that are public
+    pub fn public(&self) -> Future<Vec<Email>> {
+        self.github.get("/user/public_emails")
+    }
+
+    /// add email addresses, returning the added addresses
+    /// https://developer.github.com/v3/users/emails/#add-email-addresses
+    pub fn add(&self, emails: Vec<&str>) -> Future<Vec<Email>> {
+        self.github
+            .post("/user/emails", json_lit!({ "emails": emails }))
+    }
+
+    /// delete email addresses
+    pub fn delete(&self, emails: Vec<&str>) -> Future<()> {
+        self.github
+            .delete_message("/user/emails", json_lit!({ "emails": emails }))
+    }
+
+    /// set the visibility of the primary email address
+    /// https://developer.github.com/v3/users/emails/#toggle-primary-email-visibility
+    pub fn set_visibility(&self, visibility: EmailVisibility) -> Future<Vec<Email>> {
+        self.github.patch(
+            "/user/email/visibility",
+            json_lit!({ "visibility": visibility }),
+        )
+    }
+}
+
+/// Who can see an email address
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailVisibility {
+    Public,
+    Private,
+}
+
+/// An email address of the authenticated user
+#[derive(Debug, Deserialize)]
+pub struct Email {
+    pub email: String,
+    pub primary: bool,
+    pub verified: bool,
+    /// only set for the primary email address
+    pub visibility: Option<EmailVisibility>,
 }
 
 /// reference to contributors associated with a github repo
@@ -118,3 +471,74 @@ impl Contributors {
             .get_stream(&format!("/repos/{}/{}/contributors", self.owner, self.repo))
     }
 }
+
+/// Provides access to the users watching a repository
+pub struct Subscribers {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl Subscribers {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        Subscribers {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    /// list of users watching this repo
+    pub fn list(&self) -> Future<Vec<User>> {
+        self.github
+            .get(&format!("/repos/{}/{}/subscribers", self.owner, self.repo))
+    }
+
+    /// provides a stream over all pages of users watching this repo
+    pub fn iter(&self) -> Stream<User> {
+        self.github
+            .get_stream(&format!("/repos/{}/{}/subscribers", self.owner, self.repo))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_user_edit_options() {
+        let options = UserEditOptions::builder()
+            .company("GitHub")
+            .hireable(false)
+            .build();
+        assert_eq!(
+            serde_json::to_value(&options).unwrap(),
+            serde_json::json!({"company": "GitHub", "hireable": false})
+        );
+    }
+
+    #[test]
+    fn deserialize_emails() {
+        let emails: Vec<Email> = serde_json::from_str(
+            r#"[
+                {"email": "octocat@github.com", "primary": true, "verified": true, "visibility": "public"},
+                {"email": "octocat@example.com", "primary": false, "verified": false, "visibility": null}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(emails[0].visibility, Some(EmailVisibility::Public));
+        assert!(!emails[1].verified);
+    }
+
+    #[test]
+    fn deserialize_unknown_user_type() {
+        let types: Vec<UserType> =
+            serde_json::from_str(r#"["Bot", "Mannequin"]"#).unwrap();
+        assert_eq!(types, vec![UserType::Bot, UserType::Unknown]);
+    }
+}
@@ -1,9 +1,9 @@
 //! Watching interface
 /// https://developer.github.com/v3/activity/watching
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::repositories::Repo;
-use crate::{Future, Github, Stream};
+use crate::{DateTime, Future, Github, Stream};
 
 pub struct Watching {
     github: Github,
@@ -15,12 +15,36 @@ impl Watching {
         Self { github }
     }
 
+    /// List the repositories watched by the authenticated user.
+    /// https://developer.github.com/v3/activity/watching/#list-repositories-being-watched
+    pub fn list(&self) -> Future<Vec<Repo>> {
+        self.github.get("/user/subscriptions")
+    }
+
     /// Provides a stream over all pages of the repositories watched by the authenticated user.
     /// https://developer.github.com/v3/activity/watching/#list-repositories-being-watched
     pub fn iter(&self) -> Stream<Repo> {
         self.github.get_stream("/user/subscriptions")
     }
 
+    /// List the repositories watched by a user.
+    pub fn list_for_user<U>(&self, user: U) -> Future<Vec<Repo>>
+    where
+        U: Into<String>,
+    {
+        self.github
+            .get(&format!("/users/{}/subscriptions", user.into()))
+    }
+
+    /// Provides a stream over all pages of the repositories watched by a user.
+    pub fn iter_for_user<U>(&self, user: U) -> Stream<Repo>
+    where
+        U: Into<String>,
+    {
+        self.github
+            .get_stream(&format!("/users/{}/subscriptions", user.into()))
+    }
+
     /// https://developer.github.com/v3/activity/watching/#get-a-repository-subscription
     pub fn get_for_repo<O, R>(&self, owner: O, repo: R) -> Future<Subscription> where
         O: Into<String>,
@@ -60,12 +84,67 @@ impl Watching {
     }
 }
 
+/// The authenticated user's subscription to a repository
+pub struct RepoSubscription {
+    github: Github,
+    owner: String,
+    repo: String,
+}
+
+impl RepoSubscription {
+    #[doc(hidden)]
+    pub fn new<O, R>(github: Github, owner: O, repo: R) -> Self
+    where
+        O: Into<String>,
+        R: Into<String>,
+    {
+        RepoSubscription {
+            github,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    fn path(&self) -> String {
+        format!("/repos/{}/{}/subscription", self.owner, self.repo)
+    }
+
+    /// Get the subscription. Fails with a 404 when the authenticated user
+    /// isn't subscribed to the repository.
+    /// https://developer.github.com/v3/activity/watching/#get-a-repository-subscription
+    pub fn get(&self) -> Future<Subscription> {
+        self.github.get(&self.path())
+    }
+
+    /// Set the subscription. Subscribe to receive notifications for all
+    /// activity, or ignore to receive none.
+    /// https://developer.github.com/v3/activity/watching/#set-a-repository-subscription
+    pub fn set(&self, options: &SubscriptionOptions) -> Future<Subscription> {
+        self.github.put(&self.path(), json!(options))
+    }
+
+    /// Delete the subscription, falling back to only receiving notifications
+    /// for participation and mentions.
+    /// https://developer.github.com/v3/activity/watching/#delete-a-repository-subscription
+    pub fn delete(&self) -> Future<()> {
+        self.github.delete(&self.path())
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct SubscriptionOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscribed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignored: Option<bool>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Subscription {
     pub subscribed: bool,
     pub ignored: bool,
     pub reason: Option<String>,
-    pub created_at: String,
+    pub created_at: DateTime,
     pub url: String,
     pub repository_url: String,
 }